@@ -1,2 +1,8 @@
 // Chain signatures program state seeds
 pub const CHAIN_SIGNATURES_STATE_SEED: &[u8] = b"program-state";
+
+// Vault UTXO set seed prefix; a slice so it fits next to the hashed CAIP-2 ID
+pub const VAULT_UTXO_SET_SEED: &[u8] = b"vault_utxo_set";
+
+// Prefix of MPC responses reporting that a transaction failed, followed by the error
+pub const ERROR_PREFIX: [u8; 4] = [0xDE, 0xAD, 0xBE, 0xEF];
@@ -1,9 +1,10 @@
 use crate::constants::*;
+use crate::error::ErrorCode;
 use crate::state::*;
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
-#[instruction(request_id: [u8; 32], requester: Pubkey, inputs: Vec<BtcInput>, outputs: Vec<BtcOutput>, tx_params: BtcDepositParams)]
+#[instruction(request_id: [u8; 32], requester: Pubkey, inputs: Vec<BtcInput>, amount: u64, tx_params: BtcDepositParams)]
 pub struct DepositBtc<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
@@ -27,6 +28,14 @@ pub struct DepositBtc<'info> {
     )]
     pub pending_deposit: Account<'info, PendingBtcDeposit>,
 
+    /// CHECK: Tombstone for `request_id`, which must not exist yet
+    #[account(
+        seeds = [b"processed_request", request_id.as_ref()],
+        bump,
+        constraint = processed_request.data_is_empty() @ ErrorCode::RequestAlreadyProcessed
+    )]
+    pub processed_request: UncheckedAccount<'info>,
+
     #[account(mut)]
     pub fee_payer: Option<Signer<'info>>,
 
@@ -56,6 +65,13 @@ pub struct DepositBtc<'info> {
         bump
     )]
     pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"chain_registry"],
+        bump
+    )]
+    pub chain_registry: Account<'info, ChainRegistry>,
 }
 
 #[derive(Accounts)]
@@ -71,17 +87,33 @@ pub struct ClaimBtc<'info> {
             &request_id
         ],
         bump,
-        close = payer
+        has_one = rent_payer,
+        close = rent_payer
     )]
     pub pending_deposit: Account<'info, PendingBtcDeposit>,
 
+    /// CHECK: Receives the pending account's rent, checked by `has_one`
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    /// Marks `request_id` as settled; `init` fails if it already was
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ProcessedRequest::INIT_SPACE,
+        seeds = [b"processed_request", request_id.as_ref()],
+        bump
+    )]
+    pub processed_request: Account<'info, ProcessedRequest>,
+
     #[account(
         init_if_needed,
         payer = payer,
         space = 8 + UserBtcBalance::INIT_SPACE,
         seeds = [
             b"user_btc_balance",
-            pending_deposit.requester.as_ref()
+            pending_deposit.requester.as_ref(),
+            &caip2_id_seed(&pending_deposit.caip2_id)
         ],
         bump
     )]
@@ -94,10 +126,110 @@ pub struct ClaimBtc<'info> {
         bump
     )]
     pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"chain_registry"],
+        bump
+    )]
+    pub chain_registry: Account<'info, ChainRegistry>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + VaultUtxoSet::INIT_SPACE,
+        seeds = [VAULT_UTXO_SET_SEED, &caip2_id_seed(&pending_deposit.caip2_id)],
+        bump
+    )]
+    pub vault_utxo_set: Account<'info, VaultUtxoSet>,
 }
 
 #[derive(Accounts)]
-#[instruction(request_id: [u8; 32], inputs: Vec<BtcInput>, amount: u64, recipient_address: String, tx_params: BtcWithdrawParams)]
+#[instruction(request_id: [u8; 32])]
+pub struct FinalizeFailedDepositBtc<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"pending_btc_deposit",
+            &request_id
+        ],
+        bump,
+        has_one = rent_payer,
+        close = rent_payer
+    )]
+    pub pending_deposit: Account<'info, PendingBtcDeposit>,
+
+    /// CHECK: Receives the pending account's rent, checked by `has_one`
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    /// Marks `request_id` as settled; `init` fails if it already was
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ProcessedRequest::INIT_SPACE,
+        seeds = [b"processed_request", request_id.as_ref()],
+        bump
+    )]
+    pub processed_request: Account<'info, ProcessedRequest>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + FailedBtcDeposit::INIT_SPACE,
+        seeds = [b"failed_btc_deposit", request_id.as_ref()],
+        bump
+    )]
+    pub failed_deposit: Account<'info, FailedBtcDeposit>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        seeds = [b"vault_config"],
+        bump
+    )]
+    pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"chain_registry"],
+        bump
+    )]
+    pub chain_registry: Account<'info, ChainRegistry>,
+}
+
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct ReleaseStalledDepositBtc<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"pending_btc_deposit",
+            &request_id
+        ],
+        bump
+    )]
+    pub pending_deposit: Account<'info, PendingBtcDeposit>,
+
+    #[account(
+        seeds = [b"vault_config"],
+        bump
+    )]
+    pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"chain_registry"],
+        bump
+    )]
+    pub chain_registry: Account<'info, ChainRegistry>,
+}
+
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32], amount: u64, recipient_address: String, tx_params: BtcWithdrawParams)]
 pub struct WithdrawBtc<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
@@ -122,11 +254,20 @@ pub struct WithdrawBtc<'info> {
     )]
     pub pending_withdrawal: Account<'info, PendingBtcWithdrawal>,
 
+    /// CHECK: Tombstone for `request_id`, which must not exist yet
+    #[account(
+        seeds = [b"processed_request", request_id.as_ref()],
+        bump,
+        constraint = processed_request.data_is_empty() @ ErrorCode::RequestAlreadyProcessed
+    )]
+    pub processed_request: UncheckedAccount<'info>,
+
     #[account(
         mut,
         seeds = [
             b"user_btc_balance",
-            authority.key().as_ref()
+            authority.key().as_ref(),
+            &caip2_id_seed(&tx_params.caip2_id)
         ],
         bump,
         constraint = user_balance.amount >= amount
@@ -158,10 +299,38 @@ pub struct WithdrawBtc<'info> {
     pub instructions: Option<AccountInfo<'info>>,
 
     #[account(
+        mut,
         seeds = [b"vault_config"],
         bump
     )]
     pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"chain_registry"],
+        bump
+    )]
+    pub chain_registry: Account<'info, ChainRegistry>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + UserRateLimit::INIT_SPACE,
+        seeds = [
+            b"user_btc_rate_limit",
+            authority.key().as_ref(),
+            &caip2_id_seed(&tx_params.caip2_id)
+        ],
+        bump
+    )]
+    pub user_rate_limit: Account<'info, UserRateLimit>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_UTXO_SET_SEED, &caip2_id_seed(&tx_params.caip2_id)],
+        bump
+    )]
+    pub vault_utxo_set: Account<'info, VaultUtxoSet>,
 }
 
 #[derive(Accounts)]
@@ -177,15 +346,31 @@ pub struct CompleteWithdrawBtc<'info> {
             &request_id
         ],
         bump,
-        close = payer
+        has_one = rent_payer,
+        close = rent_payer
     )]
     pub pending_withdrawal: Account<'info, PendingBtcWithdrawal>,
 
+    /// CHECK: Receives the pending account's rent, checked by `has_one`
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    /// Marks `request_id` as settled; `init` fails if it already was
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ProcessedRequest::INIT_SPACE,
+        seeds = [b"processed_request", request_id.as_ref()],
+        bump
+    )]
+    pub processed_request: Account<'info, ProcessedRequest>,
+
     #[account(
         mut,
         seeds = [
             b"user_btc_balance",
-            pending_withdrawal.requester.as_ref()
+            pending_withdrawal.requester.as_ref(),
+            &caip2_id_seed(&pending_withdrawal.caip2_id)
         ],
         bump
     )]
@@ -198,4 +383,345 @@ pub struct CompleteWithdrawBtc<'info> {
         bump
     )]
     pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"chain_registry"],
+        bump
+    )]
+    pub chain_registry: Account<'info, ChainRegistry>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_UTXO_SET_SEED, &caip2_id_seed(&pending_withdrawal.caip2_id)],
+        bump
+    )]
+    pub vault_utxo_set: Account<'info, VaultUtxoSet>,
+}
+
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct RecordFailedWithdrawBtc<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"pending_btc_withdrawal",
+            &request_id
+        ],
+        bump
+    )]
+    pub pending_withdrawal: Account<'info, PendingBtcWithdrawal>,
+
+    #[account(
+        seeds = [b"vault_config"],
+        bump
+    )]
+    pub config: Account<'info, VaultConfig>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64, request_id: [u8; 32])]
+pub struct CancelWithdrawBtc<'info> {
+    #[account(
+        mut,
+        constraint = config.is_admin_signer(&executor.key()) @ ErrorCode::Unauthorized
+    )]
+    pub executor: Signer<'info>,
+
+    #[account(
+        seeds = [b"vault_config"],
+        bump
+    )]
+    pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"config_proposal",
+            proposal_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        has_one = proposer,
+        close = proposer
+    )]
+    pub proposal: Account<'info, ConfigProposal>,
+
+    /// CHECK: Receives the proposal rent, checked against the proposal
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"global_vault_authority"],
+        bump
+    )]
+    /// CHECK: This is a PDA that will be used as a signer
+    pub requester: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"pending_btc_withdrawal",
+            &request_id
+        ],
+        bump
+    )]
+    pub pending_withdrawal: Account<'info, PendingBtcWithdrawal>,
+
+    #[account(
+        seeds = [VAULT_UTXO_SET_SEED, &caip2_id_seed(&pending_withdrawal.caip2_id)],
+        bump
+    )]
+    pub vault_utxo_set: Account<'info, VaultUtxoSet>,
+
+    #[account(mut)]
+    pub fee_payer: Option<Signer<'info>>,
+
+    /// CHECK: Chain signatures state
+    #[account(
+        mut,
+        seeds = [CHAIN_SIGNATURES_STATE_SEED],
+        bump,
+        seeds::program = chain_signatures_program.key()
+    )]
+    pub chain_signatures_state: AccountInfo<'info>,
+
+    /// CHECK: Event authority for CPI events, PDA with seed "__event_authority"
+    #[account(
+        seeds = [b"__event_authority"],
+        bump,
+        seeds::program = chain_signatures_program.key()
+    )]
+    pub event_authority: AccountInfo<'info>,
+
+    pub chain_signatures_program: Program<'info, ::chain_signatures::program::ChainSignatures>,
+    pub system_program: Program<'info, System>,
+    pub instructions: Option<AccountInfo<'info>>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64, request_id: [u8; 32], caip2_id: String)]
+pub struct SweepBtc<'info> {
+    #[account(
+        mut,
+        constraint = config.is_admin_signer(&executor.key()) @ ErrorCode::Unauthorized
+    )]
+    pub executor: Signer<'info>,
+
+    #[account(
+        seeds = [b"vault_config"],
+        bump
+    )]
+    pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"config_proposal",
+            proposal_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        has_one = proposer,
+        close = proposer
+    )]
+    pub proposal: Account<'info, ConfigProposal>,
+
+    /// CHECK: Receives the proposal rent, checked against the proposal
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"global_vault_authority"],
+        bump
+    )]
+    /// CHECK: This is a PDA that will be used as a signer
+    pub requester: AccountInfo<'info>,
+
+    #[account(
+        init,
+        payer = executor,
+        space = 8 + PendingBtcSweep::INIT_SPACE,
+        seeds = [
+            b"pending_btc_sweep",
+            request_id.as_ref()
+        ],
+        bump
+    )]
+    pub pending_sweep: Account<'info, PendingBtcSweep>,
+
+    /// CHECK: Tombstone for `request_id`, which must not exist yet
+    #[account(
+        seeds = [b"processed_request", request_id.as_ref()],
+        bump,
+        constraint = processed_request.data_is_empty() @ ErrorCode::RequestAlreadyProcessed
+    )]
+    pub processed_request: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"chain_registry"],
+        bump
+    )]
+    pub chain_registry: Account<'info, ChainRegistry>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_UTXO_SET_SEED, &caip2_id_seed(&caip2_id)],
+        bump
+    )]
+    pub vault_utxo_set: Account<'info, VaultUtxoSet>,
+
+    #[account(mut)]
+    pub fee_payer: Option<Signer<'info>>,
+
+    /// CHECK: Chain signatures state
+    #[account(
+        mut,
+        seeds = [CHAIN_SIGNATURES_STATE_SEED],
+        bump,
+        seeds::program = chain_signatures_program.key()
+    )]
+    pub chain_signatures_state: AccountInfo<'info>,
+
+    /// CHECK: Event authority for CPI events, PDA with seed "__event_authority"
+    #[account(
+        seeds = [b"__event_authority"],
+        bump,
+        seeds::program = chain_signatures_program.key()
+    )]
+    pub event_authority: AccountInfo<'info>,
+
+    pub chain_signatures_program: Program<'info, ::chain_signatures::program::ChainSignatures>,
+    pub system_program: Program<'info, System>,
+    pub instructions: Option<AccountInfo<'info>>,
+}
+
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct CompleteSweepBtc<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"pending_btc_sweep",
+            &request_id
+        ],
+        bump,
+        has_one = rent_payer,
+        close = rent_payer
+    )]
+    pub pending_sweep: Account<'info, PendingBtcSweep>,
+
+    /// CHECK: Receives the pending account's rent, checked by `has_one`
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    /// Marks `request_id` as settled; `init` fails if it already was
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ProcessedRequest::INIT_SPACE,
+        seeds = [b"processed_request", request_id.as_ref()],
+        bump
+    )]
+    pub processed_request: Account<'info, ProcessedRequest>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        seeds = [b"vault_config"],
+        bump
+    )]
+    pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"chain_registry"],
+        bump
+    )]
+    pub chain_registry: Account<'info, ChainRegistry>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_UTXO_SET_SEED, &caip2_id_seed(&pending_sweep.caip2_id)],
+        bump
+    )]
+    pub vault_utxo_set: Account<'info, VaultUtxoSet>,
+}
+
+#[derive(Accounts)]
+#[instruction(caip2_id: String)]
+pub struct CollectBtcFees<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"vault_config"],
+        bump
+    )]
+    pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"chain_registry"],
+        bump
+    )]
+    pub chain_registry: Account<'info, ChainRegistry>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + UserBtcBalance::INIT_SPACE,
+        seeds = [
+            b"user_btc_balance",
+            config.treasury.as_ref(),
+            &caip2_id_seed(&caip2_id)
+        ],
+        bump
+    )]
+    pub treasury_balance: Account<'info, UserBtcBalance>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateBtcBalance<'info> {
+    #[account(mut)]
+    pub requester: Signer<'info>,
+
+    /// Balance account from before balances were keyed by chain
+    #[account(
+        mut,
+        seeds = [
+            b"user_btc_balance",
+            requester.key().as_ref()
+        ],
+        bump,
+        close = requester
+    )]
+    pub legacy_balance: Account<'info, UserBtcBalance>,
+
+    #[account(
+        init_if_needed,
+        payer = requester,
+        space = 8 + UserBtcBalance::INIT_SPACE,
+        seeds = [
+            b"user_btc_balance",
+            requester.key().as_ref(),
+            &caip2_id_seed(&config.legacy_btc_caip2_id)
+        ],
+        bump
+    )]
+    pub user_balance: Account<'info, UserBtcBalance>,
+
+    #[account(
+        seeds = [b"vault_config"],
+        bump
+    )]
+    pub config: Account<'info, VaultConfig>,
+
+    pub system_program: Program<'info, System>,
 }
@@ -1,8 +1,11 @@
+use crate::error::ErrorCode;
+use crate::program::SolanaCoreContracts;
 use crate::state::*;
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
 pub struct InitializeConfig<'info> {
+    /// Must be the program's upgrade authority so the config can't be front-run after deployment
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(
@@ -13,5 +16,24 @@ pub struct InitializeConfig<'info> {
         bump
     )]
     pub config: Account<'info, VaultConfig>,
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()) @ ErrorCode::Unauthorized)]
+    pub program: Program<'info, SolanaCoreContracts>,
+    #[account(constraint = program_data.upgrade_authority_address == Some(payer.key()) @ ErrorCode::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
     pub system_program: Program<'info, System>,
 }
+
+#[derive(Accounts)]
+pub struct SetPause<'info> {
+    #[account(
+        constraint = authority.key() == config.pause_guardian
+            || config.is_admin_signer(&authority.key()) @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault_config"],
+        bump
+    )]
+    pub config: Account<'info, VaultConfig>,
+}
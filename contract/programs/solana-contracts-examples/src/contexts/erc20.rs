@@ -1,9 +1,10 @@
 use crate::constants::*;
+use crate::error::ErrorCode;
 use crate::state::*;
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
-#[instruction(request_id: [u8; 32], requester: Pubkey, erc20_address: [u8; 20], recipient_address: [u8; 20], amount: u128, tx_params: EvmTransactionParams)]
+#[instruction(request_id: [u8; 32], requester: Pubkey, erc20_address: [u8; 20], amount: u128, tx_params: EvmTransactionParams)]
 pub struct DepositErc20<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
@@ -27,6 +28,14 @@ pub struct DepositErc20<'info> {
     )]
     pub pending_deposit: Account<'info, PendingErc20Deposit>,
 
+    /// CHECK: Tombstone for `request_id`, which must not exist yet
+    #[account(
+        seeds = [b"processed_request", request_id.as_ref()],
+        bump,
+        constraint = processed_request.data_is_empty() @ ErrorCode::RequestAlreadyProcessed
+    )]
+    pub processed_request: UncheckedAccount<'info>,
+
     #[account(mut)]
     pub fee_payer: Option<Signer<'info>>,
 
@@ -55,6 +64,23 @@ pub struct DepositErc20<'info> {
         bump
     )]
     pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        seeds = [b"chain_registry"],
+        bump
+    )]
+    pub chain_registry: Account<'info, ChainRegistry>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"token_registry",
+            tx_params.chain_id.to_le_bytes().as_ref(),
+            erc20_address.as_ref()
+        ],
+        bump
+    )]
+    pub token_registry: Account<'info, TokenRegistry>,
 }
 
 #[derive(Accounts)]
@@ -70,10 +96,25 @@ pub struct ClaimErc20<'info> {
             &request_id
         ],
         bump,
-        close = payer
+        has_one = rent_payer,
+        close = rent_payer
     )]
     pub pending_deposit: Account<'info, PendingErc20Deposit>,
 
+    /// CHECK: Receives the pending account's rent, checked by `has_one`
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    /// Marks `request_id` as settled; `init` fails if it already was
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ProcessedRequest::INIT_SPACE,
+        seeds = [b"processed_request", request_id.as_ref()],
+        bump
+    )]
+    pub processed_request: Account<'info, ProcessedRequest>,
+
     #[account(
         init_if_needed,
         payer = payer,
@@ -81,6 +122,7 @@ pub struct ClaimErc20<'info> {
         seeds = [
             b"user_erc20_balance",
             pending_deposit.requester.as_ref(),
+            pending_deposit.chain_id.to_le_bytes().as_ref(),
             &pending_deposit.erc20_address
         ],
         bump
@@ -93,6 +135,109 @@ pub struct ClaimErc20<'info> {
         bump
     )]
     pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"token_registry",
+            pending_deposit.chain_id.to_le_bytes().as_ref(),
+            pending_deposit.erc20_address.as_ref()
+        ],
+        bump
+    )]
+    pub token_registry: Account<'info, TokenRegistry>,
+}
+
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct FinalizeFailedDepositErc20<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"pending_erc20_deposit",
+            &request_id
+        ],
+        bump,
+        has_one = rent_payer,
+        close = rent_payer
+    )]
+    pub pending_deposit: Account<'info, PendingErc20Deposit>,
+
+    /// CHECK: Receives the pending account's rent, checked by `has_one`
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    /// Marks `request_id` as settled; `init` fails if it already was
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ProcessedRequest::INIT_SPACE,
+        seeds = [b"processed_request", request_id.as_ref()],
+        bump
+    )]
+    pub processed_request: Account<'info, ProcessedRequest>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + FailedErc20Deposit::INIT_SPACE,
+        seeds = [b"failed_erc20_deposit", request_id.as_ref()],
+        bump
+    )]
+    pub failed_deposit: Account<'info, FailedErc20Deposit>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        seeds = [b"vault_config"],
+        bump
+    )]
+    pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"token_registry",
+            pending_deposit.chain_id.to_le_bytes().as_ref(),
+            pending_deposit.erc20_address.as_ref()
+        ],
+        bump
+    )]
+    pub token_registry: Account<'info, TokenRegistry>,
+}
+
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct ReleaseStalledDepositErc20<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"pending_erc20_deposit",
+            &request_id
+        ],
+        bump
+    )]
+    pub pending_deposit: Account<'info, PendingErc20Deposit>,
+
+    #[account(
+        seeds = [b"vault_config"],
+        bump
+    )]
+    pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"token_registry",
+            pending_deposit.chain_id.to_le_bytes().as_ref(),
+            pending_deposit.erc20_address.as_ref()
+        ],
+        bump
+    )]
+    pub token_registry: Account<'info, TokenRegistry>,
 }
 
 #[derive(Accounts)]
@@ -121,11 +266,20 @@ pub struct WithdrawErc20<'info> {
     )]
     pub pending_withdrawal: Account<'info, PendingErc20Withdrawal>,
 
+    /// CHECK: Tombstone for `request_id`, which must not exist yet
+    #[account(
+        seeds = [b"processed_request", request_id.as_ref()],
+        bump,
+        constraint = processed_request.data_is_empty() @ ErrorCode::RequestAlreadyProcessed
+    )]
+    pub processed_request: UncheckedAccount<'info>,
+
     #[account(
         mut,
         seeds = [
             b"user_erc20_balance",
             authority.key().as_ref(),
+            tx_params.chain_id.to_le_bytes().as_ref(),
             &erc20_address
         ],
         bump,
@@ -157,10 +311,128 @@ pub struct WithdrawErc20<'info> {
     pub system_program: Program<'info, System>,
     pub instructions: Option<AccountInfo<'info>>,
     #[account(
+        mut,
         seeds = [b"vault_config"],
         bump
     )]
     pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        seeds = [b"chain_registry"],
+        bump
+    )]
+    pub chain_registry: Account<'info, ChainRegistry>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"token_registry",
+            tx_params.chain_id.to_le_bytes().as_ref(),
+            erc20_address.as_ref()
+        ],
+        bump
+    )]
+    pub token_registry: Account<'info, TokenRegistry>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + UserRateLimit::INIT_SPACE,
+        seeds = [
+            b"user_erc20_rate_limit",
+            authority.key().as_ref(),
+            tx_params.chain_id.to_le_bytes().as_ref(),
+            erc20_address.as_ref()
+        ],
+        bump
+    )]
+    pub user_rate_limit: Account<'info, UserRateLimit>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + EvmVaultNonce::INIT_SPACE,
+        seeds = [
+            b"evm_vault_nonce",
+            tx_params.chain_id.to_le_bytes().as_ref(),
+            config.active_key_version.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub vault_nonce: Account<'info, EvmVaultNonce>,
+}
+
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct ReplaceWithdrawErc20<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"global_vault_authority"],
+        bump
+    )]
+    /// CHECK: This is a PDA that will be used as a signer
+    pub requester: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"pending_erc20_withdrawal",
+            &request_id
+        ],
+        bump,
+        constraint = pending_withdrawal.requester == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub pending_withdrawal: Account<'info, PendingErc20Withdrawal>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"user_erc20_balance",
+            authority.key().as_ref(),
+            pending_withdrawal.chain_id.to_le_bytes().as_ref(),
+            &pending_withdrawal.erc20_address
+        ],
+        bump
+    )]
+    pub user_balance: Account<'info, UserErc20Balance>,
+
+    #[account(mut)]
+    pub fee_payer: Option<Signer<'info>>,
+
+    /// CHECK: Chain signatures state
+    #[account(
+        mut,
+        seeds = [CHAIN_SIGNATURES_STATE_SEED],
+        bump,
+        seeds::program = chain_signatures_program.key()
+    )]
+    pub chain_signatures_state: AccountInfo<'info>,
+
+    /// CHECK: Event authority for CPI events, PDA with seed "__event_authority"
+    #[account(
+        seeds = [b"__event_authority"],
+        bump,
+        seeds::program = chain_signatures_program.key()
+    )]
+    pub event_authority: AccountInfo<'info>,
+
+    pub chain_signatures_program: Program<'info, ::chain_signatures::program::ChainSignatures>,
+    pub system_program: Program<'info, System>,
+    pub instructions: Option<AccountInfo<'info>>,
+
+    #[account(
+        seeds = [b"vault_config"],
+        bump
+    )]
+    pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        seeds = [b"chain_registry"],
+        bump
+    )]
+    pub chain_registry: Account<'info, ChainRegistry>,
 }
 
 #[derive(Accounts)]
@@ -176,15 +448,31 @@ pub struct CompleteWithdrawErc20<'info> {
             &request_id
         ],
         bump,
-        close = payer
+        has_one = rent_payer,
+        close = rent_payer
     )]
     pub pending_withdrawal: Account<'info, PendingErc20Withdrawal>,
 
+    /// CHECK: Receives the pending account's rent, checked by `has_one`
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    /// Marks `request_id` as settled; `init` fails if it already was
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ProcessedRequest::INIT_SPACE,
+        seeds = [b"processed_request", request_id.as_ref()],
+        bump
+    )]
+    pub processed_request: Account<'info, ProcessedRequest>,
+
     #[account(
         mut,
         seeds = [
             b"user_erc20_balance",
             pending_withdrawal.requester.as_ref(),
+            pending_withdrawal.chain_id.to_le_bytes().as_ref(),
             &pending_withdrawal.erc20_address
         ],
         bump
@@ -197,4 +485,208 @@ pub struct CompleteWithdrawErc20<'info> {
         bump
     )]
     pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"token_registry",
+            pending_withdrawal.chain_id.to_le_bytes().as_ref(),
+            pending_withdrawal.erc20_address.as_ref()
+        ],
+        bump
+    )]
+    pub token_registry: Account<'info, TokenRegistry>,
+}
+
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct RecordFailedWithdrawErc20<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"pending_erc20_withdrawal",
+            &request_id
+        ],
+        bump
+    )]
+    pub pending_withdrawal: Account<'info, PendingErc20Withdrawal>,
+
+    #[account(
+        seeds = [b"vault_config"],
+        bump
+    )]
+    pub config: Account<'info, VaultConfig>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64, request_id: [u8; 32])]
+pub struct CancelWithdrawErc20<'info> {
+    #[account(
+        mut,
+        constraint = config.is_admin_signer(&executor.key()) @ ErrorCode::Unauthorized
+    )]
+    pub executor: Signer<'info>,
+
+    #[account(
+        seeds = [b"vault_config"],
+        bump
+    )]
+    pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"config_proposal",
+            proposal_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        has_one = proposer,
+        close = proposer
+    )]
+    pub proposal: Account<'info, ConfigProposal>,
+
+    /// CHECK: Receives the proposal rent, checked against the proposal
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"global_vault_authority"],
+        bump
+    )]
+    /// CHECK: This is a PDA that will be used as a signer
+    pub requester: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"pending_erc20_withdrawal",
+            &request_id
+        ],
+        bump
+    )]
+    pub pending_withdrawal: Account<'info, PendingErc20Withdrawal>,
+
+    #[account(
+        seeds = [b"chain_registry"],
+        bump
+    )]
+    pub chain_registry: Account<'info, ChainRegistry>,
+
+    #[account(mut)]
+    pub fee_payer: Option<Signer<'info>>,
+
+    /// CHECK: Chain signatures state
+    #[account(
+        mut,
+        seeds = [CHAIN_SIGNATURES_STATE_SEED],
+        bump,
+        seeds::program = chain_signatures_program.key()
+    )]
+    pub chain_signatures_state: AccountInfo<'info>,
+
+    /// CHECK: Event authority for CPI events, PDA with seed "__event_authority"
+    #[account(
+        seeds = [b"__event_authority"],
+        bump,
+        seeds::program = chain_signatures_program.key()
+    )]
+    pub event_authority: AccountInfo<'info>,
+
+    pub chain_signatures_program: Program<'info, ::chain_signatures::program::ChainSignatures>,
+    pub system_program: Program<'info, System>,
+    pub instructions: Option<AccountInfo<'info>>,
+}
+
+#[derive(Accounts)]
+#[instruction(erc20_address: [u8; 20])]
+pub struct MigrateErc20Balance<'info> {
+    #[account(mut)]
+    pub requester: Signer<'info>,
+
+    /// Balance account from before balances were keyed by chain
+    #[account(
+        mut,
+        seeds = [
+            b"user_erc20_balance",
+            requester.key().as_ref(),
+            &erc20_address
+        ],
+        bump,
+        close = requester
+    )]
+    pub legacy_balance: Account<'info, UserErc20Balance>,
+
+    #[account(
+        init_if_needed,
+        payer = requester,
+        space = 8 + UserErc20Balance::INIT_SPACE,
+        seeds = [
+            b"user_erc20_balance",
+            requester.key().as_ref(),
+            config.legacy_erc20_chain_id.to_le_bytes().as_ref(),
+            &erc20_address
+        ],
+        bump
+    )]
+    pub user_balance: Account<'info, UserErc20Balance>,
+
+    #[account(
+        seeds = [b"vault_config"],
+        bump
+    )]
+    pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"token_registry",
+            config.legacy_erc20_chain_id.to_le_bytes().as_ref(),
+            erc20_address.as_ref()
+        ],
+        bump
+    )]
+    pub token_registry: Account<'info, TokenRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(chain_id: u64, erc20_address: [u8; 20])]
+pub struct CollectErc20Fees<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"vault_config"],
+        bump
+    )]
+    pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"token_registry",
+            chain_id.to_le_bytes().as_ref(),
+            erc20_address.as_ref()
+        ],
+        bump
+    )]
+    pub token_registry: Account<'info, TokenRegistry>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + UserErc20Balance::INIT_SPACE,
+        seeds = [
+            b"user_erc20_balance",
+            config.treasury.as_ref(),
+            chain_id.to_le_bytes().as_ref(),
+            &erc20_address
+        ],
+        bump
+    )]
+    pub treasury_balance: Account<'info, UserErc20Balance>,
+
+    pub system_program: Program<'info, System>,
 }
@@ -0,0 +1,394 @@
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ProposeConfigChange<'info> {
+    #[account(
+        mut,
+        constraint = config.is_admin_signer(&proposer.key()) @ ErrorCode::Unauthorized
+    )]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"vault_config"],
+        bump
+    )]
+    pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + ConfigProposal::INIT_SPACE,
+        seeds = [
+            b"config_proposal",
+            config.proposal_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub proposal: Account<'info, ConfigProposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct ApproveConfigChange<'info> {
+    #[account(
+        constraint = config.is_admin_signer(&signer.key()) @ ErrorCode::Unauthorized
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [b"vault_config"],
+        bump
+    )]
+    pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"config_proposal",
+            proposal_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub proposal: Account<'info, ConfigProposal>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct ExecuteConfigChange<'info> {
+    #[account(
+        constraint = config.is_admin_signer(&executor.key()) @ ErrorCode::Unauthorized
+    )]
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"vault_config"],
+        bump
+    )]
+    pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"config_proposal",
+            proposal_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        has_one = proposer,
+        close = proposer
+    )]
+    pub proposal: Account<'info, ConfigProposal>,
+
+    /// CHECK: Receives the proposal rent, checked against the proposal
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct CancelConfigChange<'info> {
+    /// Only the pause guardian; a single admin signer must not be able to veto a
+    /// proposal the multisig approved
+    #[account(
+        constraint = authority.key() == config.pause_guardian @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"vault_config"],
+        bump
+    )]
+    pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"config_proposal",
+            proposal_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        has_one = proposer,
+        close = proposer
+    )]
+    pub proposal: Account<'info, ConfigProposal>,
+
+    /// CHECK: Receives the proposal rent, checked against the proposal
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64, chain_id: u64, erc20_address: [u8; 20])]
+pub struct ExecuteTokenChange<'info> {
+    #[account(
+        mut,
+        constraint = config.is_admin_signer(&executor.key()) @ ErrorCode::Unauthorized
+    )]
+    pub executor: Signer<'info>,
+
+    #[account(
+        seeds = [b"vault_config"],
+        bump
+    )]
+    pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"config_proposal",
+            proposal_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        has_one = proposer,
+        close = proposer
+    )]
+    pub proposal: Account<'info, ConfigProposal>,
+
+    /// CHECK: Receives the proposal rent, checked against the proposal
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = 8 + TokenRegistry::INIT_SPACE,
+        seeds = [
+            b"token_registry",
+            chain_id.to_le_bytes().as_ref(),
+            erc20_address.as_ref()
+        ],
+        bump
+    )]
+    pub token_registry: Account<'info, TokenRegistry>,
+
+    #[account(
+        mut,
+        seeds = [b"chain_registry"],
+        bump
+    )]
+    pub chain_registry: Account<'info, ChainRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct ExecuteChainChange<'info> {
+    #[account(
+        mut,
+        constraint = config.is_admin_signer(&executor.key()) @ ErrorCode::Unauthorized
+    )]
+    pub executor: Signer<'info>,
+
+    #[account(
+        seeds = [b"vault_config"],
+        bump
+    )]
+    pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"config_proposal",
+            proposal_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        has_one = proposer,
+        close = proposer
+    )]
+    pub proposal: Account<'info, ConfigProposal>,
+
+    /// CHECK: Receives the proposal rent, checked against the proposal
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = 8 + ChainRegistry::INIT_SPACE,
+        seeds = [b"chain_registry"],
+        bump
+    )]
+    pub chain_registry: Account<'info, ChainRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64, chain_id: u64, key_version: u32)]
+pub struct ExecuteEvmNonceChange<'info> {
+    #[account(
+        mut,
+        constraint = config.is_admin_signer(&executor.key()) @ ErrorCode::Unauthorized
+    )]
+    pub executor: Signer<'info>,
+
+    #[account(
+        seeds = [b"vault_config"],
+        bump
+    )]
+    pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"config_proposal",
+            proposal_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        has_one = proposer,
+        close = proposer
+    )]
+    pub proposal: Account<'info, ConfigProposal>,
+
+    /// CHECK: Receives the proposal rent, checked against the proposal
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = 8 + EvmVaultNonce::INIT_SPACE,
+        seeds = [
+            b"evm_vault_nonce",
+            chain_id.to_le_bytes().as_ref(),
+            key_version.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub vault_nonce: Account<'info, EvmVaultNonce>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64, request_id: [u8; 32], tx_params: EvmTransactionParams)]
+pub struct ExecuteEvmNativeTransfer<'info> {
+    #[account(
+        mut,
+        constraint = config.is_admin_signer(&executor.key()) @ ErrorCode::Unauthorized
+    )]
+    pub executor: Signer<'info>,
+
+    #[account(
+        seeds = [b"vault_config"],
+        bump
+    )]
+    pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"config_proposal",
+            proposal_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        has_one = proposer,
+        close = proposer
+    )]
+    pub proposal: Account<'info, ConfigProposal>,
+
+    /// CHECK: Receives the proposal rent, checked against the proposal
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"global_vault_authority"],
+        bump
+    )]
+    /// CHECK: This is a PDA that will be used as a signer
+    pub requester: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = 8 + EvmVaultNonce::INIT_SPACE,
+        seeds = [
+            b"evm_vault_nonce",
+            tx_params.chain_id.to_le_bytes().as_ref(),
+            config.active_key_version.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub vault_nonce: Account<'info, EvmVaultNonce>,
+
+    #[account(
+        seeds = [b"chain_registry"],
+        bump
+    )]
+    pub chain_registry: Account<'info, ChainRegistry>,
+
+    #[account(mut)]
+    pub fee_payer: Option<Signer<'info>>,
+
+    /// CHECK: Chain signatures state
+    #[account(
+        mut,
+        seeds = [CHAIN_SIGNATURES_STATE_SEED],
+        bump,
+        seeds::program = chain_signatures_program.key()
+    )]
+    pub chain_signatures_state: AccountInfo<'info>,
+
+    /// CHECK: Event authority for CPI events, PDA with seed "__event_authority"
+    #[account(
+        seeds = [b"__event_authority"],
+        bump,
+        seeds::program = chain_signatures_program.key()
+    )]
+    pub event_authority: AccountInfo<'info>,
+
+    pub chain_signatures_program: Program<'info, ::chain_signatures::program::ChainSignatures>,
+    pub system_program: Program<'info, System>,
+    pub instructions: Option<AccountInfo<'info>>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64, caip2_id: String)]
+pub struct ExecuteUtxoChange<'info> {
+    #[account(
+        mut,
+        constraint = config.is_admin_signer(&executor.key()) @ ErrorCode::Unauthorized
+    )]
+    pub executor: Signer<'info>,
+
+    #[account(
+        seeds = [b"vault_config"],
+        bump
+    )]
+    pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"config_proposal",
+            proposal_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        has_one = proposer,
+        close = proposer
+    )]
+    pub proposal: Account<'info, ConfigProposal>,
+
+    /// CHECK: Receives the proposal rent, checked against the proposal
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = 8 + VaultUtxoSet::INIT_SPACE,
+        seeds = [VAULT_UTXO_SET_SEED, &caip2_id_seed(&caip2_id)],
+        bump
+    )]
+    pub vault_utxo_set: Account<'info, VaultUtxoSet>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -1,7 +1,9 @@
 pub mod btc;
 pub mod config;
 pub mod erc20;
+pub mod governance;
 
 pub use btc::*;
 pub use config::*;
 pub use erc20::*;
+pub use governance::*;
@@ -37,9 +37,11 @@
 //! - https://ethresear.ch/t/you-can-kinda-abuse-ecrecover-to-do-ecmul-in-secp256k1-today/2384
 
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
 use anchor_lang::solana_program::keccak;
 use anchor_lang::solana_program::secp256k1_recover::secp256k1_recover;
 use libsecp256k1::curve::{Affine, Field, Jacobian, Scalar, AFFINE_G};
+use ripemd::{Digest, Ripemd160};
 
 /// Chain ID for Solana (CAIP-2 format)
 const SOLANA_CAIP2_ID: &str = "solana:5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp";
@@ -47,6 +49,21 @@ const SOLANA_CAIP2_ID: &str = "solana:5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp";
 /// Path used for respond bidirectional signatures (must match fakenet-signer's CONFIG.SOLANA_RESPOND_BIDIRECTIONAL_PATH)
 const RESPOND_BIDIRECTIONAL_PATH: &str = "solana response key";
 
+/// Path of the vault key that holds deposited funds and signs withdrawals
+const VAULT_ROOT_PATH: &str = "root";
+
+/// secp256k1 curve order n, big-endian
+const SECP256K1_ORDER: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe,
+    0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c, 0xd0, 0x36, 0x41, 0x41,
+];
+
+/// floor(n / 2), the largest `s` accepted in low-s form, big-endian
+const SECP256K1_HALF_ORDER: [u8; 32] = [
+    0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0x5d, 0x57, 0x6e, 0x73, 0x57, 0xa4, 0x50, 0x1d, 0xdf, 0xe9, 0x2f, 0x46, 0x68, 0x1b, 0x20, 0xa0,
+];
+
 /// Derives the epsilon scalar from the derivation path.
 ///
 /// Formula: epsilon = keccak256("sig.network v2.0.0 epsilon derivation:{chainId}:{predecessorId}:{path}")
@@ -133,7 +150,22 @@ fn point_add(p1: &Affine, p2: &Affine) -> Affine {
     result
 }
 
-/// Derives the Ethereum address from the MPC root public key and derivation parameters.
+/// Returns true if the 64-byte uncompressed key (without 0x04 prefix) is a point on secp256k1.
+pub fn is_valid_public_key(public_key: &[u8; 64]) -> bool {
+    let mut x = Field::default();
+    let mut y = Field::default();
+    if !x.set_b32(&public_key[..32].try_into().unwrap())
+        || !y.set_b32(&public_key[32..].try_into().unwrap())
+    {
+        return false;
+    }
+
+    let mut point = Affine::default();
+    point.set_xy(&x, &y);
+    point.is_valid_var()
+}
+
+/// Derives the child public key from the MPC root public key and derivation parameters.
 ///
 /// # Arguments
 /// * `mpc_root_public_key` - The 64-byte uncompressed secp256k1 public key (without 0x04 prefix)
@@ -141,12 +173,12 @@ fn point_add(p1: &Affine, p2: &Affine) -> Affine {
 /// * `path` - The derivation path (user's pubkey for deposits, "root" for withdrawals)
 ///
 /// # Returns
-/// The 20-byte Ethereum address
-pub fn derive_ethereum_address(
+/// The 64-byte uncompressed child public key (without 0x04 prefix)
+pub fn derive_child_public_key(
     mpc_root_public_key: &[u8; 64],
     predecessor_id: &str,
     path: &str,
-) -> Result<[u8; 20]> {
+) -> Result<[u8; 64]> {
     // Step 1: Compute epsilon = keccak256(derivation_path)
     let epsilon = derive_epsilon(predecessor_id, path);
 
@@ -165,7 +197,6 @@ pub fn derive_ethereum_address(
     // Step 4: Compute childPublicKey = basePublicKey + (epsilon × G)
     let child_point = point_add(&base_point, &epsilon_g);
 
-    // Step 5: Compute Ethereum address = keccak256(childPublicKey)[12..32]
     let mut x_bytes = [0u8; 32];
     let mut y_bytes = [0u8; 32];
     child_point.x.fill_b32(&mut x_bytes);
@@ -175,6 +206,20 @@ pub fn derive_ethereum_address(
     pubkey_bytes[..32].copy_from_slice(&x_bytes);
     pubkey_bytes[32..].copy_from_slice(&y_bytes);
 
+    Ok(pubkey_bytes)
+}
+
+/// Derives the Ethereum address from the MPC root public key and derivation parameters.
+///
+/// Takes the same arguments as [`derive_child_public_key`] and returns the 20-byte address.
+pub fn derive_ethereum_address(
+    mpc_root_public_key: &[u8; 64],
+    predecessor_id: &str,
+    path: &str,
+) -> Result<[u8; 20]> {
+    let pubkey_bytes = derive_child_public_key(mpc_root_public_key, predecessor_id, path)?;
+
+    // Ethereum address = keccak256(childPublicKey)[12..32]
     let pubkey_hash = keccak::hash(&pubkey_bytes).to_bytes();
 
     let mut address = [0u8; 20];
@@ -197,7 +242,11 @@ pub fn derive_deposit_expected_address(
 
     let predecessor_id = vault_authority.to_string();
 
-    derive_ethereum_address(mpc_root_public_key, &predecessor_id, RESPOND_BIDIRECTIONAL_PATH)
+    derive_ethereum_address(
+        mpc_root_public_key,
+        &predecessor_id,
+        RESPOND_BIDIRECTIONAL_PATH,
+    )
 }
 
 /// Derives the expected Ethereum address for a withdrawal completion (respond bidirectional).
@@ -211,7 +260,132 @@ pub fn derive_withdrawal_expected_address(mpc_root_public_key: &[u8; 64]) -> Res
 
     let predecessor_id = global_vault_authority.to_string();
 
-    derive_ethereum_address(mpc_root_public_key, &predecessor_id, RESPOND_BIDIRECTIONAL_PATH)
+    derive_ethereum_address(
+        mpc_root_public_key,
+        &predecessor_id,
+        RESPOND_BIDIRECTIONAL_PATH,
+    )
+}
+
+/// Derives the vault's Ethereum address.
+///
+/// The vault key is derived from the global vault authority PDA using the "root" path. It
+/// receives ERC20 deposits and signs withdrawals.
+pub fn derive_vault_ethereum_address(mpc_root_public_key: &[u8; 64]) -> Result<[u8; 20]> {
+    let (global_vault_authority, _bump) =
+        Pubkey::find_program_address(&[b"global_vault_authority"], &crate::ID);
+
+    let predecessor_id = global_vault_authority.to_string();
+
+    derive_ethereum_address(mpc_root_public_key, &predecessor_id, VAULT_ROOT_PATH)
+}
+
+/// Derives the vault's compressed Bitcoin public key from the same "root" key that holds
+/// the vault's EVM funds.
+pub fn derive_vault_bitcoin_public_key(mpc_root_public_key: &[u8; 64]) -> Result<[u8; 33]> {
+    let (global_vault_authority, _bump) =
+        Pubkey::find_program_address(&[b"global_vault_authority"], &crate::ID);
+
+    let predecessor_id = global_vault_authority.to_string();
+    let pubkey_bytes =
+        derive_child_public_key(mpc_root_public_key, &predecessor_id, VAULT_ROOT_PATH)?;
+
+    Ok(compress_public_key(&pubkey_bytes))
+}
+
+/// Derives the vault's P2WPKH script_pubkey (`OP_0 <HASH160(compressed pubkey)>`).
+pub fn derive_vault_script_pubkey(mpc_root_public_key: &[u8; 64]) -> Result<[u8; 22]> {
+    let compressed = derive_vault_bitcoin_public_key(mpc_root_public_key)?;
+
+    Ok(p2wpkh_script_pubkey(&compressed))
+}
+
+/// Derives the P2WPKH script_pubkey of a user's deposit address, which holds the UTXOs they
+/// deposit from and receives deposit change.
+pub fn derive_deposit_script_pubkey(
+    mpc_root_public_key: &[u8; 64],
+    user_pubkey: &Pubkey,
+) -> Result<[u8; 22]> {
+    let (vault_authority, _bump) =
+        Pubkey::find_program_address(&[b"vault_authority", user_pubkey.as_ref()], &crate::ID);
+
+    let predecessor_id = vault_authority.to_string();
+    let pubkey_bytes = derive_child_public_key(
+        mpc_root_public_key,
+        &predecessor_id,
+        &user_pubkey.to_string(),
+    )?;
+
+    Ok(p2wpkh_script_pubkey(&compress_public_key(&pubkey_bytes)))
+}
+
+/// Compresses a 64-byte uncompressed public key (without 0x04 prefix) to SEC1 form.
+pub fn compress_public_key(public_key: &[u8; 64]) -> [u8; 33] {
+    let mut compressed = [0u8; 33];
+    compressed[0] = if public_key[63] & 1 == 0 { 0x02 } else { 0x03 };
+    compressed[1..].copy_from_slice(&public_key[..32]);
+    compressed
+}
+
+/// Builds the P2WPKH script_pubkey for a compressed public key.
+pub fn p2wpkh_script_pubkey(compressed_public_key: &[u8; 33]) -> [u8; 22] {
+    let sha = hash(compressed_public_key).to_bytes();
+    let pubkey_hash = Ripemd160::digest(sha);
+
+    let mut script = [0u8; 22];
+    script[0] = 0x00; // OP_0 (witness version 0)
+    script[1] = 0x14; // push 20 bytes
+    script[2..].copy_from_slice(&pubkey_hash);
+    script
+}
+
+/// Whether `r` and `s` are in `[1, n)` and `s` is in low form (`s <= n / 2`).
+///
+/// Both `(r, s)` and `(r, n - s)` verify for the same message and key, so only the low-s
+/// form is accepted. Big-endian byte arrays compare in numeric order.
+pub fn is_canonical_signature(r: &[u8; 32], s: &[u8; 32]) -> bool {
+    let zero = [0u8; 32];
+
+    *r != zero && *r < SECP256K1_ORDER && *s != zero && *s <= SECP256K1_HALF_ORDER
+}
+
+/// Verifies an MPC response signature over `message_hash` against the Ethereum address of
+/// the expected signer.
+///
+/// Recovery IDs 2 and 3 only occur when R.x ≥ n, which honest signers practically never
+/// produce, and are rejected unless `allow_high_recovery_ids` is set.
+pub fn verify_signature(
+    message_hash: &[u8; 32],
+    signature: &chain_signatures::Signature,
+    expected_address: &[u8; 20],
+    allow_high_recovery_ids: bool,
+) -> Result<()> {
+    let max_recovery_id = if allow_high_recovery_ids { 3 } else { 1 };
+    require!(
+        signature.recovery_id <= max_recovery_id,
+        crate::error::ErrorCode::InvalidRecoveryId
+    );
+    require!(
+        is_canonical_signature(&signature.big_r.x, &signature.s),
+        crate::error::ErrorCode::NonCanonicalSignature
+    );
+
+    let mut sig_bytes = [0u8; 64];
+    sig_bytes[..32].copy_from_slice(&signature.big_r.x);
+    sig_bytes[32..].copy_from_slice(&signature.s);
+
+    // The recovered key is 64 bytes (without the 0x04 prefix)
+    let recovered_pubkey = secp256k1_recover(message_hash, signature.recovery_id, &sig_bytes)
+        .map_err(|_| crate::error::ErrorCode::InvalidSignature)?;
+
+    // Ethereum address = keccak256(publicKey)[12..32]
+    let pubkey_hash = keccak::hash(&recovered_pubkey.to_bytes()).to_bytes();
+    require!(
+        pubkey_hash[12..] == expected_address[..],
+        crate::error::ErrorCode::InvalidSignature
+    );
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -223,4 +397,107 @@ mod tests {
         let epsilon = derive_epsilon("TestPredecessor", "test/path");
         assert_eq!(epsilon.len(), 32);
     }
+
+    #[test]
+    fn test_is_valid_public_key() {
+        let mut generator = [0u8; 64];
+        AFFINE_G
+            .x
+            .fill_b32((&mut generator[..32]).try_into().unwrap());
+        AFFINE_G
+            .y
+            .fill_b32((&mut generator[32..]).try_into().unwrap());
+        assert!(is_valid_public_key(&generator));
+
+        generator[63] ^= 1;
+        assert!(!is_valid_public_key(&generator));
+        assert!(!is_valid_public_key(&[0u8; 64]));
+    }
+
+    #[test]
+    fn test_p2wpkh_script_pubkey() {
+        // BIP-173 test vector: the generator point's P2WPKH script
+        let mut generator = [0u8; 64];
+        AFFINE_G
+            .x
+            .fill_b32((&mut generator[..32]).try_into().unwrap());
+        AFFINE_G
+            .y
+            .fill_b32((&mut generator[32..]).try_into().unwrap());
+
+        let compressed = compress_public_key(&generator);
+        assert_eq!(
+            hex::encode(compressed),
+            "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"
+        );
+        assert_eq!(
+            hex::encode(p2wpkh_script_pubkey(&compressed)),
+            "0014751e76e8199196d454941c45d1b3a323f1433bd6"
+        );
+    }
+
+    #[test]
+    fn test_is_canonical_signature() {
+        let one = {
+            let mut one = [0u8; 32];
+            one[31] = 1;
+            one
+        };
+        let mut above_half_order = SECP256K1_HALF_ORDER;
+        above_half_order[31] += 1;
+        let mut below_order = SECP256K1_ORDER;
+        below_order[31] -= 1;
+
+        assert!(is_canonical_signature(&one, &one));
+        assert!(is_canonical_signature(&below_order, &SECP256K1_HALF_ORDER));
+
+        assert!(!is_canonical_signature(&one, &above_half_order));
+        assert!(!is_canonical_signature(&[0u8; 32], &one));
+        assert!(!is_canonical_signature(&one, &[0u8; 32]));
+        assert!(!is_canonical_signature(&SECP256K1_ORDER, &one));
+    }
+
+    #[test]
+    fn test_verify_signature_normalized_high_s() {
+        let scalar = |bytes: &[u8; 32]| {
+            let mut scalar = Scalar::default();
+            let _ = scalar.set_b32(bytes);
+            scalar
+        };
+        let secret_key = scalar(&[7u8; 32]);
+        let message_hash = keccak::hash(b"respond bidirectional").to_bytes();
+        let (r, s, recovery_id) = libsecp256k1::ECMULT_GEN_CONTEXT
+            .sign_raw(&secret_key, &scalar(&message_hash), &scalar(&[9u8; 32]))
+            .unwrap();
+
+        let public_key = scalar_mul_generator(&secret_key.b32()).unwrap();
+        let mut public_key_bytes = [0u8; 64];
+        public_key
+            .x
+            .fill_b32((&mut public_key_bytes[..32]).try_into().unwrap());
+        public_key
+            .y
+            .fill_b32((&mut public_key_bytes[32..]).try_into().unwrap());
+        let mut expected_address = [0u8; 20];
+        expected_address.copy_from_slice(&keccak::hash(&public_key_bytes).to_bytes()[12..]);
+
+        // (r, n - s) with the other recovery ID is the same signature in high-s form
+        let high_s = chain_signatures::Signature {
+            big_r: chain_signatures::AffinePoint {
+                x: r.b32(),
+                y: [0u8; 32],
+            },
+            s: (-s).b32(),
+            recovery_id: recovery_id ^ 1,
+        };
+        assert!(verify_signature(&message_hash, &high_s, &expected_address, false).is_err());
+
+        // What the relayers' normalizeResponseSignature submits instead
+        let normalized = chain_signatures::Signature {
+            s: (-scalar(&high_s.s)).b32(),
+            recovery_id: high_s.recovery_id ^ 1,
+            ..high_s
+        };
+        assert!(verify_signature(&message_hash, &normalized, &expected_address, false).is_ok());
+    }
 }
@@ -22,8 +22,130 @@ pub enum ErrorCode {
     InsufficientBalance,
     #[msg("Underflow error")]
     Underflow,
-    #[msg("No vault outputs found in transaction")]
-    VaultOutputNotFound,
     #[msg("Provided inputs do not cover requested amount + fee")]
     InsufficientInputs,
+    #[msg("Signer is not authorized to perform this action")]
+    Unauthorized,
+    #[msg("Public key is not a valid secp256k1 point")]
+    InvalidPublicKey,
+    #[msg("MPC key version is not registered")]
+    UnknownKeyVersion,
+    #[msg("MPC key version is already registered")]
+    KeyVersionAlreadyExists,
+    #[msg("Maximum number of MPC key versions reached")]
+    TooManyKeyVersions,
+    #[msg("Deposits are paused")]
+    DepositsPaused,
+    #[msg("Withdrawals are paused")]
+    WithdrawalsPaused,
+    #[msg("Maximum number of per-chain pause entries reached")]
+    TooManyPausedChains,
+    #[msg("CAIP-2 ID is malformed or too long")]
+    InvalidCaip2Id,
+    #[msg("Admin signer set is empty, too large or contains duplicates")]
+    InvalidAdminSigners,
+    #[msg("Admin threshold must be between 1 and the number of signers")]
+    InvalidAdminThreshold,
+    #[msg("Proposal already approved by this signer")]
+    AlreadyApproved,
+    #[msg("Proposal has not reached the approval threshold")]
+    ThresholdNotMet,
+    #[msg("Proposal timelock has not elapsed")]
+    TimelockNotElapsed,
+    #[msg("Timelock exceeds the maximum delay")]
+    InvalidTimelock,
+    #[msg("Proposal action must be executed with its dedicated instruction")]
+    InvalidProposalAction,
+    #[msg("Token is not enabled")]
+    TokenDisabled,
+    #[msg("Amount is below the token minimum")]
+    AmountBelowMinimum,
+    #[msg("Amount is above the token maximum")]
+    AmountAboveMaximum,
+    #[msg("Token minimum exceeds its maximum")]
+    InvalidTokenSettings,
+    #[msg("Chain is not in the chain registry")]
+    UnsupportedChain,
+    #[msg("Chain is disabled")]
+    ChainDisabled,
+    #[msg("Maximum number of registered chains reached")]
+    TooManyChains,
+    #[msg("Legacy balance migration is disabled")]
+    MigrationDisabled,
+    #[msg("Withdrawal exceeds the user's rate limit for this asset")]
+    UserRateLimitExceeded,
+    #[msg("Withdrawal exceeds the rate limit for this asset")]
+    AssetRateLimitExceeded,
+    #[msg("Withdrawal exceeds the global rate limit")]
+    GlobalRateLimitExceeded,
+    #[msg("Maximum number of rate limit overrides reached")]
+    TooManyRateLimitOverrides,
+    #[msg("Deposit would exceed the asset's deposit cap")]
+    DepositCapExceeded,
+    #[msg("Debit exceeds the asset's tracked liabilities")]
+    LiabilitiesUnderflow,
+    #[msg("Fee exceeds 10000 basis points")]
+    InvalidFee,
+    #[msg("Output is below the P2WPKH dust limit")]
+    DustOutput,
+    #[msg("Network fee exceeds the chain's bound")]
+    NetworkFeeTooHigh,
+    #[msg("Request ID has already been processed")]
+    RequestAlreadyProcessed,
+    #[msg("Signature r or s is out of range, or s is not in low form")]
+    NonCanonicalSignature,
+    #[msg("Signature recovery ID is not accepted")]
+    InvalidRecoveryId,
+    #[msg("Withdrawal cancellation is disabled")]
+    WithdrawalCancellationDisabled,
+    #[msg("Withdrawal has not timed out yet")]
+    WithdrawalNotTimedOut,
+    #[msg("Response does not report a failed transfer")]
+    DepositNotFailed,
+    #[msg("Transaction nonce does not match the vault nonce")]
+    NonceMismatch,
+    #[msg("Replacement fees must exceed those of the transaction it replaces")]
+    ReplacementUnderpriced,
+    #[msg("Maximum number of withdrawal replacements reached")]
+    TooManyReplacements,
+    #[msg("Response does not report a failed withdrawal")]
+    WithdrawalNotFailed,
+    #[msg("Another transaction of this withdrawal may still execute")]
+    WithdrawalOutcomePending,
+    #[msg("Gas limit exceeds the chain's bound")]
+    GasLimitTooHigh,
+    #[msg("Max fee per gas exceeds the chain's bound")]
+    MaxFeePerGasTooHigh,
+    #[msg("Priority fee exceeds the chain's bound or the max fee per gas")]
+    PriorityFeeTooHigh,
+    #[msg("ERC20 transactions must not carry native value")]
+    NativeValueNotAllowed,
+    #[msg("UTXO is not in the vault UTXO set")]
+    UtxoNotRegistered,
+    #[msg("UTXO is reserved by a pending withdrawal")]
+    UtxoReserved,
+    #[msg("UTXO is already in the vault UTXO set")]
+    DuplicateUtxo,
+    #[msg("Vault UTXO set is full")]
+    VaultUtxoSetFull,
+    #[msg("Vault UTXOs do not cover requested amount + fee")]
+    InsufficientVaultUtxos,
+    #[msg("Withdrawal needs more vault UTXOs than one transaction may spend")]
+    TooManyWithdrawalInputs,
+    #[msg("Fee rate is below the minimum relay fee rate")]
+    FeeRateTooLow,
+    #[msg("Chain still has liabilities or uncollected fees")]
+    ChainHasLiabilities,
+    #[msg("Withdrawal already has a signed cancellation")]
+    WithdrawalAlreadyCancelled,
+    #[msg("Lock time must be a block height")]
+    InvalidLockTime,
+    #[msg("Sweep fee exceeds the chain's accrued fees")]
+    InsufficientAccruedFees,
+    #[msg("Releasing stalled deposits is disabled")]
+    DepositReleaseDisabled,
+    #[msg("Deposit has not timed out yet")]
+    DepositNotTimedOut,
+    #[msg("Deposit reservation was already released")]
+    DepositAlreadyReleased,
 }
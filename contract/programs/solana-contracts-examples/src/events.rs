@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::state::ConfigAction;
+
+#[event]
+pub struct ConfigChangeProposed {
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub action: ConfigAction,
+}
+
+#[event]
+pub struct ConfigChangeQueued {
+    pub proposal_id: u64,
+    pub eta_slot: u64,
+}
+
+#[event]
+pub struct ConfigChangeCancelled {
+    pub proposal_id: u64,
+    pub cancelled_by: Pubkey,
+}
+
+#[event]
+pub struct ConfigChangeExecuted {
+    pub proposal_id: u64,
+    pub action: ConfigAction,
+}
+
+/// Native value sent from the EVM vault address, outside any ERC20 flow
+#[event]
+pub struct EvmNativeTransferRequested {
+    pub proposal_id: u64,
+    pub chain_id: u64,
+    pub recipient: [u8; 20],
+    pub value: u128,
+    pub nonce: u64,
+    pub request_id: [u8; 32],
+}
@@ -1,7 +1,5 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::keccak;
-use anchor_lang::solana_program::secp256k1_recover::secp256k1_recover;
-use borsh::BorshDeserialize;
 use chain_signatures::cpi::accounts::SignBidirectional;
 use chain_signatures::cpi::sign_bidirectional;
 
@@ -9,8 +7,18 @@ use signet_rs::bitcoin::psbt::Psbt;
 use signet_rs::bitcoin::types::*;
 use signet_rs::{TransactionBuilder, TxBuilder, BITCOIN};
 
-use crate::contexts::{ClaimBtc, CompleteWithdrawBtc, DepositBtc, WithdrawBtc};
-use crate::state::{BtcDepositParams, BtcInput, BtcOutput, BtcWithdrawParams};
+use crate::contexts::{
+    CancelWithdrawBtc, ClaimBtc, CollectBtcFees, CompleteSweepBtc, CompleteWithdrawBtc, DepositBtc,
+    FinalizeFailedDepositBtc, MigrateBtcBalance, RecordFailedWithdrawBtc, ReleaseStalledDepositBtc,
+    SweepBtc, WithdrawBtc,
+};
+use crate::events::ConfigChangeExecuted;
+use crate::instructions::governance::require_executable;
+use crate::state::{
+    transfer_failure_reason, BtcDepositParams, BtcInput, BtcOutpoint, BtcWithdrawParams,
+    ChainEntry, ConfigAction, Direction, PendingBtcWithdrawal, UserBtcBalance, VaultUtxo,
+    MIN_WITHDRAWAL_FEE_RATE, P2WPKH_DUST_LIMIT,
+};
 
 const HARDCODED_ROOT_PATH: &str = "root";
 
@@ -19,17 +27,43 @@ pub fn deposit_btc(
     request_id: [u8; 32],
     requester: Pubkey,
     inputs: Vec<BtcInput>,
-    outputs: Vec<BtcOutput>,
+    amount: u64,
     tx_params: BtcDepositParams,
 ) -> Result<()> {
     let path = requester.to_string();
-    // SECURITY: caller-provided outputs can redirect funds; the vault output should be hardcoded
-    // (e.g., to a program-derived script) so deposits always land in the contract-controlled vault.
+    let key_version = ctx.accounts.config.active_key_version;
     let BtcDepositParams {
         lock_time,
         caip2_id,
-        vault_script_pubkey,
+        fee,
     } = tx_params;
+    let max_network_fee = ctx
+        .accounts
+        .chain_registry
+        .require_enabled(&caip2_id)?
+        .settings
+        .max_deposit_network_fee;
+    ctx.accounts
+        .config
+        .require_not_paused(&caip2_id, Direction::Deposit)?;
+
+    require!(
+        fee <= max_network_fee,
+        crate::error::ErrorCode::NetworkFeeTooHigh
+    );
+    require!(
+        amount >= P2WPKH_DUST_LIMIT,
+        crate::error::ErrorCode::DustOutput
+    );
+
+    // The outputs are fixed by the program: `amount` to the vault and any change back
+    // to the requester's deposit address, so the signature can't spend elsewhere
+    let root_public_key = ctx.accounts.config.root_public_key(key_version)?;
+    let vault_script =
+        ScriptBuf::from_bytes(crate::crypto::derive_vault_script_pubkey(root_public_key)?.to_vec());
+    let change_script = ScriptBuf::from_bytes(
+        crate::crypto::derive_deposit_script_pubkey(root_public_key, &requester)?.to_vec(),
+    );
 
     // Build Bitcoin transaction inputs
     let mut btc_inputs = Vec::new();
@@ -51,34 +85,34 @@ pub fn deposit_btc(
             .ok_or(crate::error::ErrorCode::Overflow)?;
     }
 
-    // Build Bitcoin transaction outputs
-    let mut btc_outputs = Vec::new();
-    let mut total_output_value = 0u64;
-    let mut vault_output_value = 0u64;
-
-    for output in &outputs {
-        let script_pubkey = ScriptBuf::from_bytes(output.script_pubkey.clone());
-
-        let txout = TxOut {
-            value: Amount::from_sat(output.value),
-            script_pubkey,
-        };
+    let total_spend = amount
+        .checked_add(fee)
+        .ok_or(crate::error::ErrorCode::Overflow)?;
+    let change_output_value = total_input_value
+        .checked_sub(total_spend)
+        .ok_or(crate::error::ErrorCode::InsufficientInputs)?;
+    // Dust change would make the transaction non-standard; leave it to the fee instead
+    require!(
+        change_output_value == 0 || change_output_value >= P2WPKH_DUST_LIMIT,
+        crate::error::ErrorCode::DustOutput
+    );
 
-        btc_outputs.push(txout);
-        total_output_value = total_output_value
-            .checked_add(output.value)
-            .ok_or(crate::error::ErrorCode::Overflow)?;
+    let mut btc_outputs = Vec::new();
+    btc_outputs.push(TxOut {
+        value: Amount::from_sat(amount),
+        script_pubkey: vault_script,
+    });
 
-        if output.script_pubkey.as_slice() == vault_script_pubkey.as_slice() {
-            vault_output_value = vault_output_value
-                .checked_add(output.value)
-                .ok_or(crate::error::ErrorCode::Overflow)?;
-        }
+    if change_output_value > 0 {
+        btc_outputs.push(TxOut {
+            value: Amount::from_sat(change_output_value),
+            script_pubkey: change_script,
+        });
     }
 
     // Build unsigned Bitcoin transaction (SegWit - Version::Two)
     let lock_time =
-        LockTime::from_height(lock_time).map_err(|_| crate::error::ErrorCode::InvalidAddress)?;
+        LockTime::from_height(lock_time).map_err(|_| crate::error::ErrorCode::InvalidLockTime)?;
 
     let tx = TransactionBuilder::new::<BITCOIN>()
         .version(Version::Two)
@@ -109,7 +143,7 @@ pub fn deposit_btc(
         &ctx.accounts.requester_pda.key(),
         &txid_explorer_reversed_bytes,
         &caip2_id,
-        1,
+        key_version,
         &path,
         "ECDSA",
         "bitcoin",
@@ -121,25 +155,27 @@ pub fn deposit_btc(
         crate::error::ErrorCode::InvalidRequestId
     );
 
-    require!(
-        vault_output_value > 0,
-        crate::error::ErrorCode::VaultOutputNotFound
-    );
+    let chain = ctx.accounts.chain_registry.require_enabled_mut(&caip2_id)?;
+    chain
+        .liabilities
+        .reserve_deposit(amount as u128, chain.settings.deposit_cap)?;
+    // The fee never exceeds the amount it is charged on, so it fits in a u64
+    let protocol_fee = chain.settings.fees.deposit_fee(amount as u128)? as u64;
 
     // Store pending deposit info
     let pending = &mut ctx.accounts.pending_deposit;
     pending.requester = requester;
-    pending.amount = vault_output_value;
+    pending.amount = amount;
+    pending.caip2_id = caip2_id.clone();
     pending.path = path.clone();
     pending.request_id = request_id;
+    pending.key_version = key_version;
+    pending.protocol_fee = protocol_fee;
+    pending.rent_payer = ctx.accounts.payer.key();
+    pending.txid = txid_explorer_reversed_bytes;
+    pending.created_slot = Clock::get()?.slot;
+    pending.reservation_released = false;
 
-    // Create callback schema for boolean result
-    let callback_schema = serde_json::to_vec(&serde_json::json!("bool"))
-        .map_err(|_| crate::error::ErrorCode::SerializationError)?;
-
-    let explorer_schema = callback_schema.clone();
-
-    // CPI to sign_bidirectional
     let requester_key_bytes = requester.to_bytes();
     let requester_bump = ctx.bumps.requester_pda;
     let signer_seeds: &[&[&[u8]]] = &[&[
@@ -148,41 +184,12 @@ pub fn deposit_btc(
         &[requester_bump],
     ]];
 
-    let cpi_ctx = CpiContext::new_with_signer(
-        ctx.accounts.chain_signatures_program.to_account_info(),
-        SignBidirectional {
-            program_state: ctx.accounts.chain_signatures_state.to_account_info(),
-            requester: ctx.accounts.requester_pda.to_account_info(),
-            fee_payer: ctx
-                .accounts
-                .fee_payer
-                .as_ref()
-                .map(|fp| fp.to_account_info()),
-            system_program: ctx.accounts.system_program.to_account_info(),
-            instructions: ctx
-                .accounts
-                .instructions
-                .as_ref()
-                .map(|i| i.to_account_info()),
-            event_authority: ctx.accounts.event_authority.to_account_info(),
-            program: ctx.accounts.chain_signatures_program.to_account_info(),
-        },
-        signer_seeds,
-    );
-
-    // Send PSBT to Chain Signatures for signing (needs metadata!)
-    sign_bidirectional(
-        cpi_ctx,
+    request_btc_signature(
+        sign_bidirectional_cpi!(ctx.accounts, requester_pda, signer_seeds),
         psbt_bytes,
         caip2_id,
-        1,
+        key_version,
         path,
-        "ECDSA".to_string(),
-        "bitcoin".to_string(),
-        "".to_string(),
-        crate::ID,
-        explorer_schema,
-        callback_schema,
     )?;
 
     msg!("BTC deposit initiated with request_id: {:?}", request_id);
@@ -201,156 +208,272 @@ pub fn claim_btc(
 
     // Derive the expected address on-chain from MPC root public key + user's derivation path
     // Formula: childPubKey = basePubKey + (epsilon × G), where epsilon = keccak256(derivation_path)
+    // The key version recorded at deposit time is used so requests survive a key rotation.
     let expected_address_bytes = crate::crypto::derive_deposit_expected_address(
-        &config.mpc_root_public_key,
+        config.root_public_key(pending.key_version)?,
         &pending.requester,
     )?;
 
     // Verify signature against the derived expected address
     let message_hash = hash_message(&request_id, &serialized_output);
-    let expected_address_str = format!("0x{}", hex::encode(expected_address_bytes));
-    verify_signature_from_address(&message_hash, &signature, &expected_address_str)?;
+    crate::crypto::verify_signature(
+        &message_hash,
+        &signature,
+        &expected_address_bytes,
+        config.allow_high_recovery_ids,
+    )?;
 
-    // Deserialize result as boolean
-    let success: bool = BorshDeserialize::try_from_slice(&serialized_output)
-        .map_err(|_| crate::error::ErrorCode::InvalidOutput)?;
+    // Failed deposits are closed with finalize_failed_deposit_btc instead
+    require!(
+        transfer_failure_reason(&serialized_output)?.is_none(),
+        crate::error::ErrorCode::TransferFailed
+    );
 
-    require!(success, crate::error::ErrorCode::TransferFailed);
+    // A chain with pending deposits can't be removed, so a missing entry means the
+    // deposit predates the registry or its reservation was released: no protocol
+    // fee is taken and the balance is added to the liabilities later with
+    // CreditChainLiabilities
+    let protocol_fee = match ctx.accounts.chain_registry.get_mut(&pending.caip2_id) {
+        Some(chain) => {
+            if pending.reservation_released {
+                chain.liabilities.credit(pending.amount as u128)?;
+            } else {
+                chain.liabilities.settle_deposit(pending.amount as u128)?;
+            }
+            chain.accrued_fees = chain
+                .accrued_fees
+                .checked_add(pending.protocol_fee as u128)
+                .ok_or(crate::error::ErrorCode::Overflow)?;
+            pending.protocol_fee
+        }
+        None => 0,
+    };
 
-    // Update user balance
+    // Update user balance, net of the protocol fee
+    let credited_amount = pending
+        .amount
+        .checked_sub(protocol_fee)
+        .ok_or(crate::error::ErrorCode::Underflow)?;
     let balance = &mut ctx.accounts.user_balance;
     balance.amount = balance
         .amount
-        .checked_add(pending.amount)
+        .checked_add(credited_amount)
         .ok_or(crate::error::ErrorCode::Overflow)?;
 
+    // The deposit's vault output becomes spendable by withdrawals. A full set fails
+    // the claim, which can be retried once a sweep frees slots.
+    let utxo_set = &mut ctx.accounts.vault_utxo_set;
+    utxo_set.caip2_id = pending.caip2_id.clone();
+    let outpoint = BtcOutpoint {
+        txid: pending.txid,
+        vout: 0,
+    };
+    utxo_set.add(outpoint, pending.amount, pending.key_version)?;
+
+    ctx.accounts.config.pay_settler(
+        &ctx.accounts.pending_deposit.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+    )?;
+
     msg!("BTC deposit claimed successfully");
 
     Ok(())
 }
 
+/// Closes a deposit the MPC network reported as failed and records a receipt with the reason.
+pub fn finalize_failed_deposit_btc(
+    ctx: Context<FinalizeFailedDepositBtc>,
+    request_id: [u8; 32],
+    serialized_output: Vec<u8>,
+    signature: chain_signatures::Signature,
+) -> Result<()> {
+    let pending = &ctx.accounts.pending_deposit;
+    let config = &ctx.accounts.config;
+
+    let expected_address_bytes = crate::crypto::derive_deposit_expected_address(
+        config.root_public_key(pending.key_version)?,
+        &pending.requester,
+    )?;
+
+    let message_hash = hash_message(&request_id, &serialized_output);
+    crate::crypto::verify_signature(
+        &message_hash,
+        &signature,
+        &expected_address_bytes,
+        config.allow_high_recovery_ids,
+    )?;
+
+    let reason = transfer_failure_reason(&serialized_output)?
+        .ok_or(crate::error::ErrorCode::DepositNotFailed)?;
+
+    if let Some(chain) = ctx.accounts.chain_registry.get_mut(&pending.caip2_id) {
+        if !pending.reservation_released {
+            chain.liabilities.release_deposit(pending.amount as u128);
+        }
+    }
+
+    let receipt = &mut ctx.accounts.failed_deposit;
+    receipt.requester = pending.requester;
+    receipt.amount = pending.amount;
+    receipt.caip2_id = pending.caip2_id.clone();
+    receipt.reason = reason;
+    receipt.failed_slot = Clock::get()?.slot;
+
+    ctx.accounts.config.pay_settler(
+        &ctx.accounts.pending_deposit.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+    )?;
+
+    msg!("BTC deposit failed: {}", receipt.reason);
+
+    Ok(())
+}
+
+/// Releases the deposit cap reservation of a deposit that got no response within
+/// `deposit_timeout_slots`. The deposit stays pending and can still be claimed or
+/// finalized; a late claim credits the chain's liabilities even past its cap.
+pub fn release_stalled_deposit_btc(
+    ctx: Context<ReleaseStalledDepositBtc>,
+    _request_id: [u8; 32],
+) -> Result<()> {
+    let pending = &mut ctx.accounts.pending_deposit;
+    ctx.accounts
+        .config
+        .require_deposit_timed_out(pending.created_slot)?;
+    require!(
+        !pending.reservation_released,
+        crate::error::ErrorCode::DepositAlreadyReleased
+    );
+
+    if let Some(chain) = ctx.accounts.chain_registry.get_mut(&pending.caip2_id) {
+        chain.liabilities.release_deposit(pending.amount as u128);
+    }
+    pending.reservation_released = true;
+
+    msg!("Released the reservation of a stalled BTC deposit");
+
+    Ok(())
+}
+
 pub fn withdraw_btc(
     ctx: Context<WithdrawBtc>,
     request_id: [u8; 32],
-    inputs: Vec<BtcInput>,
     amount: u64,
     recipient_address: String,
     tx_params: BtcWithdrawParams,
 ) -> Result<()> {
     let authority = ctx.accounts.authority.key();
     let path = HARDCODED_ROOT_PATH.to_string();
+    let key_version = ctx.accounts.config.active_key_version;
     let BtcWithdrawParams {
         lock_time,
         caip2_id,
-        vault_script_pubkey,
         recipient_script_pubkey,
-        fee,
+        fee_rate,
     } = tx_params;
+    let chain = ctx.accounts.chain_registry.require_enabled_mut(&caip2_id)?;
+    ctx.accounts
+        .config
+        .require_not_paused(&caip2_id, Direction::Withdrawal)?;
+
+    require!(
+        amount >= P2WPKH_DUST_LIMIT,
+        crate::error::ErrorCode::DustOutput
+    );
+    require!(
+        fee_rate >= MIN_WITHDRAWAL_FEE_RATE,
+        crate::error::ErrorCode::FeeRateTooLow
+    );
+
+    // The program picks the inputs, so callers can't spend dust-heavy or oversized
+    // selections out of the vault
+    let selection = ctx.accounts.vault_utxo_set.select(
+        key_version,
+        amount,
+        fee_rate,
+        recipient_script_pubkey.len(),
+    )?;
+    let fee = selection.fee;
 
     let total_debit = amount
         .checked_add(fee)
         .ok_or(crate::error::ErrorCode::Overflow)?;
 
+    ctx.accounts.config.consume_withdrawal_limits(
+        &authority,
+        total_debit as u128,
+        (
+            &mut ctx.accounts.user_rate_limit.window,
+            &chain.settings.user_withdrawal_rate_limit,
+        ),
+        (
+            &mut chain.withdrawal_window,
+            &chain.settings.withdrawal_rate_limit,
+        ),
+    )?;
+
+    // The protocol fee is debited on top of the withdrawn amount and network fee
+    let protocol_fee = chain.settings.fees.withdrawal_fee(amount as u128)? as u64;
+    let balance_debit = total_debit
+        .checked_add(protocol_fee)
+        .ok_or(crate::error::ErrorCode::Overflow)?;
+
     // Check user has sufficient balance
     let balance = &mut ctx.accounts.user_balance;
     require!(
-        balance.amount >= total_debit,
+        balance.amount >= balance_debit,
         crate::error::ErrorCode::InsufficientBalance
     );
 
     // Optimistically decrement the balance
     balance.amount = balance
         .amount
-        .checked_sub(total_debit)
+        .checked_sub(balance_debit)
         .ok_or(crate::error::ErrorCode::Underflow)?;
-
-    // Build Bitcoin transaction inputs
-    let mut btc_inputs = Vec::new();
-    let mut total_input_value = 0u64;
-    for input in &inputs {
-        let txid = Txid(Hash(input.txid));
-
-        let txin = TxIn {
-            previous_output: OutPoint::new(txid, input.vout),
-            script_sig: ScriptBuf::default(),
-            sequence: Sequence::MAX,
-            witness: Witness::default(),
-        };
-
-        btc_inputs.push(txin);
-
-        total_input_value = total_input_value
-            .checked_add(input.value)
-            .ok_or(crate::error::ErrorCode::Overflow)?;
+    chain.liabilities.debit(total_debit as u128)?;
+
+    // Inputs and change are locked to the vault script derived from the active MPC key
+    let vault_script_bytes = crate::crypto::derive_vault_script_pubkey(
+        ctx.accounts.config.root_public_key(key_version)?,
+    )?
+    .to_vec();
+
+    // Reserve the selected UTXOs so no other withdrawal can spend them
+    for utxo in &selection.inputs {
+        ctx.accounts
+            .vault_utxo_set
+            .reserve(&utxo.outpoint, request_id)?;
     }
 
-    require!(
-        total_input_value >= total_debit,
-        crate::error::ErrorCode::InsufficientInputs
-    );
-
     let recipient_script = ScriptBuf::from_bytes(recipient_script_pubkey.clone());
-    let vault_script = ScriptBuf::from_bytes(vault_script_pubkey.clone());
+    let vault_script = ScriptBuf::from_bytes(vault_script_bytes.clone());
 
-    let change_output_value = total_input_value
-        .checked_sub(total_debit)
-        .ok_or(crate::error::ErrorCode::Underflow)?;
-
-    // SECURITY: vault change is caller-supplied; the vault/change script should be hardcoded
-    // by the program to avoid malicious change addresses siphoning funds.
     let mut btc_outputs = Vec::new();
     btc_outputs.push(TxOut {
         value: Amount::from_sat(amount),
         script_pubkey: recipient_script,
     });
 
-    if change_output_value > 0 {
+    if selection.change > 0 {
         btc_outputs.push(TxOut {
-            value: Amount::from_sat(change_output_value),
-            script_pubkey: vault_script.clone(),
+            value: Amount::from_sat(selection.change),
+            script_pubkey: vault_script,
         });
     }
 
-    let _total_output_value = amount
-        .checked_add(change_output_value)
-        .ok_or(crate::error::ErrorCode::Overflow)?;
-
-    // Change is derived deterministically, so no extra validation required beyond initial checks.
-
-    // Build unsigned Bitcoin transaction (SegWit - Version::Two)
-    let lock_time =
-        LockTime::from_height(lock_time).map_err(|_| crate::error::ErrorCode::InvalidAddress)?;
-
-    let tx = TransactionBuilder::new::<BITCOIN>()
-        .version(Version::Two)
-        .inputs(btc_inputs)
-        .outputs(btc_outputs)
-        .lock_time(lock_time)
-        .build();
-
-    // Get the TXID in explorer order for deterministic request IDs
-    let mut txid_explorer_reversed_bytes = tx.compute_txid().as_byte_array();
-    txid_explorer_reversed_bytes.reverse(); // Revert to get explorer format
-
-    // Generate PSBT for MPC signing (includes metadata for signing)
-    let mut psbt = Psbt::from_unsigned_tx(tx);
-
-    // Add witnessUtxo for each input (required for SegWit P2WPKH signing)
-    for (i, input) in inputs.iter().enumerate() {
-        psbt.update_input_with_witness_utxo(i, input.script_pubkey.clone(), input.value)
-            .map_err(|_| crate::error::ErrorCode::SerializationError)?;
-    }
-
-    let psbt_bytes = psbt
-        .serialize()
-        .map_err(|_| crate::error::ErrorCode::SerializationError)?;
+    let (txid_explorer_reversed_bytes, psbt_bytes) = build_vault_spend(
+        &selection.inputs,
+        btc_outputs,
+        lock_time,
+        &vault_script_bytes,
+    )?;
 
     // Generate request ID using TXID (deterministic!)
     let computed_request_id = generate_sign_bidirectional_request_id(
         &ctx.accounts.requester.key(),
         &txid_explorer_reversed_bytes,
         &caip2_id,
-        1,
+        key_version,
         &path,
         "ECDSA",
         "bitcoin",
@@ -367,55 +490,27 @@ pub fn withdraw_btc(
     pending.requester = authority;
     pending.amount = amount;
     pending.fee = fee;
+    pending.caip2_id = caip2_id.clone();
     pending.recipient_address = recipient_address;
     pending.path = path.clone();
     pending.request_id = request_id;
+    pending.key_version = key_version;
+    pending.protocol_fee = protocol_fee;
+    pending.rent_payer = authority;
+    pending.created_slot = Clock::get()?.slot;
+    pending.txid = txid_explorer_reversed_bytes;
+    pending.change = selection.change;
+    pending.inputs = selection.inputs.iter().map(|utxo| utxo.outpoint).collect();
 
-    // Create callback schema
-    let callback_schema = serde_json::to_vec(&serde_json::json!("bool"))
-        .map_err(|_| crate::error::ErrorCode::SerializationError)?;
-
-    let explorer_schema = callback_schema.clone();
-
-    // CPI to sign_bidirectional
     let requester_bump = ctx.bumps.requester;
     let signer_seeds: &[&[&[u8]]] = &[&[b"global_vault_authority", &[requester_bump]]];
 
-    let cpi_ctx = CpiContext::new_with_signer(
-        ctx.accounts.chain_signatures_program.to_account_info(),
-        SignBidirectional {
-            program_state: ctx.accounts.chain_signatures_state.to_account_info(),
-            requester: ctx.accounts.requester.to_account_info(),
-            fee_payer: ctx
-                .accounts
-                .fee_payer
-                .as_ref()
-                .map(|fp| fp.to_account_info()),
-            system_program: ctx.accounts.system_program.to_account_info(),
-            instructions: ctx
-                .accounts
-                .instructions
-                .as_ref()
-                .map(|i| i.to_account_info()),
-            event_authority: ctx.accounts.event_authority.to_account_info(),
-            program: ctx.accounts.chain_signatures_program.to_account_info(),
-        },
-        signer_seeds,
-    );
-
-    // Send PSBT to Chain Signatures for signing (needs metadata!)
-    sign_bidirectional(
-        cpi_ctx,
+    request_btc_signature(
+        sign_bidirectional_cpi!(ctx.accounts, requester, signer_seeds),
         psbt_bytes,
         caip2_id.clone(),
-        1,
+        key_version,
         path,
-        "ECDSA".to_string(),
-        "bitcoin".to_string(),
-        "".to_string(),
-        crate::ID,
-        explorer_schema,
-        callback_schema,
     )?;
 
     msg!("BTC withdrawal initiated with request_id: {:?}", request_id);
@@ -425,7 +520,7 @@ pub fn withdraw_btc(
 
 pub fn complete_withdraw_btc(
     ctx: Context<CompleteWithdrawBtc>,
-    request_id: [u8; 32],
+    _request_id: [u8; 32],
     serialized_output: Vec<u8>,
     signature: chain_signatures::Signature,
 ) -> Result<()> {
@@ -435,86 +530,593 @@ pub fn complete_withdraw_btc(
     // Derive the expected address on-chain from MPC root public key + "root" path
     // For withdrawals, the signer is always the global vault address
     let expected_address_bytes = crate::crypto::derive_withdrawal_expected_address(
-        &config.mpc_root_public_key,
+        config.root_public_key(pending.key_version)?,
     )?;
 
-    // Verify signature against the derived expected address
-    let message_hash = hash_message(&request_id, &serialized_output);
-    let expected_address_str = format!("0x{}", hex::encode(expected_address_bytes));
-    verify_signature_from_address(&message_hash, &signature, &expected_address_str)?;
+    // Verify the signature against the withdrawal or its cancellation
+    let responded_request_id = verify_withdrawal_response(
+        pending,
+        &serialized_output,
+        &signature,
+        &expected_address_bytes,
+        config.allow_high_recovery_ids,
+    )?;
 
     msg!("Signature verified successfully");
 
-    // Check for error magic prefix
-    const ERROR_PREFIX: [u8; 4] = [0xDE, 0xAD, 0xBE, 0xEF];
-
-    let should_refund = if serialized_output.len() >= 4 && serialized_output[..4] == ERROR_PREFIX {
-        msg!("Detected error response (magic prefix)");
-        true
+    // Refund on an error response or a transfer that returned false
+    let failure_reason = transfer_failure_reason(&serialized_output)?;
+
+    let chain = ctx.accounts.chain_registry.get_mut(&pending.caip2_id);
+    let utxo_set = &mut ctx.accounts.vault_utxo_set;
+    if let Some(reason) = failure_reason {
+        // A signed cancellation may still confirm; its failure is recorded first
+        // with record_failed_withdraw_btc
+        require!(
+            pending.siblings_failed(&responded_request_id),
+            crate::error::ErrorCode::WithdrawalOutcomePending
+        );
+        msg!("Withdrawal failed: {}", reason);
+        refund_withdrawal(pending, 0, &mut ctx.accounts.user_balance, chain)?;
+        utxo_set.release(&pending.request_id);
+    } else if pending.is_cancellation(&responded_request_id) {
+        // The cancellation spent the inputs back to the vault, so the withdrawal
+        // transaction can no longer confirm
+        msg!("Withdrawal cancelled");
+        refund_withdrawal(
+            pending,
+            pending.cancel_fee,
+            &mut ctx.accounts.user_balance,
+            chain,
+        )?;
+        utxo_set.consume(&pending.request_id);
+        let outpoint = BtcOutpoint {
+            txid: pending.cancel_txid,
+            vout: 0,
+        };
+        utxo_set.add(outpoint, pending.cancel_output, pending.key_version)?;
     } else {
-        let success: bool = BorshDeserialize::try_from_slice(&serialized_output)
-            .map_err(|_| crate::error::ErrorCode::InvalidOutput)?;
-
-        if !success {
-            msg!("Transfer returned false");
-            true
-        } else {
-            msg!("Transfer returned true");
-            false
+        if let Some(chain) = chain {
+            chain.accrued_fees = chain
+                .accrued_fees
+                .checked_add(pending.protocol_fee as u128)
+                .ok_or(crate::error::ErrorCode::Overflow)?;
         }
-    };
-
-    if should_refund {
-        // Refund the balance
-        let balance = &mut ctx.accounts.user_balance;
-        let refund_total = pending
-            .amount
-            .checked_add(pending.fee)
-            .ok_or(crate::error::ErrorCode::Overflow)?;
-        balance.amount = balance
-            .amount
-            .checked_add(refund_total)
-            .ok_or(crate::error::ErrorCode::Overflow)?;
 
-        msg!("Balance refunded: {} sats (amount + fee)", refund_total);
+        // The inputs are spent and the change output belongs to the vault
+        utxo_set.consume(&pending.request_id);
+        if pending.change > 0 {
+            let outpoint = BtcOutpoint {
+                txid: pending.txid,
+                vout: 1,
+            };
+            utxo_set.add(outpoint, pending.change, pending.key_version)?;
+        }
     }
 
+    ctx.accounts.config.pay_settler(
+        &ctx.accounts.pending_withdrawal.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+    )?;
+
     msg!("BTC withdrawal process completed");
 
     Ok(())
 }
 
-fn verify_signature_from_address(
-    message_hash: &[u8; 32],
+/// Records a failure response to a cancelled withdrawal's transaction or its
+/// cancellation. The withdrawal stays pending, since the other one may still
+/// confirm; a failure of the last one refunds it through `complete_withdraw_btc`.
+pub fn record_failed_withdraw_btc(
+    ctx: Context<RecordFailedWithdrawBtc>,
+    _request_id: [u8; 32],
+    serialized_output: Vec<u8>,
+    signature: chain_signatures::Signature,
+) -> Result<()> {
+    let pending = &mut ctx.accounts.pending_withdrawal;
+    let config = &ctx.accounts.config;
+
+    let expected_address_bytes = crate::crypto::derive_withdrawal_expected_address(
+        config.root_public_key(pending.key_version)?,
+    )?;
+
+    let responded_request_id = verify_withdrawal_response(
+        pending,
+        &serialized_output,
+        &signature,
+        &expected_address_bytes,
+        config.allow_high_recovery_ids,
+    )?;
+
+    let reason = transfer_failure_reason(&serialized_output)?
+        .ok_or(crate::error::ErrorCode::WithdrawalNotFailed)?;
+
+    if !pending.failed_request_ids.contains(&responded_request_id) {
+        pending.failed_request_ids.push(responded_request_id);
+    }
+
+    msg!(
+        "Withdrawal transaction {} failed: {}",
+        hex::encode(responded_request_id),
+        reason
+    );
+
+    Ok(())
+}
+
+/// Executes a `CancelWithdrawal` proposal for a timed-out withdrawal by signing a
+/// transaction that spends its reserved inputs back to the vault script, paying
+/// more than the withdrawal so nodes replace it. Only one of the two can confirm:
+/// a success response to the cancellation refunds the withdrawal, less the
+/// cancellation's network fee, through `complete_withdraw_btc`.
+pub fn cancel_withdraw_btc(
+    ctx: Context<CancelWithdrawBtc>,
+    proposal_id: u64,
+    request_id: [u8; 32],
+    cancel_request_id: [u8; 32],
+    fee_rate: u64,
+) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+    let config = &ctx.accounts.config;
+
+    require_executable(config, proposal)?;
+    require!(
+        matches!(
+            proposal.action,
+            ConfigAction::CancelWithdrawal { request_id: id } if id == request_id
+        ),
+        crate::error::ErrorCode::InvalidProposalAction
+    );
+
+    let pending = &mut ctx.accounts.pending_withdrawal;
+    config.require_withdrawal_timed_out(pending.created_slot)?;
+    require!(
+        pending.cancel_request_id.is_none(),
+        crate::error::ErrorCode::WithdrawalAlreadyCancelled
+    );
+
+    let fee = pending.cancellation_fee(fee_rate)?;
+    // The fee comes out of the refund, so it can't exceed what the user paid in
+    let refundable = pending
+        .amount
+        .checked_add(pending.fee)
+        .ok_or(crate::error::ErrorCode::Overflow)?;
+    require!(
+        fee <= refundable,
+        crate::error::ErrorCode::NetworkFeeTooHigh
+    );
+
+    let mut inputs = Vec::with_capacity(pending.inputs.len());
+    let mut total_input_value = 0u64;
+    for outpoint in &pending.inputs {
+        let utxo = *ctx.accounts.vault_utxo_set.get(outpoint)?;
+        total_input_value = total_input_value
+            .checked_add(utxo.value)
+            .ok_or(crate::error::ErrorCode::Overflow)?;
+        inputs.push(utxo);
+    }
+    let output_value = total_input_value
+        .checked_sub(fee)
+        .ok_or(crate::error::ErrorCode::InsufficientVaultUtxos)?;
+    require!(
+        output_value >= P2WPKH_DUST_LIMIT,
+        crate::error::ErrorCode::DustOutput
+    );
+
+    // The inputs are locked to the vault script of the withdrawal's key version
+    let vault_script_bytes =
+        crate::crypto::derive_vault_script_pubkey(config.root_public_key(pending.key_version)?)?
+            .to_vec();
+    let outputs = vec![TxOut {
+        value: Amount::from_sat(output_value),
+        script_pubkey: ScriptBuf::from_bytes(vault_script_bytes.clone()),
+    }];
+    let (cancel_txid, psbt_bytes) = build_vault_spend(&inputs, outputs, 0, &vault_script_bytes)?;
+
+    let path = pending.path.clone();
+    let key_version = pending.key_version;
+    let computed_request_id = generate_sign_bidirectional_request_id(
+        &ctx.accounts.requester.key(),
+        &cancel_txid,
+        &pending.caip2_id,
+        key_version,
+        &path,
+        "ECDSA",
+        "bitcoin",
+        "",
+    );
+
+    require!(
+        computed_request_id == cancel_request_id,
+        crate::error::ErrorCode::InvalidRequestId
+    );
+
+    pending.cancel_request_id = Some(cancel_request_id);
+    pending.cancel_txid = cancel_txid;
+    pending.cancel_fee = fee;
+    pending.cancel_output = output_value;
+    let caip2_id = pending.caip2_id.clone();
+
+    let requester_bump = ctx.bumps.requester;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"global_vault_authority", &[requester_bump]]];
+
+    request_btc_signature(
+        sign_bidirectional_cpi!(ctx.accounts, requester, signer_seeds),
+        psbt_bytes,
+        caip2_id,
+        key_version,
+        path,
+    )?;
+
+    emit!(ConfigChangeExecuted {
+        proposal_id,
+        action: proposal.action.clone(),
+    });
+
+    msg!(
+        "BTC withdrawal {:?} cancellation signed with request_id: {:?}",
+        request_id,
+        cancel_request_id
+    );
+
+    Ok(())
+}
+
+/// Verifies an MPC response to the withdrawal transaction or its cancellation,
+/// returning the request ID of the transaction it responds to.
+fn verify_withdrawal_response(
+    pending: &PendingBtcWithdrawal,
+    serialized_output: &[u8],
     signature: &chain_signatures::Signature,
-    expected_address: &str,
+    expected_address: &[u8; 20],
+    allow_high_recovery_ids: bool,
+) -> Result<[u8; 32]> {
+    let mut result = err!(crate::error::ErrorCode::InvalidSignature);
+    for request_id in pending.signed_request_ids() {
+        let message_hash = hash_message(request_id, serialized_output);
+        result = crate::crypto::verify_signature(
+            &message_hash,
+            signature,
+            expected_address,
+            allow_high_recovery_ids,
+        )
+        .map(|()| *request_id);
+        if result.is_ok() {
+            break;
+        }
+    }
+
+    result
+}
+
+/// Returns a withdrawn amount, its network fee and its protocol fee to the requester's
+/// balance, less `spent_fee` the vault paid to the network on its behalf. `chain`
+/// is `None` if the chain was removed from the registry since.
+fn refund_withdrawal(
+    pending: &PendingBtcWithdrawal,
+    spent_fee: u64,
+    balance: &mut UserBtcBalance,
+    chain: Option<&mut ChainEntry>,
+) -> Result<()> {
+    let refund_total = pending
+        .amount
+        .checked_add(pending.fee)
+        .and_then(|total| total.checked_sub(spent_fee))
+        .ok_or(crate::error::ErrorCode::Overflow)?;
+    balance.amount = balance
+        .amount
+        .checked_add(refund_total)
+        .and_then(|total| total.checked_add(pending.protocol_fee))
+        .ok_or(crate::error::ErrorCode::Overflow)?;
+    if let Some(chain) = chain {
+        chain.liabilities.credit(refund_total as u128)?;
+    }
+
+    msg!(
+        "Balance refunded: {} sats (amount + fee) and {} sats protocol fee",
+        refund_total,
+        pending.protocol_fee
+    );
+
+    Ok(())
+}
+
+/// Executes a `SweepVaultUtxos` proposal, signing a transaction that merges the
+/// smallest unreserved vault UTXOs under the proposal's key version into one output
+/// to the active key's vault script. Each sweep frees up to
+/// `MAX_WITHDRAWAL_INPUTS - 1` slots in the vault UTXO set, and sweeping a retired
+/// key version moves its outputs to where withdrawals can spend them. The network
+/// fee comes out of the chain's accrued fees, which back no user balance.
+pub fn sweep_btc(
+    ctx: Context<SweepBtc>,
+    proposal_id: u64,
+    request_id: [u8; 32],
+    caip2_id: String,
 ) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+    let config = &ctx.accounts.config;
+
+    require_executable(config, proposal)?;
+
+    let ConfigAction::SweepVaultUtxos {
+        caip2_id: proposed_caip2_id,
+        key_version,
+        fee_rate,
+    } = proposal.action.clone()
+    else {
+        return err!(crate::error::ErrorCode::InvalidProposalAction);
+    };
+    require!(
+        proposed_caip2_id == caip2_id,
+        crate::error::ErrorCode::InvalidProposalAction
+    );
+    require!(
+        fee_rate >= MIN_WITHDRAWAL_FEE_RATE,
+        crate::error::ErrorCode::FeeRateTooLow
+    );
+
+    // The inputs are signed under the key they are locked to; the output pays the
+    // active key, which withdrawals select from
+    let path = HARDCODED_ROOT_PATH.to_string();
+    let output_key_version = config.active_key_version;
+    let selection = ctx
+        .accounts
+        .vault_utxo_set
+        .select_sweep(key_version, fee_rate)?;
+
+    let chain = ctx.accounts.chain_registry.require_enabled_mut(&caip2_id)?;
+    chain.accrued_fees = chain
+        .accrued_fees
+        .checked_sub(selection.fee as u128)
+        .ok_or(crate::error::ErrorCode::InsufficientAccruedFees)?;
+
+    for utxo in &selection.inputs {
+        ctx.accounts
+            .vault_utxo_set
+            .reserve(&utxo.outpoint, request_id)?;
+    }
+
+    let input_script_bytes =
+        crate::crypto::derive_vault_script_pubkey(config.root_public_key(key_version)?)?.to_vec();
+    let output_script_bytes =
+        crate::crypto::derive_vault_script_pubkey(config.root_public_key(output_key_version)?)?
+            .to_vec();
+    let outputs = vec![TxOut {
+        value: Amount::from_sat(selection.change),
+        script_pubkey: ScriptBuf::from_bytes(output_script_bytes),
+    }];
+    let (txid, psbt_bytes) = build_vault_spend(&selection.inputs, outputs, 0, &input_script_bytes)?;
+
+    let computed_request_id = generate_sign_bidirectional_request_id(
+        &ctx.accounts.requester.key(),
+        &txid,
+        &caip2_id,
+        key_version,
+        &path,
+        "ECDSA",
+        "bitcoin",
+        "",
+    );
+
     require!(
-        signature.recovery_id < 4,
-        crate::error::ErrorCode::InvalidSignature
+        computed_request_id == request_id,
+        crate::error::ErrorCode::InvalidRequestId
     );
 
-    let mut sig_bytes = [0u8; 64];
-    sig_bytes[..32].copy_from_slice(&signature.big_r.x);
-    sig_bytes[32..].copy_from_slice(&signature.s);
+    let pending = &mut ctx.accounts.pending_sweep;
+    pending.caip2_id = caip2_id.clone();
+    pending.request_id = request_id;
+    pending.key_version = key_version;
+    pending.output_key_version = output_key_version;
+    pending.rent_payer = ctx.accounts.executor.key();
+    pending.txid = txid;
+    pending.output = selection.change;
+    pending.fee = selection.fee;
+    pending.inputs = selection.inputs.iter().map(|utxo| utxo.outpoint).collect();
+
+    let requester_bump = ctx.bumps.requester;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"global_vault_authority", &[requester_bump]]];
+
+    request_btc_signature(
+        sign_bidirectional_cpi!(ctx.accounts, requester, signer_seeds),
+        psbt_bytes,
+        caip2_id,
+        key_version,
+        path,
+    )?;
 
-    let recovered_pubkey = secp256k1_recover(message_hash, signature.recovery_id, &sig_bytes)
-        .map_err(|_| crate::error::ErrorCode::InvalidSignature)?;
+    emit!(ConfigChangeExecuted {
+        proposal_id,
+        action: proposal.action.clone(),
+    });
 
-    let pubkey_bytes = recovered_pubkey.to_bytes();
-    let pubkey_hash = keccak::hash(&pubkey_bytes);
-    let address_bytes = &pubkey_hash.to_bytes()[12..];
+    msg!(
+        "BTC sweep of {} vault UTXOs initiated with request_id: {:?}",
+        selection.inputs.len(),
+        request_id
+    );
 
-    let recovered_address = format!("0x{}", hex::encode(address_bytes));
+    Ok(())
+}
+
+/// Settles a sweep from the MPC response: on success its inputs leave the vault
+/// UTXO set and its output joins it, on failure the inputs are released and the
+/// fee is returned to the chain's accrued fees.
+pub fn complete_sweep_btc(
+    ctx: Context<CompleteSweepBtc>,
+    request_id: [u8; 32],
+    serialized_output: Vec<u8>,
+    signature: chain_signatures::Signature,
+) -> Result<()> {
+    let pending = &ctx.accounts.pending_sweep;
+    let config = &ctx.accounts.config;
+
+    let expected_address_bytes = crate::crypto::derive_withdrawal_expected_address(
+        config.root_public_key(pending.key_version)?,
+    )?;
+
+    let message_hash = hash_message(&request_id, &serialized_output);
+    crate::crypto::verify_signature(
+        &message_hash,
+        &signature,
+        &expected_address_bytes,
+        config.allow_high_recovery_ids,
+    )?;
 
+    let utxo_set = &mut ctx.accounts.vault_utxo_set;
+    if let Some(reason) = transfer_failure_reason(&serialized_output)? {
+        msg!("Sweep failed: {}", reason);
+        utxo_set.release(&request_id);
+        if let Some(chain) = ctx.accounts.chain_registry.get_mut(&pending.caip2_id) {
+            chain.accrued_fees = chain
+                .accrued_fees
+                .checked_add(pending.fee as u128)
+                .ok_or(crate::error::ErrorCode::Overflow)?;
+        }
+    } else {
+        utxo_set.consume(&request_id);
+        let outpoint = BtcOutpoint {
+            txid: pending.txid,
+            vout: 0,
+        };
+        utxo_set.add(outpoint, pending.output, pending.output_key_version)?;
+    }
+
+    ctx.accounts.config.pay_settler(
+        &ctx.accounts.pending_sweep.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+    )?;
+
+    msg!("BTC sweep completed");
+
+    Ok(())
+}
+
+/// Moves a balance held in a pre-upgrade account, which had no chain in its
+/// seeds, to the chain configured in `legacy_btc_caip2_id`. Claims already
+/// counted the balance in the chain's liabilities, or `CreditChainLiabilities`
+/// added it, so they are left unchanged.
+pub fn migrate_btc_balance(ctx: Context<MigrateBtcBalance>) -> Result<()> {
     require!(
-        recovered_address.to_lowercase() == expected_address.to_lowercase(),
-        crate::error::ErrorCode::InvalidSignature
+        !ctx.accounts.config.legacy_btc_caip2_id.is_empty(),
+        crate::error::ErrorCode::MigrationDisabled
+    );
+
+    let amount = ctx.accounts.legacy_balance.amount;
+    let balance = &mut ctx.accounts.user_balance;
+    balance.amount = balance
+        .amount
+        .checked_add(amount)
+        .ok_or(crate::error::ErrorCode::Overflow)?;
+
+    msg!(
+        "Migrated {} sats to {}",
+        amount,
+        ctx.accounts.config.legacy_btc_caip2_id
     );
 
     Ok(())
 }
 
+/// Moves the chain's accrued protocol fees into the treasury's BTC balance.
+pub fn collect_btc_fees(ctx: Context<CollectBtcFees>, caip2_id: String) -> Result<()> {
+    let chain = ctx
+        .accounts
+        .chain_registry
+        .get_mut(&caip2_id)
+        .ok_or(crate::error::ErrorCode::UnsupportedChain)?;
+    let fees = u64::try_from(chain.accrued_fees).map_err(|_| crate::error::ErrorCode::Overflow)?;
+    chain.accrued_fees = 0;
+
+    let balance = &mut ctx.accounts.treasury_balance;
+    balance.amount = balance
+        .amount
+        .checked_add(fees)
+        .ok_or(crate::error::ErrorCode::Overflow)?;
+
+    msg!("Collected {} sats on {} into the treasury", fees, caip2_id);
+
+    Ok(())
+}
+
+/// Builds the unsigned transaction spending `inputs`, vault UTXOs locked to
+/// `vault_script`. Returns its ID in explorer byte order, from which request IDs
+/// are derived, and the PSBT sent for signing.
+fn build_vault_spend(
+    inputs: &[VaultUtxo],
+    outputs: Vec<TxOut>,
+    lock_time: u32,
+    vault_script: &[u8],
+) -> Result<([u8; 32], Vec<u8>)> {
+    let btc_inputs = inputs
+        .iter()
+        .map(|utxo| TxIn {
+            previous_output: OutPoint::new(Txid(Hash(utxo.outpoint.txid)), utxo.outpoint.vout),
+            script_sig: ScriptBuf::default(),
+            sequence: Sequence::MAX,
+            witness: Witness::default(),
+        })
+        .collect();
+
+    // Build unsigned Bitcoin transaction (SegWit - Version::Two)
+    let lock_time =
+        LockTime::from_height(lock_time).map_err(|_| crate::error::ErrorCode::InvalidLockTime)?;
+
+    let tx = TransactionBuilder::new::<BITCOIN>()
+        .version(Version::Two)
+        .inputs(btc_inputs)
+        .outputs(outputs)
+        .lock_time(lock_time)
+        .build();
+
+    // Get the TXID in explorer order for deterministic request IDs
+    let mut txid_explorer_reversed_bytes = tx.compute_txid().as_byte_array();
+    txid_explorer_reversed_bytes.reverse(); // Revert to get explorer format
+
+    // Generate PSBT for MPC signing (includes metadata for signing)
+    let mut psbt = Psbt::from_unsigned_tx(tx);
+
+    // Add witnessUtxo for each input (required for SegWit P2WPKH signing)
+    for (i, utxo) in inputs.iter().enumerate() {
+        psbt.update_input_with_witness_utxo(i, vault_script.to_vec(), utxo.value)
+            .map_err(|_| crate::error::ErrorCode::SerializationError)?;
+    }
+
+    let psbt_bytes = psbt
+        .serialize()
+        .map_err(|_| crate::error::ErrorCode::SerializationError)?;
+
+    Ok((txid_explorer_reversed_bytes, psbt_bytes))
+}
+
+/// Requests the MPC signatures of a Bitcoin transaction's inputs. The response
+/// reports whether the transaction confirmed.
+fn request_btc_signature<'info>(
+    cpi_ctx: CpiContext<'_, '_, '_, 'info, SignBidirectional<'info>>,
+    psbt_bytes: Vec<u8>,
+    caip2_id: String,
+    key_version: u32,
+    path: String,
+) -> Result<()> {
+    let callback_schema = serde_json::to_vec(&serde_json::json!("bool"))
+        .map_err(|_| crate::error::ErrorCode::SerializationError)?;
+    let explorer_schema = callback_schema.clone();
+
+    sign_bidirectional(
+        cpi_ctx,
+        psbt_bytes,
+        caip2_id,
+        key_version,
+        path,
+        "ECDSA".to_string(),
+        "bitcoin".to_string(),
+        "".to_string(),
+        crate::ID,
+        explorer_schema,
+        callback_schema,
+    )
+}
+
 #[allow(clippy::too_many_arguments)]
 fn generate_sign_bidirectional_request_id(
     sender: &Pubkey,
@@ -0,0 +1,308 @@
+use anchor_lang::prelude::*;
+
+use crate::contexts::{InitializeConfig, SetPause};
+use crate::error::ErrorCode;
+use crate::state::{
+    is_supported_caip2_id, ChainPause, MpcKeyVersion, PauseFlags, PauseScope, RateLimitOverride,
+    RateLimitSettings, RateLimitWindow, VaultConfig, MAX_ADMIN_SIGNERS, MAX_CAIP2_ID_LEN,
+    MAX_KEY_VERSIONS, MAX_PAUSED_CHAINS, MAX_RATE_LIMIT_OVERRIDES, MAX_TIMELOCK_SLOTS,
+};
+
+pub fn initialize_config(
+    ctx: Context<InitializeConfig>,
+    mpc_root_public_key: [u8; 64],
+    key_version: u32,
+) -> Result<()> {
+    require!(
+        crate::crypto::is_valid_public_key(&mpc_root_public_key),
+        ErrorCode::InvalidPublicKey
+    );
+
+    // The upgrade authority starts as a 1-of-1 admin multisig and can hand over
+    // to the production signer set through a SetAdminSigners proposal.
+    let config = &mut ctx.accounts.config;
+    config.admin_signers = vec![ctx.accounts.payer.key()];
+    config.admin_threshold = 1;
+    config.proposal_count = 0;
+    config.timelock_slots = 0;
+    config.pause_guardian = ctx.accounts.payer.key();
+    config.treasury = ctx.accounts.payer.key();
+    config.active_key_version = key_version;
+    config.key_versions = vec![MpcKeyVersion {
+        version: key_version,
+        mpc_root_public_key,
+    }];
+    config.legacy_erc20_chain_id = 0;
+    config.legacy_btc_caip2_id = String::new();
+    config.global_withdrawal_rate_limit = RateLimitSettings::default();
+    config.global_withdrawal_window = RateLimitWindow::default();
+    config.rate_limit_overrides = Vec::new();
+    config.allow_high_recovery_ids = false;
+    config.withdrawal_timeout_slots = 0;
+    config.crank_bounty_lamports = 0;
+    config.deposit_timeout_slots = 0;
+
+    Ok(())
+}
+
+/// Emergency pause. The guardian and individual admin signers can only add pause
+/// switches; lifting them requires an executed SetPause config proposal.
+pub fn set_pause(ctx: Context<SetPause>, scope: PauseScope, flags: PauseFlags) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    require!(
+        config.pause_flags(&scope).is_subset_of(&flags),
+        ErrorCode::Unauthorized
+    );
+
+    apply_pause(config, &scope, flags)
+}
+
+pub(crate) fn add_key_version(
+    config: &mut VaultConfig,
+    key_version: u32,
+    mpc_root_public_key: [u8; 64],
+) -> Result<()> {
+    require!(
+        crate::crypto::is_valid_public_key(&mpc_root_public_key),
+        ErrorCode::InvalidPublicKey
+    );
+    require!(
+        config
+            .key_versions
+            .iter()
+            .all(|entry| entry.version != key_version),
+        ErrorCode::KeyVersionAlreadyExists
+    );
+    require!(
+        config.key_versions.len() < MAX_KEY_VERSIONS,
+        ErrorCode::TooManyKeyVersions
+    );
+
+    config.key_versions.push(MpcKeyVersion {
+        version: key_version,
+        mpc_root_public_key,
+    });
+
+    msg!("MPC key version {} added", key_version);
+
+    Ok(())
+}
+
+pub(crate) fn set_active_key_version(config: &mut VaultConfig, key_version: u32) -> Result<()> {
+    // Fails if the version has not been registered
+    config.root_public_key(key_version)?;
+    config.active_key_version = key_version;
+
+    msg!("Active MPC key version set to {}", key_version);
+
+    Ok(())
+}
+
+pub(crate) fn set_pause_guardian(config: &mut VaultConfig, pause_guardian: Pubkey) -> Result<()> {
+    config.pause_guardian = pause_guardian;
+
+    msg!("Pause guardian set to {}", pause_guardian);
+
+    Ok(())
+}
+
+pub(crate) fn set_treasury(config: &mut VaultConfig, treasury: Pubkey) -> Result<()> {
+    config.treasury = treasury;
+
+    msg!("Treasury set to {}", treasury);
+
+    Ok(())
+}
+
+pub(crate) fn set_admin_signers(
+    config: &mut VaultConfig,
+    admin_signers: Vec<Pubkey>,
+    admin_threshold: u8,
+) -> Result<()> {
+    require!(
+        !admin_signers.is_empty() && admin_signers.len() <= MAX_ADMIN_SIGNERS,
+        ErrorCode::InvalidAdminSigners
+    );
+    require!(
+        admin_signers
+            .iter()
+            .enumerate()
+            .all(|(i, signer)| !admin_signers[..i].contains(signer)),
+        ErrorCode::InvalidAdminSigners
+    );
+    require!(
+        admin_threshold > 0 && admin_threshold as usize <= admin_signers.len(),
+        ErrorCode::InvalidAdminThreshold
+    );
+
+    msg!(
+        "Admin multisig set to {}-of-{}",
+        admin_threshold,
+        admin_signers.len()
+    );
+
+    config.admin_signers = admin_signers;
+    config.admin_threshold = admin_threshold;
+
+    Ok(())
+}
+
+pub(crate) fn set_timelock(config: &mut VaultConfig, timelock_slots: u64) -> Result<()> {
+    require!(
+        timelock_slots <= MAX_TIMELOCK_SLOTS,
+        ErrorCode::InvalidTimelock
+    );
+    config.timelock_slots = timelock_slots;
+
+    msg!("Config timelock set to {} slots", timelock_slots);
+
+    Ok(())
+}
+
+pub(crate) fn set_legacy_erc20_chain_id(config: &mut VaultConfig, chain_id: u64) -> Result<()> {
+    config.legacy_erc20_chain_id = chain_id;
+
+    msg!("Legacy ERC20 balances migrate to chain {}", chain_id);
+
+    Ok(())
+}
+
+pub(crate) fn set_legacy_btc_caip2_id(config: &mut VaultConfig, caip2_id: String) -> Result<()> {
+    require!(
+        caip2_id.is_empty()
+            || (caip2_id.starts_with("bip122:") && is_supported_caip2_id(&caip2_id)),
+        ErrorCode::InvalidCaip2Id
+    );
+
+    msg!("Legacy BTC balances migrate to {}", caip2_id);
+    config.legacy_btc_caip2_id = caip2_id;
+
+    Ok(())
+}
+
+pub(crate) fn set_allow_high_recovery_ids(config: &mut VaultConfig, allowed: bool) -> Result<()> {
+    config.allow_high_recovery_ids = allowed;
+
+    msg!("High signature recovery IDs allowed: {}", allowed);
+
+    Ok(())
+}
+
+pub(crate) fn set_withdrawal_timeout(config: &mut VaultConfig, timeout_slots: u64) -> Result<()> {
+    config.withdrawal_timeout_slots = timeout_slots;
+
+    msg!("Withdrawal timeout set to {} slots", timeout_slots);
+
+    Ok(())
+}
+
+pub(crate) fn set_deposit_timeout(config: &mut VaultConfig, timeout_slots: u64) -> Result<()> {
+    config.deposit_timeout_slots = timeout_slots;
+
+    msg!("Deposit timeout set to {} slots", timeout_slots);
+
+    Ok(())
+}
+
+pub(crate) fn set_crank_bounty(config: &mut VaultConfig, lamports: u64) -> Result<()> {
+    config.crank_bounty_lamports = lamports;
+
+    msg!("Crank bounty set to {} lamports", lamports);
+
+    Ok(())
+}
+
+pub(crate) fn set_global_withdrawal_rate_limit(
+    config: &mut VaultConfig,
+    settings: RateLimitSettings,
+) -> Result<()> {
+    config.global_withdrawal_rate_limit = settings;
+
+    msg!(
+        "Global withdrawal rate limit set to {} requests per {} slots",
+        settings.limit,
+        settings.window_slots
+    );
+
+    Ok(())
+}
+
+pub(crate) fn set_rate_limit_override(
+    config: &mut VaultConfig,
+    user: Pubkey,
+    expires_slot: u64,
+) -> Result<()> {
+    let slot = Clock::get()?.slot;
+
+    // Drop expired overrides and any previous override for this user
+    config
+        .rate_limit_overrides
+        .retain(|entry| entry.user != user && slot < entry.expires_slot);
+
+    if slot < expires_slot {
+        require!(
+            config.rate_limit_overrides.len() < MAX_RATE_LIMIT_OVERRIDES,
+            ErrorCode::TooManyRateLimitOverrides
+        );
+        config
+            .rate_limit_overrides
+            .push(RateLimitOverride { user, expires_slot });
+    }
+
+    msg!(
+        "Rate limit override for {} expires at slot {}",
+        user,
+        expires_slot
+    );
+
+    Ok(())
+}
+
+pub(crate) fn apply_pause(
+    config: &mut VaultConfig,
+    scope: &PauseScope,
+    flags: PauseFlags,
+) -> Result<()> {
+    match scope {
+        PauseScope::Global => config.global_pause = flags,
+        PauseScope::Chain { caip2_id } => {
+            require!(
+                caip2_id.len() <= MAX_CAIP2_ID_LEN,
+                ErrorCode::InvalidCaip2Id
+            );
+
+            let existing = config
+                .chain_pauses
+                .iter()
+                .position(|entry| &entry.caip2_id == caip2_id);
+
+            match (existing, flags == PauseFlags::default()) {
+                (Some(index), true) => {
+                    config.chain_pauses.remove(index);
+                }
+                (Some(index), false) => config.chain_pauses[index].flags = flags,
+                (None, true) => {}
+                (None, false) => {
+                    require!(
+                        config.chain_pauses.len() < MAX_PAUSED_CHAINS,
+                        ErrorCode::TooManyPausedChains
+                    );
+                    config.chain_pauses.push(ChainPause {
+                        caip2_id: caip2_id.clone(),
+                        flags,
+                    });
+                }
+            }
+        }
+    }
+
+    msg!(
+        "Pause updated: {:?} deposits={} withdrawals={}",
+        scope,
+        flags.deposits,
+        flags.withdrawals
+    );
+
+    Ok(())
+}
@@ -2,15 +2,22 @@ use alloy_primitives::{Address, U256};
 use alloy_sol_types::SolCall;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::keccak;
-use anchor_lang::solana_program::secp256k1_recover::secp256k1_recover;
-use borsh::BorshDeserialize;
 use chain_signatures::cpi::accounts::SignBidirectional;
 use chain_signatures::cpi::sign_bidirectional;
 
 use signet_rs::{TransactionBuilder, TxBuilder, EVM};
 
-use crate::contexts::{ClaimErc20, CompleteWithdrawErc20, DepositErc20, WithdrawErc20};
-use crate::state::{EvmTransactionParams, IERC20};
+use crate::contexts::{
+    CancelWithdrawErc20, ClaimErc20, CollectErc20Fees, CompleteWithdrawErc20, DepositErc20,
+    ExecuteEvmNativeTransfer, FinalizeFailedDepositErc20, MigrateErc20Balance,
+    RecordFailedWithdrawErc20, ReleaseStalledDepositErc20, ReplaceWithdrawErc20, WithdrawErc20,
+};
+use crate::events::{ConfigChangeExecuted, EvmNativeTransferRequested};
+use crate::instructions::governance::require_executable;
+use crate::state::{
+    error_reason, transfer_failure_reason, ConfigAction, Direction, EvmTransactionParams,
+    PendingErc20Withdrawal, TokenRegistry, UserErc20Balance, IERC20, MAX_WITHDRAWAL_REPLACEMENTS,
+};
 
 const HARDCODED_ROOT_PATH: &str = "root";
 
@@ -19,16 +26,38 @@ pub fn deposit_erc20(
     request_id: [u8; 32],
     requester: Pubkey,
     erc20_address: [u8; 20],
-    recipient_address: [u8; 20],
     amount: u128,
     tx_params: EvmTransactionParams,
 ) -> Result<()> {
     let path = requester.to_string();
-    // SECURITY: recipient_address should eventually be derived on-chain instead of supplied.
+    let key_version = ctx.accounts.config.active_key_version;
+
+    require!(
+        tx_params.value == 0,
+        crate::error::ErrorCode::NativeValueNotAllowed
+    );
+
+    // Generate CAIP-2 ID from chain ID
+    let caip2_id = format!("eip155:{}", tx_params.chain_id);
+    ctx.accounts.chain_registry.require_enabled(&caip2_id)?;
+    ctx.accounts
+        .config
+        .require_not_paused(&caip2_id, Direction::Deposit)?;
+    let token_registry = &mut ctx.accounts.token_registry;
+    token_registry.require_transferable(amount)?;
+    let deposit_cap = token_registry.settings.deposit_cap;
+    token_registry
+        .liabilities
+        .reserve_deposit(amount, deposit_cap)?;
+    let protocol_fee = token_registry.settings.fees.deposit_fee(amount)?;
+    // Deposits always sweep to the vault address derived from the active MPC key
+    let vault_address = crate::crypto::derive_vault_ethereum_address(
+        ctx.accounts.config.root_public_key(key_version)?,
+    )?;
+
     // Create ERC20 transfer call
-    let recipient = Address::from_slice(&recipient_address);
     let call = IERC20::transferCall {
-        to: recipient,
+        to: Address::from(vault_address),
         amount: U256::from(amount),
     };
 
@@ -37,7 +66,7 @@ pub fn deposit_erc20(
         .chain_id(tx_params.chain_id)
         .nonce(tx_params.nonce)
         .to(erc20_address)
-        .value(tx_params.value)
+        .value(0)
         .input(call.abi_encode())
         .gas_limit(tx_params.gas_limit)
         .max_fee_per_gas(tx_params.max_fee_per_gas)
@@ -46,15 +75,12 @@ pub fn deposit_erc20(
 
     let rlp_encoded_tx = evm_tx.build_for_signing();
 
-    // Generate CAIP-2 ID from chain ID
-    let caip2_id = format!("eip155:{}", tx_params.chain_id);
-
     // Generate request ID and verify it matches the one passed in
     let computed_request_id = generate_sign_bidirectional_request_id(
         &ctx.accounts.requester_pda.key(),
         &rlp_encoded_tx,
         &caip2_id,
-        1, // key_version
+        key_version,
         &path,
         "ECDSA",
         "ethereum",
@@ -71,23 +97,15 @@ pub fn deposit_erc20(
     pending.requester = requester;
     pending.amount = amount;
     pending.erc20_address = erc20_address;
+    pending.chain_id = tx_params.chain_id;
     pending.path = path.clone();
     pending.request_id = request_id;
+    pending.key_version = key_version;
+    pending.protocol_fee = protocol_fee;
+    pending.rent_payer = ctx.accounts.payer.key();
+    pending.created_slot = Clock::get()?.slot;
+    pending.reservation_released = false;
 
-    // Create schema for ERC20 transfer return value from alloy-sol-types
-    let functions = IERC20::abi::functions();
-    let transfer_func = functions
-        .get("transfer")
-        .and_then(|funcs| funcs.first())
-        .ok_or(crate::error::ErrorCode::FunctionNotFound)?;
-
-    let explorer_schema = serde_json::to_vec(&transfer_func.outputs)
-        .map_err(|_| crate::error::ErrorCode::SerializationError)?;
-
-    let callback_schema = serde_json::to_vec(&serde_json::json!("bool"))
-        .map_err(|_| crate::error::ErrorCode::SerializationError)?;
-
-    // CPI to sign_respond
     let requester_key_bytes = requester.to_bytes();
     let requester_bump = ctx.bumps.requester_pda;
     let signer_seeds: &[&[&[u8]]] = &[&[
@@ -96,40 +114,13 @@ pub fn deposit_erc20(
         &[requester_bump],
     ]];
 
-    let cpi_ctx = CpiContext::new_with_signer(
-        ctx.accounts.chain_signatures_program.to_account_info(),
-        SignBidirectional {
-            program_state: ctx.accounts.chain_signatures_state.to_account_info(),
-            requester: ctx.accounts.requester_pda.to_account_info(),
-            fee_payer: ctx
-                .accounts
-                .fee_payer
-                .as_ref()
-                .map(|fp| fp.to_account_info()),
-            system_program: ctx.accounts.system_program.to_account_info(),
-            instructions: ctx
-                .accounts
-                .instructions
-                .as_ref()
-                .map(|i| i.to_account_info()),
-            event_authority: ctx.accounts.event_authority.to_account_info(),
-            program: ctx.accounts.chain_signatures_program.to_account_info(),
-        },
-        signer_seeds,
-    );
-
-    sign_bidirectional(
-        cpi_ctx,
+    request_evm_signature(
+        sign_bidirectional_cpi!(ctx.accounts, requester_pda, signer_seeds),
         rlp_encoded_tx,
         caip2_id,
-        1, // key_version
+        key_version,
         path,
-        "ECDSA".to_string(),
-        "ethereum".to_string(),
-        "".to_string(),
-        crate::ID,
-        explorer_schema,
-        callback_schema,
+        transfer_schemas()?,
     )?;
 
     msg!("ERC20 deposit initiated with request_id: {:?}", request_id);
@@ -146,9 +137,10 @@ pub fn claim_erc20(
     let pending = &ctx.accounts.pending_deposit;
     let config = &ctx.accounts.config;
 
-    // Derive the expected address on-chain from MPC root public key + user's derivation path
+    // Derive the expected address on-chain from MPC root public key + user's derivation path.
+    // Use the key version recorded at deposit time so requests survive a key rotation.
     let expected_address_bytes = crate::crypto::derive_deposit_expected_address(
-        &config.mpc_root_public_key,
+        config.root_public_key(pending.key_version)?,
         &pending.requester,
     )?;
 
@@ -156,27 +148,129 @@ pub fn claim_erc20(
     let message_hash = hash_message(&request_id, &serialized_output);
 
     // Verify the signature
-    let expected_address = format!("0x{}", hex::encode(expected_address_bytes));
-    verify_signature_from_address(&message_hash, &signature, &expected_address)?;
-
-    // Deserialize directly as bool (server now sends just the boolean)
-    let success: bool = BorshDeserialize::try_from_slice(&serialized_output)
-        .map_err(|_| crate::error::ErrorCode::InvalidOutput)?;
+    crate::crypto::verify_signature(
+        &message_hash,
+        &signature,
+        &expected_address_bytes,
+        config.allow_high_recovery_ids,
+    )?;
 
-    require!(success, crate::error::ErrorCode::TransferFailed);
+    // Failed deposits are closed with finalize_failed_deposit_erc20 instead
+    require!(
+        transfer_failure_reason(&serialized_output)?.is_none(),
+        crate::error::ErrorCode::TransferFailed
+    );
 
-    // Update user balance
+    // Update user balance, net of the protocol fee
+    let credited_amount = pending
+        .amount
+        .checked_sub(pending.protocol_fee)
+        .ok_or(crate::error::ErrorCode::Underflow)?;
     let balance = &mut ctx.accounts.user_balance;
     balance.amount = balance
         .amount
-        .checked_add(pending.amount)
+        .checked_add(credited_amount)
+        .ok_or(crate::error::ErrorCode::Overflow)?;
+
+    let token_registry = &mut ctx.accounts.token_registry;
+    if pending.reservation_released {
+        token_registry.liabilities.credit(pending.amount)?;
+    } else {
+        token_registry.liabilities.settle_deposit(pending.amount)?;
+    }
+    token_registry.accrued_fees = token_registry
+        .accrued_fees
+        .checked_add(pending.protocol_fee)
         .ok_or(crate::error::ErrorCode::Overflow)?;
 
+    ctx.accounts.config.pay_settler(
+        &ctx.accounts.pending_deposit.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+    )?;
+
     msg!("ERC20 deposit claimed successfully");
 
     Ok(())
 }
 
+/// Closes a deposit the MPC network reported as failed and records a receipt with the reason.
+pub fn finalize_failed_deposit_erc20(
+    ctx: Context<FinalizeFailedDepositErc20>,
+    request_id: [u8; 32],
+    serialized_output: Vec<u8>,
+    signature: chain_signatures::Signature,
+) -> Result<()> {
+    let pending = &ctx.accounts.pending_deposit;
+    let config = &ctx.accounts.config;
+
+    let expected_address_bytes = crate::crypto::derive_deposit_expected_address(
+        config.root_public_key(pending.key_version)?,
+        &pending.requester,
+    )?;
+
+    let message_hash = hash_message(&request_id, &serialized_output);
+    crate::crypto::verify_signature(
+        &message_hash,
+        &signature,
+        &expected_address_bytes,
+        config.allow_high_recovery_ids,
+    )?;
+
+    let reason = transfer_failure_reason(&serialized_output)?
+        .ok_or(crate::error::ErrorCode::DepositNotFailed)?;
+
+    if !pending.reservation_released {
+        ctx.accounts
+            .token_registry
+            .liabilities
+            .release_deposit(pending.amount);
+    }
+
+    let receipt = &mut ctx.accounts.failed_deposit;
+    receipt.requester = pending.requester;
+    receipt.amount = pending.amount;
+    receipt.erc20_address = pending.erc20_address;
+    receipt.chain_id = pending.chain_id;
+    receipt.reason = reason;
+    receipt.failed_slot = Clock::get()?.slot;
+
+    ctx.accounts.config.pay_settler(
+        &ctx.accounts.pending_deposit.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+    )?;
+
+    msg!("ERC20 deposit failed: {}", receipt.reason);
+
+    Ok(())
+}
+
+/// Releases the deposit cap reservation of a deposit that got no response within
+/// `deposit_timeout_slots`. The deposit stays pending and can still be claimed or
+/// finalized; a late claim credits the token's liabilities even past its cap.
+pub fn release_stalled_deposit_erc20(
+    ctx: Context<ReleaseStalledDepositErc20>,
+    _request_id: [u8; 32],
+) -> Result<()> {
+    let pending = &mut ctx.accounts.pending_deposit;
+    ctx.accounts
+        .config
+        .require_deposit_timed_out(pending.created_slot)?;
+    require!(
+        !pending.reservation_released,
+        crate::error::ErrorCode::DepositAlreadyReleased
+    );
+
+    ctx.accounts
+        .token_registry
+        .liabilities
+        .release_deposit(pending.amount);
+    pending.reservation_released = true;
+
+    msg!("Released the reservation of a stalled ERC20 deposit");
+
+    Ok(())
+}
+
 pub fn withdraw_erc20(
     ctx: Context<WithdrawErc20>,
     request_id: [u8; 32],
@@ -189,50 +283,82 @@ pub fn withdraw_erc20(
 
     // Use the hardcoded root path for withdrawals
     let path = HARDCODED_ROOT_PATH.to_string();
+    let key_version = ctx.accounts.config.active_key_version;
+
+    // Generate CAIP-2 ID from chain ID
+    let caip2_id = format!("eip155:{}", tx_params.chain_id);
+    ctx.accounts
+        .chain_registry
+        .require_enabled(&caip2_id)?
+        .settings
+        .require_gas_within_bounds(&tx_params)?;
+    ctx.accounts
+        .config
+        .require_not_paused(&caip2_id, Direction::Withdrawal)?;
+    ctx.accounts.token_registry.require_transferable(amount)?;
+
+    require!(
+        tx_params.value == 0,
+        crate::error::ErrorCode::NativeValueNotAllowed
+    );
+
+    let token_registry: &mut TokenRegistry = &mut ctx.accounts.token_registry;
+    ctx.accounts.config.consume_withdrawal_limits(
+        &authority,
+        amount,
+        (
+            &mut ctx.accounts.user_rate_limit.window,
+            &token_registry.settings.user_withdrawal_rate_limit,
+        ),
+        (
+            &mut token_registry.withdrawal_window,
+            &token_registry.settings.withdrawal_rate_limit,
+        ),
+    )?;
+
+    // The protocol fee and the gas fee are debited on top of the withdrawn amount
+    let protocol_fee = token_registry.settings.fees.withdrawal_fee(amount)?;
+    let gas_fee = token_registry.settings.withdrawal_gas_fee;
+    let total_debit = amount
+        .checked_add(protocol_fee)
+        .and_then(|debit| debit.checked_add(gas_fee))
+        .ok_or(crate::error::ErrorCode::Overflow)?;
 
     // Check user has sufficient balance
     let balance = &mut ctx.accounts.user_balance;
     require!(
-        balance.amount >= amount,
+        balance.amount >= total_debit,
         crate::error::ErrorCode::InsufficientBalance
     );
 
     // Optimistically decrement the balance
     balance.amount = balance
         .amount
-        .checked_sub(amount)
+        .checked_sub(total_debit)
         .ok_or(crate::error::ErrorCode::Underflow)?;
+    token_registry.liabilities.debit(amount)?;
 
-    // Create ERC20 transfer call
-    let recipient = Address::from_slice(&recipient_address);
-    let call = IERC20::transferCall {
-        to: recipient,
-        amount: U256::from(amount),
-    };
-
-    // Build EVM transaction - note: this is FROM the hardcoded recipient address
-    let evm_tx = TransactionBuilder::new::<EVM>()
-        .chain_id(tx_params.chain_id)
-        .nonce(tx_params.nonce)
-        .to(erc20_address)
-        .value(tx_params.value)
-        .input(call.abi_encode())
-        .gas_limit(tx_params.gas_limit)
-        .max_fee_per_gas(tx_params.max_fee_per_gas)
-        .max_priority_fee_per_gas(tx_params.max_priority_fee_per_gas)
-        .build();
+    // The program assigns the vault nonce so concurrent withdrawals can't collide
+    let vault_nonce = &mut ctx.accounts.vault_nonce;
+    vault_nonce.chain_id = tx_params.chain_id;
+    vault_nonce.key_version = key_version;
+    require!(
+        tx_params.nonce == vault_nonce.next_nonce,
+        crate::error::ErrorCode::NonceMismatch
+    );
+    vault_nonce.next_nonce = vault_nonce
+        .next_nonce
+        .checked_add(1)
+        .ok_or(crate::error::ErrorCode::Overflow)?;
 
-    let rlp_encoded_tx = evm_tx.build_for_signing();
-
-    // Generate CAIP-2 ID from chain ID
-    let caip2_id = format!("eip155:{}", tx_params.chain_id);
+    let rlp_encoded_tx = build_withdrawal_tx(erc20_address, amount, recipient_address, &tx_params);
 
     // Generate request ID
     let computed_request_id = generate_sign_bidirectional_request_id(
         &ctx.accounts.requester.key(),
         &rlp_encoded_tx,
         &caip2_id,
-        1, // key_version
+        key_version,
         &path,
         "ECDSA",
         "ethereum",
@@ -249,69 +375,254 @@ pub fn withdraw_erc20(
     pending.requester = authority;
     pending.amount = amount;
     pending.erc20_address = erc20_address;
+    pending.chain_id = tx_params.chain_id;
     pending.recipient_address = recipient_address;
     pending.path = path.clone();
     pending.request_id = request_id;
+    pending.key_version = key_version;
+    pending.protocol_fee = protocol_fee;
+    pending.gas_fee = gas_fee;
+    pending.gas_cost = tx_params.max_gas_cost()?;
+    pending.rent_payer = authority;
+    pending.created_slot = Clock::get()?.slot;
+    pending.nonce = tx_params.nonce;
+    pending.max_fee_per_gas = tx_params.max_fee_per_gas;
+    pending.max_priority_fee_per_gas = tx_params.max_priority_fee_per_gas;
+    pending.replacement_request_ids = Vec::new();
+    pending.failed_request_ids = Vec::new();
+    pending.cancel_request_id = None;
 
-    // Create schema for ERC20 transfer return value
-    let functions = IERC20::abi::functions();
-    let transfer_func = functions
-        .get("transfer")
-        .and_then(|funcs| funcs.first())
-        .ok_or(crate::error::ErrorCode::FunctionNotFound)?;
+    let requester_bump = ctx.bumps.requester;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"global_vault_authority", &[requester_bump]]];
 
-    let explorer_schema = serde_json::to_vec(&transfer_func.outputs)
-        .map_err(|_| crate::error::ErrorCode::SerializationError)?;
+    request_evm_signature(
+        sign_bidirectional_cpi!(ctx.accounts, requester, signer_seeds),
+        rlp_encoded_tx,
+        caip2_id,
+        key_version,
+        path,
+        transfer_schemas()?,
+    )?;
 
-    let callback_schema = serde_json::to_vec(&serde_json::json!("bool"))
-        .map_err(|_| crate::error::ErrorCode::SerializationError)?;
+    msg!(
+        "ERC20 withdrawal initiated with request_id: {:?}",
+        request_id
+    );
+
+    Ok(())
+}
+
+/// Executes a TransferEvmNative proposal, signing a plain value transfer from the
+/// vault address. This is the only path that moves native value out of the vault;
+/// ERC20 transactions are built with a value of 0.
+pub fn execute_evm_native_transfer(
+    ctx: Context<ExecuteEvmNativeTransfer>,
+    proposal_id: u64,
+    request_id: [u8; 32],
+    tx_params: EvmTransactionParams,
+) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+
+    require_executable(&ctx.accounts.config, proposal)?;
+
+    let ConfigAction::TransferEvmNative {
+        chain_id,
+        recipient,
+        value,
+    } = proposal.action.clone()
+    else {
+        return err!(crate::error::ErrorCode::InvalidProposalAction);
+    };
+    require!(
+        tx_params.chain_id == chain_id && tx_params.value == value,
+        crate::error::ErrorCode::InvalidProposalAction
+    );
+
+    let caip2_id = format!("eip155:{}", chain_id);
+    ctx.accounts
+        .chain_registry
+        .require_enabled(&caip2_id)?
+        .settings
+        .require_gas_within_bounds(&tx_params)?;
+
+    let vault_nonce = &mut ctx.accounts.vault_nonce;
+    vault_nonce.chain_id = chain_id;
+    vault_nonce.key_version = ctx.accounts.config.active_key_version;
+    require!(
+        tx_params.nonce == vault_nonce.next_nonce,
+        crate::error::ErrorCode::NonceMismatch
+    );
+    vault_nonce.next_nonce = vault_nonce
+        .next_nonce
+        .checked_add(1)
+        .ok_or(crate::error::ErrorCode::Overflow)?;
+
+    let rlp_encoded_tx = TransactionBuilder::new::<EVM>()
+        .chain_id(chain_id)
+        .nonce(tx_params.nonce)
+        .to(recipient)
+        .value(value)
+        .input(Vec::new())
+        .gas_limit(tx_params.gas_limit)
+        .max_fee_per_gas(tx_params.max_fee_per_gas)
+        .max_priority_fee_per_gas(tx_params.max_priority_fee_per_gas)
+        .build()
+        .build_for_signing();
+
+    let path = HARDCODED_ROOT_PATH.to_string();
+    let key_version = ctx.accounts.config.active_key_version;
+    let computed_request_id = generate_sign_bidirectional_request_id(
+        &ctx.accounts.requester.key(),
+        &rlp_encoded_tx,
+        &caip2_id,
+        key_version,
+        &path,
+        "ECDSA",
+        "ethereum",
+        "",
+    );
+
+    require!(
+        computed_request_id == request_id,
+        crate::error::ErrorCode::InvalidRequestId
+    );
 
-    // CPI to sign_respond
     let requester_bump = ctx.bumps.requester;
-    let signer_seeds: &[&[&[u8]]] = &[&[
-        b"global_vault_authority", // Just this seed
-        &[requester_bump],
-    ]];
+    let signer_seeds: &[&[&[u8]]] = &[&[b"global_vault_authority", &[requester_bump]]];
 
-    let cpi_ctx = CpiContext::new_with_signer(
-        ctx.accounts.chain_signatures_program.to_account_info(),
-        SignBidirectional {
-            program_state: ctx.accounts.chain_signatures_state.to_account_info(),
-            requester: ctx.accounts.requester.to_account_info(),
-            fee_payer: ctx
-                .accounts
-                .fee_payer
-                .as_ref()
-                .map(|fp| fp.to_account_info()),
-            system_program: ctx.accounts.system_program.to_account_info(),
-            instructions: ctx
-                .accounts
-                .instructions
-                .as_ref()
-                .map(|i| i.to_account_info()),
-            event_authority: ctx.accounts.event_authority.to_account_info(),
-            program: ctx.accounts.chain_signatures_program.to_account_info(),
-        },
-        signer_seeds,
+    request_evm_signature(
+        sign_bidirectional_cpi!(ctx.accounts, requester, signer_seeds),
+        rlp_encoded_tx,
+        caip2_id,
+        key_version,
+        path,
+        value_transfer_schemas()?,
+    )?;
+
+    emit!(EvmNativeTransferRequested {
+        proposal_id,
+        chain_id,
+        recipient,
+        value,
+        nonce: tx_params.nonce,
+        request_id,
+    });
+    emit!(ConfigChangeExecuted {
+        proposal_id,
+        action: proposal.action.clone(),
+    });
+
+    Ok(())
+}
+
+/// Signs a fee-bumped replacement for a stuck withdrawal. The replacement reuses
+/// the withdrawal's nonce, so only one of the signed transactions can execute. A
+/// success response to any of them settles the withdrawal; it is refunded only
+/// once all of them were reported failed. A replacement that may spend more gas
+/// than was paid for debits the difference in gas fee from the requester. The
+/// fee bump rules are those of `PendingErc20Withdrawal::require_outbid`.
+pub fn replace_withdraw_erc20(
+    ctx: Context<ReplaceWithdrawErc20>,
+    request_id: [u8; 32],
+    new_request_id: [u8; 32],
+    tx_params: EvmTransactionParams,
+) -> Result<()> {
+    let pending = &mut ctx.accounts.pending_withdrawal;
+
+    let caip2_id = format!("eip155:{}", pending.chain_id);
+    ctx.accounts
+        .config
+        .require_not_paused(&caip2_id, Direction::Withdrawal)?;
+    let settings = &ctx
+        .accounts
+        .chain_registry
+        .require_enabled(&caip2_id)?
+        .settings;
+    settings.require_gas_within_bounds(&tx_params)?;
+
+    require!(
+        tx_params.chain_id == pending.chain_id && tx_params.nonce == pending.nonce,
+        crate::error::ErrorCode::NonceMismatch
+    );
+    require!(
+        tx_params.value == 0,
+        crate::error::ErrorCode::NativeValueNotAllowed
+    );
+    pending.require_outbid(&tx_params, settings.max_fee_per_gas)?;
+    require!(
+        pending.replacement_request_ids.len() < MAX_WITHDRAWAL_REPLACEMENTS,
+        crate::error::ErrorCode::TooManyReplacements
+    );
+    require!(
+        pending.cancel_request_id.is_none(),
+        crate::error::ErrorCode::WithdrawalAlreadyCancelled
     );
 
-    sign_bidirectional(
-        cpi_ctx,
+    let rlp_encoded_tx = build_withdrawal_tx(
+        pending.erc20_address,
+        pending.amount,
+        pending.recipient_address,
+        &tx_params,
+    );
+
+    // Sign under the original key version so the transaction comes from the same address
+    let path = pending.path.clone();
+    let key_version = pending.key_version;
+    let computed_request_id = generate_sign_bidirectional_request_id(
+        &ctx.accounts.requester.key(),
+        &rlp_encoded_tx,
+        &caip2_id,
+        key_version,
+        &path,
+        "ECDSA",
+        "ethereum",
+        "",
+    );
+
+    require!(
+        computed_request_id == new_request_id,
+        crate::error::ErrorCode::InvalidRequestId
+    );
+
+    // The vault pays for whichever signed transaction executes, so a replacement
+    // that may spend more gas tops up the gas fee
+    let gas_cost = tx_params.max_gas_cost()?;
+    let extra_gas_fee = pending.replacement_gas_fee(gas_cost)?;
+    let balance = &mut ctx.accounts.user_balance;
+    require!(
+        balance.amount >= extra_gas_fee,
+        crate::error::ErrorCode::InsufficientBalance
+    );
+    balance.amount = balance
+        .amount
+        .checked_sub(extra_gas_fee)
+        .ok_or(crate::error::ErrorCode::Underflow)?;
+    pending.gas_fee = pending
+        .gas_fee
+        .checked_add(extra_gas_fee)
+        .ok_or(crate::error::ErrorCode::Overflow)?;
+    pending.gas_cost = pending.gas_cost.max(gas_cost);
+
+    pending.replacement_request_ids.push(new_request_id);
+    pending.max_fee_per_gas = tx_params.max_fee_per_gas;
+    pending.max_priority_fee_per_gas = tx_params.max_priority_fee_per_gas;
+
+    let requester_bump = ctx.bumps.requester;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"global_vault_authority", &[requester_bump]]];
+
+    request_evm_signature(
+        sign_bidirectional_cpi!(ctx.accounts, requester, signer_seeds),
         rlp_encoded_tx,
         caip2_id,
-        1, // key_version
+        key_version,
         path,
-        "ECDSA".to_string(),
-        "ethereum".to_string(),
-        "".to_string(),
-        crate::ID,
-        explorer_schema,
-        callback_schema,
+        transfer_schemas()?,
     )?;
 
     msg!(
-        "ERC20 withdrawal initiated with request_id: {:?}",
-        request_id
+        "ERC20 withdrawal {:?} replaced with request_id: {:?}",
+        request_id,
+        new_request_id
     );
 
     Ok(())
@@ -319,7 +630,7 @@ pub fn withdraw_erc20(
 
 pub fn complete_withdraw_erc20(
     ctx: Context<CompleteWithdrawErc20>,
-    request_id: [u8; 32],
+    _request_id: [u8; 32],
     serialized_output: Vec<u8>,
     signature: chain_signatures::Signature,
 ) -> Result<()> {
@@ -329,89 +640,329 @@ pub fn complete_withdraw_erc20(
     // Derive the expected address on-chain from MPC root public key + "root" path
     // For withdrawals, the signer is always the global vault address
     let expected_address_bytes = crate::crypto::derive_withdrawal_expected_address(
-        &config.mpc_root_public_key,
+        config.root_public_key(pending.key_version)?,
     )?;
 
-    let message_hash = hash_message(&request_id, &serialized_output);
-    // Verify the signature
-    let expected_address = format!("0x{}", hex::encode(expected_address_bytes));
-
-    verify_signature_from_address(&message_hash, &signature, &expected_address)?;
+    // Verify the signature against the withdrawal or any of its replacements
+    let responded_request_id = verify_withdrawal_response(
+        pending,
+        &serialized_output,
+        &signature,
+        &expected_address_bytes,
+        config.allow_high_recovery_ids,
+    )?;
 
     msg!("Signature verified successfully");
 
-    // Check for error magic prefix
-    const ERROR_PREFIX: [u8; 4] = [0xDE, 0xAD, 0xBE, 0xEF];
-
-    let should_refund = if serialized_output.len() >= 4 && serialized_output[..4] == ERROR_PREFIX {
-        msg!("Detected error response (magic prefix)");
-        true // Always refund on error
+    // Refund on an error response or a transfer that returned false
+    let failure_reason =
+        withdrawal_failure_reason(pending, &responded_request_id, &serialized_output)?;
+
+    let token_registry = &mut ctx.accounts.token_registry;
+    if let Some(reason) = failure_reason {
+        // Transactions sharing the nonce may still execute; their failures are
+        // recorded first with record_failed_withdraw_erc20
+        require!(
+            pending.siblings_failed(&responded_request_id),
+            crate::error::ErrorCode::WithdrawalOutcomePending
+        );
+        msg!("Withdrawal failed: {}", reason);
+        refund_withdrawal(pending, &mut ctx.accounts.user_balance, token_registry)?;
+    } else if pending.is_cancellation(&responded_request_id) {
+        // The cancellation took the nonce, so the transfer can no longer execute
+        msg!("Withdrawal cancelled");
+        refund_withdrawal(pending, &mut ctx.accounts.user_balance, token_registry)?;
     } else {
-        // Normal response - deserialize as boolean
-        let success: bool = BorshDeserialize::try_from_slice(&serialized_output)
-            .map_err(|_| crate::error::ErrorCode::InvalidOutput)?;
-
-        if !success {
-            msg!("Transfer returned false");
-            true // Refund if transfer returned false
-        } else {
-            msg!("Transfer returned true");
-            false // Don't refund
-        }
-    };
-
-    if should_refund {
-        // Refund the balance
-        let balance = &mut ctx.accounts.user_balance;
-        balance.amount = balance
-            .amount
-            .checked_add(pending.amount)
+        token_registry.accrued_fees = token_registry
+            .accrued_fees
+            .checked_add(pending.protocol_fee)
+            .and_then(|fees| fees.checked_add(pending.gas_fee))
             .ok_or(crate::error::ErrorCode::Overflow)?;
-
-        msg!("Balance refunded: {}", pending.amount);
     }
 
+    ctx.accounts.config.pay_settler(
+        &ctx.accounts.pending_withdrawal.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+    )?;
+
     msg!("ERC20 withdrawal process completed");
 
     Ok(())
 }
 
-// Add this helper function to verify signature by recovering address
-fn verify_signature_from_address(
-    message_hash: &[u8; 32],
-    signature: &chain_signatures::Signature,
-    expected_address: &str,
+/// Records a failure response to one of the transactions signed for a replaced
+/// withdrawal. The withdrawal stays pending, since a transaction sharing its nonce
+/// may still execute; once all but one have failed, a failure of the last one
+/// refunds it through `complete_withdraw_erc20`.
+pub fn record_failed_withdraw_erc20(
+    ctx: Context<RecordFailedWithdrawErc20>,
+    _request_id: [u8; 32],
+    serialized_output: Vec<u8>,
+    signature: chain_signatures::Signature,
 ) -> Result<()> {
-    // Validate recovery ID
+    let pending = &mut ctx.accounts.pending_withdrawal;
+    let config = &ctx.accounts.config;
+
+    let expected_address_bytes = crate::crypto::derive_withdrawal_expected_address(
+        config.root_public_key(pending.key_version)?,
+    )?;
+
+    let responded_request_id = verify_withdrawal_response(
+        pending,
+        &serialized_output,
+        &signature,
+        &expected_address_bytes,
+        config.allow_high_recovery_ids,
+    )?;
+
+    let reason = withdrawal_failure_reason(pending, &responded_request_id, &serialized_output)?
+        .ok_or(crate::error::ErrorCode::WithdrawalNotFailed)?;
+
+    if !pending.failed_request_ids.contains(&responded_request_id) {
+        pending.failed_request_ids.push(responded_request_id);
+    }
+
+    msg!(
+        "Withdrawal transaction {} failed: {}",
+        hex::encode(responded_request_id),
+        reason
+    );
+
+    Ok(())
+}
+
+/// Executes a `CancelWithdrawal` proposal for a timed-out withdrawal by signing a
+/// zero-value transfer from the vault to itself with the withdrawal's nonce and
+/// higher fees, bumped as for a replacement. Only one of the transactions
+/// sharing the nonce can execute: a success response to the cancellation
+/// refunds the withdrawal through `complete_withdraw_erc20`, and either way the
+/// nonce is consumed, so later withdrawals aren't blocked behind it.
+pub fn cancel_withdraw_erc20(
+    ctx: Context<CancelWithdrawErc20>,
+    proposal_id: u64,
+    request_id: [u8; 32],
+    cancel_request_id: [u8; 32],
+    tx_params: EvmTransactionParams,
+) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+    let config = &ctx.accounts.config;
+
+    require_executable(config, proposal)?;
     require!(
-        signature.recovery_id < 4,
-        crate::error::ErrorCode::InvalidSignature
+        matches!(
+            proposal.action,
+            ConfigAction::CancelWithdrawal { request_id: id } if id == request_id
+        ),
+        crate::error::ErrorCode::InvalidProposalAction
+    );
+
+    let pending = &mut ctx.accounts.pending_withdrawal;
+    config.require_withdrawal_timed_out(pending.created_slot)?;
+    require!(
+        pending.cancel_request_id.is_none(),
+        crate::error::ErrorCode::WithdrawalAlreadyCancelled
+    );
+
+    let caip2_id = format!("eip155:{}", pending.chain_id);
+    let settings = &ctx
+        .accounts
+        .chain_registry
+        .require_enabled(&caip2_id)?
+        .settings;
+    settings.require_gas_within_bounds(&tx_params)?;
+
+    require!(
+        tx_params.chain_id == pending.chain_id && tx_params.nonce == pending.nonce,
+        crate::error::ErrorCode::NonceMismatch
+    );
+    require!(
+        tx_params.value == 0,
+        crate::error::ErrorCode::NativeValueNotAllowed
+    );
+    pending.require_outbid(&tx_params, settings.max_fee_per_gas)?;
+
+    // The vault address of the withdrawal's key version, which signed the withdrawal
+    let vault_address =
+        crate::crypto::derive_vault_ethereum_address(config.root_public_key(pending.key_version)?)?;
+
+    let rlp_encoded_tx = TransactionBuilder::new::<EVM>()
+        .chain_id(tx_params.chain_id)
+        .nonce(tx_params.nonce)
+        .to(vault_address)
+        .value(0)
+        .input(Vec::new())
+        .gas_limit(tx_params.gas_limit)
+        .max_fee_per_gas(tx_params.max_fee_per_gas)
+        .max_priority_fee_per_gas(tx_params.max_priority_fee_per_gas)
+        .build()
+        .build_for_signing();
+
+    let path = pending.path.clone();
+    let key_version = pending.key_version;
+    let computed_request_id = generate_sign_bidirectional_request_id(
+        &ctx.accounts.requester.key(),
+        &rlp_encoded_tx,
+        &caip2_id,
+        key_version,
+        &path,
+        "ECDSA",
+        "ethereum",
+        "",
+    );
+
+    require!(
+        computed_request_id == cancel_request_id,
+        crate::error::ErrorCode::InvalidRequestId
+    );
+
+    pending.cancel_request_id = Some(cancel_request_id);
+    pending.max_fee_per_gas = tx_params.max_fee_per_gas;
+    pending.max_priority_fee_per_gas = tx_params.max_priority_fee_per_gas;
+
+    let requester_bump = ctx.bumps.requester;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"global_vault_authority", &[requester_bump]]];
+
+    request_evm_signature(
+        sign_bidirectional_cpi!(ctx.accounts, requester, signer_seeds),
+        rlp_encoded_tx,
+        caip2_id,
+        key_version,
+        path,
+        value_transfer_schemas()?,
+    )?;
+
+    emit!(ConfigChangeExecuted {
+        proposal_id,
+        action: proposal.action.clone(),
+    });
+
+    msg!(
+        "ERC20 withdrawal {:?} cancellation signed with request_id: {:?}",
+        request_id,
+        cancel_request_id
     );
 
-    // Prepare signature for secp256k1_recover
-    let mut sig_bytes = [0u8; 64];
-    sig_bytes[..32].copy_from_slice(&signature.big_r.x);
-    sig_bytes[32..].copy_from_slice(&signature.s);
+    Ok(())
+}
+
+/// Reads a response to one of a withdrawal's transactions. The cancellation is a
+/// value transfer, so only an error response reports its failure.
+fn withdrawal_failure_reason(
+    pending: &PendingErc20Withdrawal,
+    responded_request_id: &[u8; 32],
+    serialized_output: &[u8],
+) -> Result<Option<String>> {
+    if pending.is_cancellation(responded_request_id) {
+        Ok(error_reason(serialized_output))
+    } else {
+        transfer_failure_reason(serialized_output)
+    }
+}
+
+/// Verifies an MPC response to any of the transactions signed for a withdrawal,
+/// returning the request ID of the transaction it responds to.
+fn verify_withdrawal_response(
+    pending: &PendingErc20Withdrawal,
+    serialized_output: &[u8],
+    signature: &chain_signatures::Signature,
+    expected_address: &[u8; 20],
+    allow_high_recovery_ids: bool,
+) -> Result<[u8; 32]> {
+    let mut result = err!(crate::error::ErrorCode::InvalidSignature);
+    for request_id in pending.signed_request_ids() {
+        let message_hash = hash_message(request_id, serialized_output);
+        result = crate::crypto::verify_signature(
+            &message_hash,
+            signature,
+            expected_address,
+            allow_high_recovery_ids,
+        )
+        .map(|()| *request_id);
+        if result.is_ok() {
+            break;
+        }
+    }
 
-    // Recover the public key
-    let recovered_pubkey = secp256k1_recover(message_hash, signature.recovery_id, &sig_bytes)
-        .map_err(|_| crate::error::ErrorCode::InvalidSignature)?;
+    result
+}
 
-    // Convert public key to Ethereum address
-    // The recovered key is 64 bytes (without the 0x04 prefix)
-    let pubkey_bytes = recovered_pubkey.to_bytes();
+/// Returns a withdrawn amount and its protocol fee to the requester's balance.
+/// The gas fee is not refunded, since the vault paid for the transaction.
+fn refund_withdrawal(
+    pending: &PendingErc20Withdrawal,
+    balance: &mut UserErc20Balance,
+    token_registry: &mut TokenRegistry,
+) -> Result<()> {
+    let refund_total = pending
+        .amount
+        .checked_add(pending.protocol_fee)
+        .ok_or(crate::error::ErrorCode::Overflow)?;
+    balance.amount = balance
+        .amount
+        .checked_add(refund_total)
+        .ok_or(crate::error::ErrorCode::Overflow)?;
+    token_registry.liabilities.credit(pending.amount)?;
+    token_registry.accrued_fees = token_registry
+        .accrued_fees
+        .checked_add(pending.gas_fee)
+        .ok_or(crate::error::ErrorCode::Overflow)?;
 
-    // Hash the public key to get address
-    let pubkey_hash = keccak::hash(&pubkey_bytes);
-    let address_bytes = &pubkey_hash.to_bytes()[12..]; // Last 20 bytes
+    msg!("Balance refunded: {}", refund_total);
 
-    // Convert to hex string for comparison
-    let recovered_address = format!("0x{}", hex::encode(address_bytes));
+    Ok(())
+}
 
-    // Compare addresses (case-insensitive)
+/// Moves a balance held in a pre-upgrade account, which had no chain in its
+/// seeds, to the chain configured in `legacy_erc20_chain_id`.
+pub fn migrate_erc20_balance(
+    ctx: Context<MigrateErc20Balance>,
+    erc20_address: [u8; 20],
+) -> Result<()> {
     require!(
-        recovered_address.to_lowercase() == expected_address.to_lowercase(),
-        crate::error::ErrorCode::InvalidSignature
+        ctx.accounts.config.legacy_erc20_chain_id != 0,
+        crate::error::ErrorCode::MigrationDisabled
+    );
+
+    let amount = ctx.accounts.legacy_balance.amount;
+    let balance = &mut ctx.accounts.user_balance;
+    balance.amount = balance
+        .amount
+        .checked_add(amount)
+        .ok_or(crate::error::ErrorCode::Overflow)?;
+    ctx.accounts.token_registry.liabilities.credit(amount)?;
+
+    msg!(
+        "Migrated {} of ERC20 0x{} to chain {}",
+        amount,
+        hex::encode(erc20_address),
+        ctx.accounts.config.legacy_erc20_chain_id
+    );
+
+    Ok(())
+}
+
+/// Moves the token's accrued protocol fees into the treasury's balance, from
+/// where the treasury withdraws them like any other user.
+pub fn collect_erc20_fees(
+    ctx: Context<CollectErc20Fees>,
+    chain_id: u64,
+    erc20_address: [u8; 20],
+) -> Result<()> {
+    let token_registry = &mut ctx.accounts.token_registry;
+    let fees = token_registry.accrued_fees;
+    token_registry.accrued_fees = 0;
+
+    let balance = &mut ctx.accounts.treasury_balance;
+    balance.amount = balance
+        .amount
+        .checked_add(fees)
+        .ok_or(crate::error::ErrorCode::Overflow)?;
+
+    msg!(
+        "Collected {} of ERC20 0x{} on chain {} into the treasury",
+        fees,
+        hex::encode(erc20_address),
+        chain_id
     );
 
     Ok(())
@@ -419,6 +970,56 @@ fn verify_signature_from_address(
 
 // Helper functions
 
+/// Requests the MPC signature of an EVM transaction. The response is decoded with
+/// `schemas` (explorer, callback).
+fn request_evm_signature<'info>(
+    cpi_ctx: CpiContext<'_, '_, '_, 'info, SignBidirectional<'info>>,
+    rlp_encoded_tx: Vec<u8>,
+    caip2_id: String,
+    key_version: u32,
+    path: String,
+    (explorer_schema, callback_schema): (Vec<u8>, Vec<u8>),
+) -> Result<()> {
+    sign_bidirectional(
+        cpi_ctx,
+        rlp_encoded_tx,
+        caip2_id,
+        key_version,
+        path,
+        "ECDSA".to_string(),
+        "ethereum".to_string(),
+        "".to_string(),
+        crate::ID,
+        explorer_schema,
+        callback_schema,
+    )
+}
+
+/// Response schemas of an ERC20 `transfer`, which returns a bool.
+fn transfer_schemas() -> Result<(Vec<u8>, Vec<u8>)> {
+    let functions = IERC20::abi::functions();
+    let transfer_func = functions
+        .get("transfer")
+        .and_then(|funcs| funcs.first())
+        .ok_or(crate::error::ErrorCode::FunctionNotFound)?;
+
+    let explorer_schema = serde_json::to_vec(&transfer_func.outputs)
+        .map_err(|_| crate::error::ErrorCode::SerializationError)?;
+
+    let callback_schema = serde_json::to_vec(&serde_json::json!("bool"))
+        .map_err(|_| crate::error::ErrorCode::SerializationError)?;
+
+    Ok((explorer_schema, callback_schema))
+}
+
+/// Response schemas of a value transfer, which has no return data.
+fn value_transfer_schemas() -> Result<(Vec<u8>, Vec<u8>)> {
+    let schema = serde_json::to_vec(&serde_json::json!([]))
+        .map_err(|_| crate::error::ErrorCode::SerializationError)?;
+
+    Ok((schema.clone(), schema))
+}
+
 #[allow(clippy::too_many_arguments)]
 fn generate_sign_bidirectional_request_id(
     sender: &Pubkey,
@@ -447,6 +1048,31 @@ fn generate_sign_bidirectional_request_id(
     keccak::hash(&encoded).to_bytes()
 }
 
+fn build_withdrawal_tx(
+    erc20_address: [u8; 20],
+    amount: u128,
+    recipient_address: [u8; 20],
+    tx_params: &EvmTransactionParams,
+) -> Vec<u8> {
+    let call = IERC20::transferCall {
+        to: Address::from_slice(&recipient_address),
+        amount: U256::from(amount),
+    };
+
+    // The transaction is sent FROM the vault's root-path address
+    TransactionBuilder::new::<EVM>()
+        .chain_id(tx_params.chain_id)
+        .nonce(tx_params.nonce)
+        .to(erc20_address)
+        .value(0)
+        .input(call.abi_encode())
+        .gas_limit(tx_params.gas_limit)
+        .max_fee_per_gas(tx_params.max_fee_per_gas)
+        .max_priority_fee_per_gas(tx_params.max_priority_fee_per_gas)
+        .build()
+        .build_for_signing()
+}
+
 fn hash_message(request_id: &[u8; 32], serialized_output: &[u8]) -> [u8; 32] {
     let mut data = Vec::with_capacity(32 + serialized_output.len());
     data.extend_from_slice(request_id);
@@ -0,0 +1,415 @@
+use anchor_lang::prelude::*;
+
+use crate::contexts::{
+    ApproveConfigChange, CancelConfigChange, ExecuteChainChange, ExecuteConfigChange,
+    ExecuteEvmNonceChange, ExecuteTokenChange, ExecuteUtxoChange, ProposeConfigChange,
+};
+use crate::error::ErrorCode;
+use crate::events::{
+    ConfigChangeCancelled, ConfigChangeExecuted, ConfigChangeProposed, ConfigChangeQueued,
+};
+use crate::instructions::config;
+use crate::state::{
+    is_supported_caip2_id, ChainEntry, ConfigAction, ConfigProposal, Liabilities, RateLimitWindow,
+    VaultConfig, MAX_CHAINS,
+};
+
+pub fn propose_config_change(
+    ctx: Context<ProposeConfigChange>,
+    action: ConfigAction,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let proposal = &mut ctx.accounts.proposal;
+
+    proposal.id = config.proposal_count;
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.action = action;
+    // Proposing counts as the proposer's approval
+    proposal.approvals = vec![ctx.accounts.proposer.key()];
+    proposal.eta_slot = 0;
+
+    config.proposal_count = config
+        .proposal_count
+        .checked_add(1)
+        .ok_or(ErrorCode::Overflow)?;
+
+    emit!(ConfigChangeProposed {
+        proposal_id: proposal.id,
+        proposer: proposal.proposer,
+        action: proposal.action.clone(),
+    });
+
+    queue_if_approved(config, proposal)
+}
+
+pub fn approve_config_change(ctx: Context<ApproveConfigChange>, proposal_id: u64) -> Result<()> {
+    let signer = ctx.accounts.signer.key();
+    let proposal = &mut ctx.accounts.proposal;
+
+    require!(
+        !proposal.approvals.contains(&signer),
+        ErrorCode::AlreadyApproved
+    );
+    // Approvals from removed signers no longer count; dropping them keeps the
+    // list within MAX_ADMIN_SIGNERS
+    let config = &ctx.accounts.config;
+    proposal
+        .approvals
+        .retain(|approver| config.is_admin_signer(approver));
+    proposal.approvals.push(signer);
+
+    msg!("Config proposal {} approved by {}", proposal_id, signer);
+
+    queue_if_approved(config, proposal)
+}
+
+pub fn cancel_config_change(ctx: Context<CancelConfigChange>, proposal_id: u64) -> Result<()> {
+    emit!(ConfigChangeCancelled {
+        proposal_id,
+        cancelled_by: ctx.accounts.authority.key(),
+    });
+
+    Ok(())
+}
+
+pub fn execute_config_change(ctx: Context<ExecuteConfigChange>, proposal_id: u64) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let proposal = &ctx.accounts.proposal;
+
+    require_executable(config, proposal)?;
+
+    match proposal.action.clone() {
+        ConfigAction::AddKeyVersion {
+            key_version,
+            mpc_root_public_key,
+        } => config::add_key_version(config, key_version, mpc_root_public_key)?,
+        ConfigAction::SetActiveKeyVersion { key_version } => {
+            config::set_active_key_version(config, key_version)?
+        }
+        ConfigAction::SetPauseGuardian { pause_guardian } => {
+            config::set_pause_guardian(config, pause_guardian)?
+        }
+        ConfigAction::SetTreasury { treasury } => config::set_treasury(config, treasury)?,
+        ConfigAction::SetPause { scope, flags } => config::apply_pause(config, &scope, flags)?,
+        ConfigAction::SetAdminSigners {
+            admin_signers,
+            admin_threshold,
+        } => config::set_admin_signers(config, admin_signers, admin_threshold)?,
+        ConfigAction::SetTimelock { timelock_slots } => {
+            config::set_timelock(config, timelock_slots)?
+        }
+        ConfigAction::SetLegacyErc20ChainId { chain_id } => {
+            config::set_legacy_erc20_chain_id(config, chain_id)?
+        }
+        ConfigAction::SetLegacyBtcCaip2Id { caip2_id } => {
+            config::set_legacy_btc_caip2_id(config, caip2_id)?
+        }
+        ConfigAction::SetGlobalWithdrawalRateLimit { settings } => {
+            config::set_global_withdrawal_rate_limit(config, settings)?
+        }
+        ConfigAction::SetRateLimitOverride { user, expires_slot } => {
+            config::set_rate_limit_override(config, user, expires_slot)?
+        }
+        ConfigAction::SetAllowHighRecoveryIds { allowed } => {
+            config::set_allow_high_recovery_ids(config, allowed)?
+        }
+        ConfigAction::SetWithdrawalTimeout { timeout_slots } => {
+            config::set_withdrawal_timeout(config, timeout_slots)?
+        }
+        ConfigAction::SetCrankBounty { lamports } => config::set_crank_bounty(config, lamports)?,
+        ConfigAction::SetDepositTimeout { timeout_slots } => {
+            config::set_deposit_timeout(config, timeout_slots)?
+        }
+        ConfigAction::SetEvmVaultNonce { .. }
+        | ConfigAction::TransferEvmNative { .. }
+        | ConfigAction::CancelWithdrawal { .. }
+        | ConfigAction::SetToken { .. }
+        | ConfigAction::SetChain { .. }
+        | ConfigAction::RemoveChain { .. }
+        | ConfigAction::CreditChainLiabilities { .. }
+        | ConfigAction::AddVaultUtxos { .. }
+        | ConfigAction::RemoveVaultUtxos { .. }
+        | ConfigAction::SweepVaultUtxos { .. } => return err!(ErrorCode::InvalidProposalAction),
+    }
+
+    emit!(ConfigChangeExecuted {
+        proposal_id,
+        action: proposal.action.clone(),
+    });
+
+    Ok(())
+}
+
+pub fn execute_token_change(
+    ctx: Context<ExecuteTokenChange>,
+    proposal_id: u64,
+    chain_id: u64,
+    erc20_address: [u8; 20],
+) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+
+    require_executable(&ctx.accounts.config, proposal)?;
+
+    let ConfigAction::SetToken {
+        chain_id: proposed_chain_id,
+        erc20_address: proposed_erc20_address,
+        settings,
+    } = proposal.action.clone()
+    else {
+        return err!(ErrorCode::InvalidProposalAction);
+    };
+    require!(
+        proposed_chain_id == chain_id && proposed_erc20_address == erc20_address,
+        ErrorCode::InvalidProposalAction
+    );
+    require!(
+        settings.min_amount <= settings.max_amount,
+        ErrorCode::InvalidTokenSettings
+    );
+    require!(settings.fees.is_valid(), ErrorCode::InvalidFee);
+
+    // Tokens are counted on their chain, which can't be removed while any are
+    // registered, since their liabilities live in their own registry entries
+    let chain = ctx
+        .accounts
+        .chain_registry
+        .get_mut(&format!("eip155:{}", chain_id))
+        .ok_or(ErrorCode::UnsupportedChain)?;
+    let token_registry = &mut ctx.accounts.token_registry;
+    // A new entry is zeroed, and EVM chain IDs start at 1
+    if token_registry.chain_id == 0 {
+        chain.token_count = chain
+            .token_count
+            .checked_add(1)
+            .ok_or(ErrorCode::Overflow)?;
+    }
+    token_registry.chain_id = chain_id;
+    token_registry.erc20_address = erc20_address;
+    token_registry.settings = settings;
+
+    emit!(ConfigChangeExecuted {
+        proposal_id,
+        action: proposal.action.clone(),
+    });
+
+    Ok(())
+}
+
+pub fn execute_chain_change(ctx: Context<ExecuteChainChange>, proposal_id: u64) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+    let chains = &mut ctx.accounts.chain_registry.chains;
+
+    require_executable(&ctx.accounts.config, proposal)?;
+
+    match proposal.action.clone() {
+        ConfigAction::SetChain { caip2_id, settings } => {
+            require!(is_supported_caip2_id(&caip2_id), ErrorCode::InvalidCaip2Id);
+            require!(settings.fees.is_valid(), ErrorCode::InvalidFee);
+
+            if let Some(entry) = chains.iter_mut().find(|entry| entry.caip2_id == caip2_id) {
+                entry.settings = settings;
+            } else {
+                require!(chains.len() < MAX_CHAINS, ErrorCode::TooManyChains);
+                chains.push(ChainEntry {
+                    caip2_id,
+                    settings,
+                    withdrawal_window: RateLimitWindow::default(),
+                    liabilities: Liabilities::default(),
+                    accrued_fees: 0,
+                    token_count: 0,
+                });
+            }
+        }
+        ConfigAction::RemoveChain { caip2_id } => {
+            let index = chains
+                .iter()
+                .position(|entry| entry.caip2_id == caip2_id)
+                .ok_or(ErrorCode::UnsupportedChain)?;
+            // Removing the entry would drop what the vault owes on the chain;
+            // SetChain with `enabled: false` stops new requests instead
+            require!(
+                !chains[index].has_liabilities(),
+                ErrorCode::ChainHasLiabilities
+            );
+            chains.remove(index);
+        }
+        ConfigAction::CreditChainLiabilities { caip2_id, amount } => {
+            let entry = chains
+                .iter_mut()
+                .find(|entry| entry.caip2_id == caip2_id)
+                .ok_or(ErrorCode::UnsupportedChain)?;
+            entry.liabilities.credit(amount)?;
+        }
+        _ => return err!(ErrorCode::InvalidProposalAction),
+    }
+
+    emit!(ConfigChangeExecuted {
+        proposal_id,
+        action: proposal.action.clone(),
+    });
+
+    Ok(())
+}
+
+pub fn execute_evm_nonce_change(
+    ctx: Context<ExecuteEvmNonceChange>,
+    proposal_id: u64,
+    chain_id: u64,
+    key_version: u32,
+) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+
+    require_executable(&ctx.accounts.config, proposal)?;
+
+    let ConfigAction::SetEvmVaultNonce {
+        chain_id: proposed_chain_id,
+        key_version: proposed_key_version,
+        next_nonce,
+    } = proposal.action.clone()
+    else {
+        return err!(ErrorCode::InvalidProposalAction);
+    };
+    require!(
+        proposed_chain_id == chain_id && proposed_key_version == key_version,
+        ErrorCode::InvalidProposalAction
+    );
+    ctx.accounts.config.root_public_key(key_version)?;
+
+    let vault_nonce = &mut ctx.accounts.vault_nonce;
+    msg!(
+        "EVM vault nonce for chain {} and key version {} moved from {} to {}",
+        chain_id,
+        key_version,
+        vault_nonce.next_nonce,
+        next_nonce
+    );
+    vault_nonce.chain_id = chain_id;
+    vault_nonce.key_version = key_version;
+    vault_nonce.next_nonce = next_nonce;
+
+    emit!(ConfigChangeExecuted {
+        proposal_id,
+        action: proposal.action.clone(),
+    });
+
+    Ok(())
+}
+
+pub fn execute_utxo_change(
+    ctx: Context<ExecuteUtxoChange>,
+    proposal_id: u64,
+    caip2_id: String,
+) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+    let utxo_set = &mut ctx.accounts.vault_utxo_set;
+
+    require_executable(&ctx.accounts.config, proposal)?;
+
+    match proposal.action.clone() {
+        ConfigAction::AddVaultUtxos {
+            caip2_id: proposed_caip2_id,
+            utxos,
+        } => {
+            require!(
+                proposed_caip2_id == caip2_id,
+                ErrorCode::InvalidProposalAction
+            );
+            for utxo in utxos {
+                utxo_set.add(utxo.outpoint, utxo.value, utxo.key_version)?;
+            }
+        }
+        ConfigAction::RemoveVaultUtxos {
+            caip2_id: proposed_caip2_id,
+            outpoints,
+        } => {
+            require!(
+                proposed_caip2_id == caip2_id,
+                ErrorCode::InvalidProposalAction
+            );
+            for outpoint in &outpoints {
+                utxo_set.remove(outpoint)?;
+            }
+        }
+        _ => return err!(ErrorCode::InvalidProposalAction),
+    }
+    utxo_set.caip2_id = caip2_id;
+
+    emit!(ConfigChangeExecuted {
+        proposal_id,
+        action: proposal.action.clone(),
+    });
+
+    Ok(())
+}
+
+pub(crate) fn require_executable(config: &VaultConfig, proposal: &ConfigProposal) -> Result<()> {
+    // Approvals from signers removed since the vote no longer count
+    require!(
+        config.count_approvals(&proposal.approvals) >= config.admin_threshold as usize,
+        ErrorCode::ThresholdNotMet
+    );
+    require!(
+        proposal.eta_slot != 0 && Clock::get()?.slot >= proposal.eta_slot,
+        ErrorCode::TimelockNotElapsed
+    );
+
+    Ok(())
+}
+
+/// Starts the timelock once the proposal reaches the approval threshold.
+fn queue_if_approved(config: &VaultConfig, proposal: &mut ConfigProposal) -> Result<()> {
+    if proposal.eta_slot != 0
+        || config.count_approvals(&proposal.approvals) < config.admin_threshold as usize
+    {
+        return Ok(());
+    }
+
+    proposal.eta_slot = Clock::get()?
+        .slot
+        .checked_add(config.timelock_slots)
+        .ok_or(ErrorCode::Overflow)?;
+
+    emit!(ConfigChangeQueued {
+        proposal_id: proposal.id,
+        eta_slot: proposal.eta_slot,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_require_executable_counts_current_signers() {
+        let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let config = VaultConfig {
+            admin_signers: signers.to_vec(),
+            admin_threshold: 2,
+            ..Default::default()
+        };
+        let proposal = |approvals: Vec<Pubkey>| ConfigProposal {
+            id: 0,
+            proposer: signers[0],
+            action: ConfigAction::SetTimelock { timelock_slots: 0 },
+            approvals,
+            eta_slot: 0,
+        };
+
+        assert_eq!(
+            require_executable(&config, &proposal(vec![signers[0]])).unwrap_err(),
+            ErrorCode::ThresholdNotMet.into()
+        );
+        assert_eq!(
+            require_executable(&config, &proposal(vec![signers[0], Pubkey::new_unique()]))
+                .unwrap_err(),
+            ErrorCode::ThresholdNotMet.into()
+        );
+        // Enough approvals, but the proposal was never queued
+        assert_eq!(
+            require_executable(&config, &proposal(signers.to_vec())).unwrap_err(),
+            ErrorCode::TimelockNotElapsed.into()
+        );
+    }
+}
@@ -1,5 +1,29 @@
+/// Builds the `sign_bidirectional` CPI context from a signing context's chain
+/// signatures accounts, signed by its `$requester` PDA.
+macro_rules! sign_bidirectional_cpi {
+    ($accounts:expr, $requester:ident, $signer_seeds:expr) => {
+        CpiContext::new_with_signer(
+            $accounts.chain_signatures_program.to_account_info(),
+            SignBidirectional {
+                program_state: $accounts.chain_signatures_state.to_account_info(),
+                requester: $accounts.$requester.to_account_info(),
+                fee_payer: $accounts.fee_payer.as_ref().map(|fp| fp.to_account_info()),
+                system_program: $accounts.system_program.to_account_info(),
+                instructions: $accounts.instructions.as_ref().map(|i| i.to_account_info()),
+                event_authority: $accounts.event_authority.to_account_info(),
+                program: $accounts.chain_signatures_program.to_account_info(),
+            },
+            $signer_seeds,
+        )
+    };
+}
+
 pub mod btc_vault;
+pub mod config;
 pub mod erc20_vault;
+pub mod governance;
 
 pub use btc_vault::*;
+pub use config::*;
 pub use erc20_vault::*;
+pub use governance::*;
@@ -5,6 +5,7 @@ pub mod constants;
 pub mod contexts;
 pub mod crypto;
 pub mod error;
+pub mod events;
 pub mod instructions;
 pub mod state;
 
@@ -22,10 +23,98 @@ pub mod solana_core_contracts {
     pub fn initialize_config(
         ctx: Context<InitializeConfig>,
         mpc_root_public_key: [u8; 64],
+        key_version: u32,
     ) -> Result<()> {
-        let config = &mut ctx.accounts.config;
-        config.mpc_root_public_key = mpc_root_public_key;
-        Ok(())
+        instructions::config::initialize_config(ctx, mpc_root_public_key, key_version)
+    }
+
+    /// Opens a config proposal. Every privileged change to the vault goes through
+    /// a proposal approved by `admin_threshold` of the admin signers, then waits
+    /// `timelock_slots` before it can be executed.
+    pub fn propose_config_change(
+        ctx: Context<ProposeConfigChange>,
+        action: ConfigAction,
+    ) -> Result<()> {
+        instructions::governance::propose_config_change(ctx, action)
+    }
+
+    pub fn approve_config_change(
+        ctx: Context<ApproveConfigChange>,
+        proposal_id: u64,
+    ) -> Result<()> {
+        instructions::governance::approve_config_change(ctx, proposal_id)
+    }
+
+    /// Lets the pause guardian drop a proposal during its timelock window.
+    pub fn cancel_config_change(ctx: Context<CancelConfigChange>, proposal_id: u64) -> Result<()> {
+        instructions::governance::cancel_config_change(ctx, proposal_id)
+    }
+
+    pub fn execute_config_change(
+        ctx: Context<ExecuteConfigChange>,
+        proposal_id: u64,
+    ) -> Result<()> {
+        instructions::governance::execute_config_change(ctx, proposal_id)
+    }
+
+    /// Executes a SetToken proposal, creating the token's registry entry if needed.
+    pub fn execute_token_change(
+        ctx: Context<ExecuteTokenChange>,
+        proposal_id: u64,
+        chain_id: u64,
+        erc20_address: [u8; 20],
+    ) -> Result<()> {
+        instructions::governance::execute_token_change(ctx, proposal_id, chain_id, erc20_address)
+    }
+
+    /// Executes a SetChain, RemoveChain or CreditChainLiabilities proposal against the
+    /// chain registry.
+    pub fn execute_chain_change(ctx: Context<ExecuteChainChange>, proposal_id: u64) -> Result<()> {
+        instructions::governance::execute_chain_change(ctx, proposal_id)
+    }
+
+    /// Executes a SetEvmVaultNonce proposal, creating the nonce account of the chain
+    /// and key version if needed.
+    pub fn execute_evm_nonce_change(
+        ctx: Context<ExecuteEvmNonceChange>,
+        proposal_id: u64,
+        chain_id: u64,
+        key_version: u32,
+    ) -> Result<()> {
+        instructions::governance::execute_evm_nonce_change(ctx, proposal_id, chain_id, key_version)
+    }
+
+    /// Executes an AddVaultUtxos or RemoveVaultUtxos proposal against the chain's
+    /// vault UTXO set, creating the set if needed.
+    pub fn execute_utxo_change(
+        ctx: Context<ExecuteUtxoChange>,
+        proposal_id: u64,
+        caip2_id: String,
+    ) -> Result<()> {
+        instructions::governance::execute_utxo_change(ctx, proposal_id, caip2_id)
+    }
+
+    /// Executes a TransferEvmNative proposal, signing a native value transfer from
+    /// the EVM vault address with the chain's vault nonce.
+    pub fn execute_evm_native_transfer(
+        ctx: Context<ExecuteEvmNativeTransfer>,
+        proposal_id: u64,
+        request_id: [u8; 32],
+        tx_params: EvmTransactionParams,
+    ) -> Result<()> {
+        instructions::erc20_vault::execute_evm_native_transfer(
+            ctx,
+            proposal_id,
+            request_id,
+            tx_params,
+        )
+    }
+
+    /// Sets pause switches globally or for one chain. The pause guardian and admin
+    /// signers may only pause here; unpausing requires a SetPause proposal.
+    /// Claims and completions are never paused.
+    pub fn set_pause(ctx: Context<SetPause>, scope: PauseScope, flags: PauseFlags) -> Result<()> {
+        instructions::config::set_pause(ctx, scope, flags)
     }
 
     pub fn deposit_erc20(
@@ -33,7 +122,6 @@ pub mod solana_core_contracts {
         request_id: [u8; 32],
         requester: Pubkey,
         erc20_address: [u8; 20],
-        recipient_address: [u8; 20],
         amount: u128,
         tx_params: EvmTransactionParams,
     ) -> Result<()> {
@@ -42,7 +130,6 @@ pub mod solana_core_contracts {
             request_id,
             requester,
             erc20_address,
-            recipient_address,
             amount,
             tx_params,
         )
@@ -62,6 +149,30 @@ pub mod solana_core_contracts {
         )
     }
 
+    /// Closes a deposit the MPC network reported as failed, keeping a receipt with the reason.
+    pub fn finalize_failed_deposit_erc20(
+        ctx: Context<FinalizeFailedDepositErc20>,
+        request_id: [u8; 32],
+        serialized_output: Vec<u8>,
+        signature: Signature,
+    ) -> Result<()> {
+        instructions::erc20_vault::finalize_failed_deposit_erc20(
+            ctx,
+            request_id,
+            serialized_output,
+            signature,
+        )
+    }
+
+    /// Releases the deposit cap reservation of a deposit that got no response
+    /// within the deposit timeout; the deposit can still be claimed.
+    pub fn release_stalled_deposit_erc20(
+        ctx: Context<ReleaseStalledDepositErc20>,
+        request_id: [u8; 32],
+    ) -> Result<()> {
+        instructions::erc20_vault::release_stalled_deposit_erc20(ctx, request_id)
+    }
+
     pub fn withdraw_erc20(
         ctx: Context<WithdrawErc20>,
         request_id: [u8; 32],
@@ -80,6 +191,21 @@ pub mod solana_core_contracts {
         )
     }
 
+    /// Re-signs a stuck withdrawal with higher fees and the same vault nonce.
+    pub fn replace_withdraw_erc20(
+        ctx: Context<ReplaceWithdrawErc20>,
+        request_id: [u8; 32],
+        new_request_id: [u8; 32],
+        tx_params: EvmTransactionParams,
+    ) -> Result<()> {
+        instructions::erc20_vault::replace_withdraw_erc20(
+            ctx,
+            request_id,
+            new_request_id,
+            tx_params,
+        )
+    }
+
     pub fn complete_withdraw_erc20(
         ctx: Context<CompleteWithdrawErc20>,
         request_id: [u8; 32],
@@ -94,15 +220,67 @@ pub mod solana_core_contracts {
         )
     }
 
+    /// Records a failure of one of a replaced withdrawal's transactions; the
+    /// withdrawal is refunded once all of them have failed.
+    pub fn record_failed_withdraw_erc20(
+        ctx: Context<RecordFailedWithdrawErc20>,
+        request_id: [u8; 32],
+        serialized_output: Vec<u8>,
+        signature: Signature,
+    ) -> Result<()> {
+        instructions::erc20_vault::record_failed_withdraw_erc20(
+            ctx,
+            request_id,
+            serialized_output,
+            signature,
+        )
+    }
+
+    /// Executes a `CancelWithdrawal` proposal by signing a zero-value self-transfer
+    /// with the withdrawal's nonce. Its success response refunds the withdrawal
+    /// through `complete_withdraw_erc20`.
+    pub fn cancel_withdraw_erc20(
+        ctx: Context<CancelWithdrawErc20>,
+        proposal_id: u64,
+        request_id: [u8; 32],
+        cancel_request_id: [u8; 32],
+        tx_params: EvmTransactionParams,
+    ) -> Result<()> {
+        instructions::erc20_vault::cancel_withdraw_erc20(
+            ctx,
+            proposal_id,
+            request_id,
+            cancel_request_id,
+            tx_params,
+        )
+    }
+
+    /// Moves a pre-upgrade ERC20 balance to its per-chain balance account.
+    pub fn migrate_erc20_balance(
+        ctx: Context<MigrateErc20Balance>,
+        erc20_address: [u8; 20],
+    ) -> Result<()> {
+        instructions::erc20_vault::migrate_erc20_balance(ctx, erc20_address)
+    }
+
+    /// Moves accrued ERC20 protocol fees into the treasury balance.
+    pub fn collect_erc20_fees(
+        ctx: Context<CollectErc20Fees>,
+        chain_id: u64,
+        erc20_address: [u8; 20],
+    ) -> Result<()> {
+        instructions::erc20_vault::collect_erc20_fees(ctx, chain_id, erc20_address)
+    }
+
     pub fn deposit_btc(
         ctx: Context<DepositBtc>,
         request_id: [u8; 32],
         requester: Pubkey,
         inputs: Vec<BtcInput>,
-        outputs: Vec<BtcOutput>,
+        amount: u64,
         tx_params: BtcDepositParams,
     ) -> Result<()> {
-        instructions::btc_vault::deposit_btc(ctx, request_id, requester, inputs, outputs, tx_params)
+        instructions::btc_vault::deposit_btc(ctx, request_id, requester, inputs, amount, tx_params)
     }
 
     pub fn claim_btc(
@@ -119,22 +297,38 @@ pub mod solana_core_contracts {
         )
     }
 
+    /// Closes a deposit the MPC network reported as failed, keeping a receipt with the reason.
+    pub fn finalize_failed_deposit_btc(
+        ctx: Context<FinalizeFailedDepositBtc>,
+        request_id: [u8; 32],
+        serialized_output: Vec<u8>,
+        signature: Signature,
+    ) -> Result<()> {
+        instructions::btc_vault::finalize_failed_deposit_btc(
+            ctx,
+            request_id,
+            serialized_output,
+            signature,
+        )
+    }
+
+    /// Releases the deposit cap reservation of a deposit that got no response
+    /// within the deposit timeout; the deposit can still be claimed.
+    pub fn release_stalled_deposit_btc(
+        ctx: Context<ReleaseStalledDepositBtc>,
+        request_id: [u8; 32],
+    ) -> Result<()> {
+        instructions::btc_vault::release_stalled_deposit_btc(ctx, request_id)
+    }
+
     pub fn withdraw_btc(
         ctx: Context<WithdrawBtc>,
         request_id: [u8; 32],
-        inputs: Vec<BtcInput>,
         amount: u64,
         recipient_address: String,
         tx_params: BtcWithdrawParams,
     ) -> Result<()> {
-        instructions::btc_vault::withdraw_btc(
-            ctx,
-            request_id,
-            inputs,
-            amount,
-            recipient_address,
-            tx_params,
-        )
+        instructions::btc_vault::withdraw_btc(ctx, request_id, amount, recipient_address, tx_params)
     }
 
     pub fn complete_withdraw_btc(
@@ -150,4 +344,74 @@ pub mod solana_core_contracts {
             signature,
         )
     }
+
+    /// Records a failure of a cancelled withdrawal's transaction or its
+    /// cancellation; the withdrawal is refunded once both have failed.
+    pub fn record_failed_withdraw_btc(
+        ctx: Context<RecordFailedWithdrawBtc>,
+        request_id: [u8; 32],
+        serialized_output: Vec<u8>,
+        signature: Signature,
+    ) -> Result<()> {
+        instructions::btc_vault::record_failed_withdraw_btc(
+            ctx,
+            request_id,
+            serialized_output,
+            signature,
+        )
+    }
+
+    /// Executes a `CancelWithdrawal` proposal, signing a transaction that spends a
+    /// timed-out withdrawal's inputs back to the vault. Its success response refunds
+    /// the withdrawal.
+    pub fn cancel_withdraw_btc(
+        ctx: Context<CancelWithdrawBtc>,
+        proposal_id: u64,
+        request_id: [u8; 32],
+        cancel_request_id: [u8; 32],
+        fee_rate: u64,
+    ) -> Result<()> {
+        instructions::btc_vault::cancel_withdraw_btc(
+            ctx,
+            proposal_id,
+            request_id,
+            cancel_request_id,
+            fee_rate,
+        )
+    }
+
+    /// Executes a `SweepVaultUtxos` proposal, signing a transaction that merges
+    /// vault UTXOs into one output to free slots in the vault UTXO set.
+    pub fn sweep_btc(
+        ctx: Context<SweepBtc>,
+        proposal_id: u64,
+        request_id: [u8; 32],
+        caip2_id: String,
+    ) -> Result<()> {
+        instructions::btc_vault::sweep_btc(ctx, proposal_id, request_id, caip2_id)
+    }
+
+    pub fn complete_sweep_btc(
+        ctx: Context<CompleteSweepBtc>,
+        request_id: [u8; 32],
+        serialized_output: Vec<u8>,
+        signature: Signature,
+    ) -> Result<()> {
+        instructions::btc_vault::complete_sweep_btc(
+            ctx,
+            request_id,
+            serialized_output,
+            signature,
+        )
+    }
+
+    /// Moves a pre-upgrade BTC balance to its per-chain balance account.
+    pub fn migrate_btc_balance(ctx: Context<MigrateBtcBalance>) -> Result<()> {
+        instructions::btc_vault::migrate_btc_balance(ctx)
+    }
+
+    /// Moves accrued BTC protocol fees into the treasury balance.
+    pub fn collect_btc_fees(ctx: Context<CollectBtcFees>, caip2_id: String) -> Result<()> {
+        instructions::btc_vault::collect_btc_fees(ctx, caip2_id)
+    }
 }
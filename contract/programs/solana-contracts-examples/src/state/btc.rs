@@ -1,5 +1,29 @@
 use anchor_lang::prelude::*;
 
+use crate::error::ErrorCode;
+use crate::state::{MAX_CAIP2_ID_LEN, MAX_FAILURE_REASON_LEN};
+
+/// Smallest P2WPKH output Bitcoin Core relays at the default dust relay fee
+pub const P2WPKH_DUST_LIMIT: u64 = 294;
+
+/// Maximum number of UTXOs tracked in a vault UTXO set
+pub const MAX_VAULT_UTXOS: usize = 100;
+
+/// Maximum number of UTXOs added or removed by one proposal
+pub const MAX_UTXO_CHANGES: usize = 8;
+
+/// Maximum number of vault UTXOs one withdrawal may spend
+pub const MAX_WITHDRAWAL_INPUTS: usize = 10;
+
+/// Lowest withdrawal fee rate, in sat/vB, that nodes relay by default
+pub const MIN_WITHDRAWAL_FEE_RATE: u64 = 1;
+
+// Weight units of a withdrawal's parts: version, counts, lock time and the segwit
+// marker; and a P2WPKH input with its signature and public key witness
+const TX_OVERHEAD_WEIGHT: u64 = 42;
+const P2WPKH_INPUT_WEIGHT: u64 = 272;
+const P2WPKH_SCRIPT_LEN: usize = 22;
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct BtcInput {
     pub txid: [u8; 32],
@@ -8,26 +32,22 @@ pub struct BtcInput {
     pub value: u64,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
-pub struct BtcOutput {
-    pub script_pubkey: Vec<u8>,
-    pub value: u64,
-}
-
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct BtcDepositParams {
     pub lock_time: u32,
     pub caip2_id: String,
-    pub vault_script_pubkey: Vec<u8>,
+    /// Network fee paid from the requester's inputs, bounded by the chain's
+    /// `max_deposit_network_fee`
+    pub fee: u64,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct BtcWithdrawParams {
     pub lock_time: u32,
     pub caip2_id: String,
-    pub vault_script_pubkey: Vec<u8>,
     pub recipient_script_pubkey: Vec<u8>,
-    pub fee: u64,
+    /// Network fee rate in sat/vB, at least `MIN_WITHDRAWAL_FEE_RATE`
+    pub fee_rate: u64,
 }
 
 #[account]
@@ -35,9 +55,39 @@ pub struct BtcWithdrawParams {
 pub struct PendingBtcDeposit {
     pub requester: Pubkey,
     pub amount: u64,
+    #[max_len(MAX_CAIP2_ID_LEN)]
+    pub caip2_id: String,
     #[max_len(64)]
     pub path: String,
     pub request_id: [u8; 32],
+    /// MPC key version the request was signed under
+    pub key_version: u32,
+    /// Protocol fee charged on `amount`
+    pub protocol_fee: u64,
+    /// Paid the account's rent and gets it back when the account is closed
+    pub rent_payer: Pubkey,
+    /// Deposit transaction ID in explorer byte order; its output 0 pays the vault
+    pub txid: [u8; 32],
+    /// Slot the deposit was requested in, checked against the deposit timeout
+    pub created_slot: u64,
+    /// Set once the deposit's reservation against the deposit cap was released
+    /// by `release_stalled_deposit_btc`
+    pub reservation_released: bool,
+}
+
+/// Receipt for a deposit the MPC network reported as failed, seeded by
+/// `[b"failed_btc_deposit", request_id]`
+#[account]
+#[derive(InitSpace)]
+pub struct FailedBtcDeposit {
+    pub requester: Pubkey,
+    pub amount: u64,
+    #[max_len(MAX_CAIP2_ID_LEN)]
+    pub caip2_id: String,
+    /// Failure reported in the signed response
+    #[max_len(MAX_FAILURE_REASON_LEN)]
+    pub reason: String,
+    pub failed_slot: u64,
 }
 
 #[account]
@@ -46,11 +96,103 @@ pub struct PendingBtcWithdrawal {
     pub requester: Pubkey,
     pub amount: u64,
     pub fee: u64,
+    #[max_len(MAX_CAIP2_ID_LEN)]
+    pub caip2_id: String,
     #[max_len(64)]
     pub recipient_address: String,
     #[max_len(64)]
     pub path: String,
     pub request_id: [u8; 32],
+    /// MPC key version the request was signed under
+    pub key_version: u32,
+    /// Protocol fee charged on `amount`
+    pub protocol_fee: u64,
+    /// Slot the withdrawal was requested in, checked against the withdrawal timeout
+    pub created_slot: u64,
+    /// Paid the account's rent and gets it back when the account is closed
+    pub rent_payer: Pubkey,
+    /// Withdrawal transaction ID in explorer byte order
+    pub txid: [u8; 32],
+    /// Value of the change output (output 1) returned to the vault, 0 if none
+    pub change: u64,
+    /// Vault UTXOs spent by the transaction, in input order
+    #[max_len(MAX_WITHDRAWAL_INPUTS)]
+    pub inputs: Vec<BtcOutpoint>,
+    /// Request ID of the transaction signed by `cancel_withdraw_btc`, which spends
+    /// `inputs` back to the vault. Its success refunds the withdrawal, since the
+    /// withdrawal transaction can no longer confirm
+    pub cancel_request_id: Option<[u8; 32]>,
+    /// Cancellation transaction ID in explorer byte order; its output 0 pays the vault
+    pub cancel_txid: [u8; 32],
+    /// Network fee of the cancellation, taken from the refund
+    pub cancel_fee: u64,
+    /// Value of the cancellation's output returned to the vault
+    pub cancel_output: u64,
+    /// Signed transactions the MPC network reported as failed
+    #[max_len(2)]
+    pub failed_request_ids: Vec<[u8; 32]>,
+}
+
+impl PendingBtcWithdrawal {
+    /// Request IDs of the withdrawal transaction and its cancellation, if signed.
+    /// They spend the same inputs, so at most one of them can confirm.
+    pub fn signed_request_ids(&self) -> impl Iterator<Item = &[u8; 32]> {
+        std::iter::once(&self.request_id).chain(self.cancel_request_id.iter())
+    }
+
+    /// True if `request_id` is the withdrawal's cancellation transaction.
+    pub fn is_cancellation(&self, request_id: &[u8; 32]) -> bool {
+        self.cancel_request_id.as_ref() == Some(request_id)
+    }
+
+    /// True once every signed transaction other than `request_id` was reported
+    /// failed. Until then the other one may still confirm, so a failure alone
+    /// doesn't mean the inputs are unspent.
+    pub fn siblings_failed(&self, request_id: &[u8; 32]) -> bool {
+        self.signed_request_ids()
+            .all(|id| id == request_id || self.failed_request_ids.contains(id))
+    }
+
+    /// Network fee of a cancellation at `fee_rate` sat/vB. Nodes only replace the
+    /// withdrawal transaction with one paying its fee plus the minimum relay fee
+    /// on the replacement's own size.
+    pub fn cancellation_fee(&self, fee_rate: u64) -> Result<u64> {
+        let vsize = withdrawal_vsize(self.inputs.len(), P2WPKH_SCRIPT_LEN, false);
+        let fee = fee_rate.checked_mul(vsize).ok_or(ErrorCode::Overflow)?;
+        let min_fee = MIN_WITHDRAWAL_FEE_RATE
+            .checked_mul(vsize)
+            .and_then(|relay_fee| relay_fee.checked_add(self.fee))
+            .ok_or(ErrorCode::Overflow)?;
+        require!(fee >= min_fee, ErrorCode::ReplacementUnderpriced);
+
+        Ok(fee)
+    }
+}
+
+/// A governed transaction merging vault UTXOs into one output back to the vault,
+/// seeded by `[b"pending_btc_sweep", request_id]`
+#[account]
+#[derive(InitSpace)]
+pub struct PendingBtcSweep {
+    #[max_len(MAX_CAIP2_ID_LEN)]
+    pub caip2_id: String,
+    pub request_id: [u8; 32],
+    /// MPC key version the request was signed under
+    pub key_version: u32,
+    /// Paid the account's rent and gets it back when the account is closed
+    pub rent_payer: Pubkey,
+    /// MPC key version whose vault script output 0 pays to, the active one when
+    /// the sweep was signed
+    pub output_key_version: u32,
+    /// Sweep transaction ID in explorer byte order; its output 0 pays the vault
+    pub txid: [u8; 32],
+    /// Value of output 0
+    pub output: u64,
+    /// Network fee, taken from the chain's accrued fees
+    pub fee: u64,
+    /// Vault UTXOs spent by the transaction, in input order
+    #[max_len(MAX_WITHDRAWAL_INPUTS)]
+    pub inputs: Vec<BtcOutpoint>,
 }
 
 #[account]
@@ -58,3 +200,418 @@ pub struct PendingBtcWithdrawal {
 pub struct UserBtcBalance {
     pub amount: u64,
 }
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct BtcOutpoint {
+    /// Transaction ID in explorer byte order, as in `BtcInput`
+    pub txid: [u8; 32],
+    pub vout: u32,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace)]
+pub struct VaultUtxo {
+    pub outpoint: BtcOutpoint,
+    pub value: u64,
+    /// MPC key version whose vault script the output pays to
+    pub key_version: u32,
+    /// Request ID of the pending withdrawal spending it
+    pub reserved_for: Option<[u8; 32]>,
+}
+
+/// Inputs chosen for a withdrawal by `VaultUtxoSet::select`
+#[derive(Debug)]
+pub struct CoinSelection {
+    pub inputs: Vec<VaultUtxo>,
+    pub fee: u64,
+    pub change: u64,
+}
+
+/// Virtual size of a withdrawal spending `inputs` vault UTXOs, rounded up.
+pub fn withdrawal_vsize(inputs: usize, recipient_script_len: usize, with_change: bool) -> u64 {
+    let output_weight = |script_len: usize| {
+        let len_prefix = if script_len < 0xfd { 1 } else { 3 };
+        4 * (8 + len_prefix + script_len as u64)
+    };
+
+    let mut weight = TX_OVERHEAD_WEIGHT
+        + inputs as u64 * P2WPKH_INPUT_WEIGHT
+        + output_weight(recipient_script_len);
+    if with_change {
+        weight += output_weight(P2WPKH_SCRIPT_LEN);
+    }
+
+    weight.div_ceil(4)
+}
+
+/// Outputs held by the BTC vault on one chain, seeded by
+/// `[b"vault_utxo_set", caip2_id_seed(caip2_id)]`. Claims add deposit outputs,
+/// withdrawals reserve what they spend, and completions consume the reserved
+/// outputs and add the change.
+#[account]
+#[derive(InitSpace)]
+pub struct VaultUtxoSet {
+    #[max_len(MAX_CAIP2_ID_LEN)]
+    pub caip2_id: String,
+    #[max_len(MAX_VAULT_UTXOS)]
+    pub utxos: Vec<VaultUtxo>,
+}
+
+impl VaultUtxoSet {
+    pub fn is_full(&self) -> bool {
+        self.utxos.len() >= MAX_VAULT_UTXOS
+    }
+
+    /// Adds an unreserved UTXO.
+    pub fn add(&mut self, outpoint: BtcOutpoint, value: u64, key_version: u32) -> Result<()> {
+        require!(!self.is_full(), ErrorCode::VaultUtxoSetFull);
+        require!(
+            !self.utxos.iter().any(|utxo| utxo.outpoint == outpoint),
+            ErrorCode::DuplicateUtxo
+        );
+
+        self.utxos.push(VaultUtxo {
+            outpoint,
+            value,
+            key_version,
+            reserved_for: None,
+        });
+
+        Ok(())
+    }
+
+    /// Returns the UTXO at `outpoint`.
+    pub fn get(&self, outpoint: &BtcOutpoint) -> Result<&VaultUtxo> {
+        self.utxos
+            .iter()
+            .find(|utxo| utxo.outpoint == *outpoint)
+            .ok_or_else(|| error!(ErrorCode::UtxoNotRegistered))
+    }
+
+    /// Removes an unreserved UTXO.
+    pub fn remove(&mut self, outpoint: &BtcOutpoint) -> Result<()> {
+        let index = self
+            .utxos
+            .iter()
+            .position(|utxo| utxo.outpoint == *outpoint)
+            .ok_or(ErrorCode::UtxoNotRegistered)?;
+        require!(
+            self.utxos[index].reserved_for.is_none(),
+            ErrorCode::UtxoReserved
+        );
+        self.utxos.remove(index);
+
+        Ok(())
+    }
+
+    /// Reserves an unreserved UTXO for `request_id` and returns it.
+    pub fn reserve(&mut self, outpoint: &BtcOutpoint, request_id: [u8; 32]) -> Result<VaultUtxo> {
+        let utxo = self
+            .utxos
+            .iter_mut()
+            .find(|utxo| utxo.outpoint == *outpoint)
+            .ok_or(ErrorCode::UtxoNotRegistered)?;
+        require!(utxo.reserved_for.is_none(), ErrorCode::UtxoReserved);
+        utxo.reserved_for = Some(request_id);
+
+        Ok(*utxo)
+    }
+
+    /// Selects the inputs of a withdrawal paying `amount` to a script of
+    /// `recipient_script_len` bytes at `fee_rate` sat/vB.
+    ///
+    /// Unreserved UTXOs under `key_version` are taken largest first, ties broken by
+    /// outpoint, until they cover the amount and fee. The result depends only on the
+    /// set, so clients can compute the transaction and its request ID in advance.
+    /// Change below the dust limit is left to the miner as part of the fee.
+    pub fn select(
+        &self,
+        key_version: u32,
+        amount: u64,
+        fee_rate: u64,
+        recipient_script_len: usize,
+    ) -> Result<CoinSelection> {
+        let mut candidates: Vec<&VaultUtxo> = self
+            .utxos
+            .iter()
+            .filter(|utxo| utxo.reserved_for.is_none() && utxo.key_version == key_version)
+            .collect();
+        candidates.sort_by(|a, b| {
+            b.value
+                .cmp(&a.value)
+                .then_with(|| a.outpoint.txid.cmp(&b.outpoint.txid))
+                .then_with(|| a.outpoint.vout.cmp(&b.outpoint.vout))
+        });
+
+        let fee_for = |inputs: usize, with_change: bool| {
+            fee_rate
+                .checked_mul(withdrawal_vsize(inputs, recipient_script_len, with_change))
+                .ok_or(ErrorCode::Overflow)
+        };
+
+        let mut inputs = Vec::new();
+        let mut total = 0u64;
+        for utxo in candidates {
+            require!(
+                inputs.len() < MAX_WITHDRAWAL_INPUTS,
+                ErrorCode::TooManyWithdrawalInputs
+            );
+            inputs.push(*utxo);
+            total = total.checked_add(utxo.value).ok_or(ErrorCode::Overflow)?;
+
+            let Some(spare) = total.checked_sub(amount) else {
+                continue;
+            };
+            let fee_with_change = fee_for(inputs.len(), true)?;
+            if spare >= fee_with_change.saturating_add(P2WPKH_DUST_LIMIT) {
+                return Ok(CoinSelection {
+                    inputs,
+                    fee: fee_with_change,
+                    change: spare - fee_with_change,
+                });
+            }
+            if spare >= fee_for(inputs.len(), false)? {
+                return Ok(CoinSelection {
+                    inputs,
+                    fee: spare,
+                    change: 0,
+                });
+            }
+        }
+
+        err!(ErrorCode::InsufficientVaultUtxos)
+    }
+
+    /// Selects the inputs of a sweep merging UTXOs into one output to the vault at
+    /// `fee_rate` sat/vB, returned as `change`.
+    ///
+    /// Up to `MAX_WITHDRAWAL_INPUTS` unreserved UTXOs under `key_version` are taken
+    /// smallest first, ties broken by outpoint, so each sweep frees the most slots
+    /// and withdrawals keep the largest outputs.
+    pub fn select_sweep(&self, key_version: u32, fee_rate: u64) -> Result<CoinSelection> {
+        let mut candidates: Vec<&VaultUtxo> = self
+            .utxos
+            .iter()
+            .filter(|utxo| utxo.reserved_for.is_none() && utxo.key_version == key_version)
+            .collect();
+        candidates.sort_by(|a, b| {
+            a.value
+                .cmp(&b.value)
+                .then_with(|| a.outpoint.txid.cmp(&b.outpoint.txid))
+                .then_with(|| a.outpoint.vout.cmp(&b.outpoint.vout))
+        });
+
+        let inputs: Vec<VaultUtxo> = candidates
+            .into_iter()
+            .take(MAX_WITHDRAWAL_INPUTS)
+            .copied()
+            .collect();
+        require!(!inputs.is_empty(), ErrorCode::InsufficientVaultUtxos);
+
+        let total = inputs.iter().try_fold(0u64, |total, utxo| {
+            total.checked_add(utxo.value).ok_or(ErrorCode::Overflow)
+        })?;
+        let fee = fee_rate
+            .checked_mul(withdrawal_vsize(inputs.len(), P2WPKH_SCRIPT_LEN, false))
+            .ok_or(ErrorCode::Overflow)?;
+        let change = total
+            .checked_sub(fee)
+            .filter(|change| *change >= P2WPKH_DUST_LIMIT)
+            .ok_or(ErrorCode::InsufficientVaultUtxos)?;
+
+        Ok(CoinSelection {
+            inputs,
+            fee,
+            change,
+        })
+    }
+
+    /// Makes the UTXOs reserved for `request_id` spendable again.
+    pub fn release(&mut self, request_id: &[u8; 32]) {
+        for utxo in self.utxos.iter_mut() {
+            if utxo.reserved_for.as_ref() == Some(request_id) {
+                utxo.reserved_for = None;
+            }
+        }
+    }
+
+    /// Drops the UTXOs reserved for `request_id`, which its transaction spent.
+    pub fn consume(&mut self, request_id: &[u8; 32]) {
+        self.utxos
+            .retain(|utxo| utxo.reserved_for.as_ref() != Some(request_id));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outpoint(vout: u32) -> BtcOutpoint {
+        BtcOutpoint {
+            txid: [7; 32],
+            vout,
+        }
+    }
+
+    #[test]
+    fn test_vault_utxo_set_lifecycle() {
+        let mut set = VaultUtxoSet {
+            caip2_id: String::new(),
+            utxos: Vec::new(),
+        };
+        set.add(outpoint(0), 1_000, 1).unwrap();
+        set.add(outpoint(1), 2_000, 1).unwrap();
+        assert!(set.add(outpoint(0), 1_000, 1).is_err());
+
+        let request_a = [1; 32];
+        let request_b = [2; 32];
+        assert_eq!(set.reserve(&outpoint(0), request_a).unwrap().value, 1_000);
+        assert!(set.reserve(&outpoint(0), request_b).is_err());
+        assert!(set.reserve(&outpoint(2), request_b).is_err());
+        assert!(set.remove(&outpoint(0)).is_err());
+
+        set.release(&request_a);
+        set.reserve(&outpoint(0), request_b).unwrap();
+        set.reserve(&outpoint(1), request_b).unwrap();
+        set.consume(&request_b);
+        assert!(set.utxos.is_empty());
+    }
+
+    #[test]
+    fn test_vault_utxo_set_select() {
+        let mut set = VaultUtxoSet {
+            caip2_id: String::new(),
+            utxos: Vec::new(),
+        };
+        set.add(outpoint(0), 5_000, 1).unwrap();
+        set.add(outpoint(1), 20_000, 1).unwrap();
+        set.add(outpoint(2), 50_000, 2).unwrap();
+        set.add(outpoint(3), 20_000, 1).unwrap();
+
+        // One P2WPKH input paying a P2WPKH recipient with change is 141 vbytes
+        assert_eq!(withdrawal_vsize(1, 22, true), 141);
+
+        // Largest first under the key version, ties broken by outpoint
+        let selection = set.select(1, 10_000, 2, 22).unwrap();
+        assert_eq!(selection.inputs.len(), 1);
+        assert_eq!(selection.inputs[0].outpoint, outpoint(1));
+        assert_eq!(selection.fee, 282);
+        assert_eq!(selection.change, 20_000 - 10_000 - 282);
+
+        // Change below the dust limit goes to the fee
+        let selection = set.select(1, 19_600, 1, 22).unwrap();
+        assert_eq!(selection.fee, 400);
+        assert_eq!(selection.change, 0);
+
+        let selection = set.select(1, 30_000, 1, 22).unwrap();
+        assert_eq!(selection.inputs.len(), 2);
+        assert_eq!(selection.inputs[1].outpoint, outpoint(3));
+
+        set.reserve(&outpoint(1), [1; 32]).unwrap();
+        let selection = set.select(1, 24_000, 1, 22).unwrap();
+        assert_eq!(selection.inputs[0].outpoint, outpoint(3));
+        assert_eq!(selection.inputs[1].outpoint, outpoint(0));
+
+        assert!(set.select(1, 30_000, 1, 22).is_err());
+    }
+
+    #[test]
+    fn test_vault_utxo_set_select_sweep() {
+        let mut set = VaultUtxoSet {
+            caip2_id: String::new(),
+            utxos: Vec::new(),
+        };
+        assert!(set.select_sweep(1, 1).is_err());
+
+        for vout in 0..12 {
+            set.add(outpoint(vout), 1_000 + 100 * (12 - vout as u64), 1)
+                .unwrap();
+        }
+        set.reserve(&outpoint(11), [1; 32]).unwrap();
+
+        // The smallest unreserved UTXOs, up to one transaction's inputs
+        let selection = set.select_sweep(1, 1).unwrap();
+        assert_eq!(selection.inputs.len(), MAX_WITHDRAWAL_INPUTS);
+        assert_eq!(selection.inputs[0].outpoint, outpoint(10));
+        assert_eq!(selection.inputs[9].outpoint, outpoint(1));
+        assert_eq!(withdrawal_vsize(10, 22, false), 722);
+        assert_eq!(selection.fee, 722);
+        assert_eq!(selection.change, 16_500 - 722);
+
+        // The fee must leave an output above the dust limit
+        assert_eq!(set.select_sweep(1, 22).unwrap().change, 16_500 - 15_884);
+        assert!(set.select_sweep(1, 23).is_err());
+    }
+
+    #[test]
+    fn test_vault_utxo_set_key_rotation() {
+        let mut set = VaultUtxoSet {
+            caip2_id: String::new(),
+            utxos: Vec::new(),
+        };
+        set.add(outpoint(0), 20_000, 1).unwrap();
+        set.add(outpoint(1), 30_000, 1).unwrap();
+
+        // After rotating to key version 2, withdrawals only select its outputs
+        assert!(set.select(2, 10_000, 1, 22).is_err());
+
+        // A sweep under the retired version spends its outputs
+        let selection = set.select_sweep(1, 1).unwrap();
+        assert_eq!(selection.inputs.len(), 2);
+        let request_id = [3; 32];
+        for utxo in &selection.inputs {
+            set.reserve(&utxo.outpoint, request_id).unwrap();
+        }
+        set.consume(&request_id);
+        let swept = BtcOutpoint {
+            txid: [8; 32],
+            vout: 0,
+        };
+        set.add(swept, selection.change, 2).unwrap();
+
+        // The output pays the active key, so withdrawals can spend it
+        assert!(set.select_sweep(1, 1).is_err());
+        let selection = set.select(2, 10_000, 1, 22).unwrap();
+        assert_eq!(selection.inputs[0].outpoint, swept);
+    }
+
+    #[test]
+    fn test_pending_withdrawal_cancellation() {
+        let mut pending = PendingBtcWithdrawal {
+            requester: Pubkey::default(),
+            amount: 10_000,
+            fee: 282,
+            caip2_id: String::new(),
+            recipient_address: String::new(),
+            path: String::new(),
+            request_id: [1; 32],
+            key_version: 1,
+            protocol_fee: 0,
+            created_slot: 0,
+            rent_payer: Pubkey::default(),
+            txid: [0; 32],
+            change: 0,
+            inputs: vec![outpoint(0)],
+            cancel_request_id: None,
+            cancel_txid: [0; 32],
+            cancel_fee: 0,
+            cancel_output: 0,
+            failed_request_ids: Vec::new(),
+        };
+        assert!(pending.siblings_failed(&[1; 32]));
+
+        // One input paying the vault is 110 vbytes: the cancellation must pay the
+        // withdrawal's 282 sats plus 110 sats of relay fee
+        assert_eq!(withdrawal_vsize(1, 22, false), 110);
+        assert!(pending.cancellation_fee(3).is_err());
+        assert_eq!(pending.cancellation_fee(4).unwrap(), 440);
+
+        pending.cancel_request_id = Some([2; 32]);
+        assert!(pending.is_cancellation(&[2; 32]));
+        assert!(!pending.is_cancellation(&[1; 32]));
+        assert!(!pending.siblings_failed(&[1; 32]));
+        assert!(!pending.siblings_failed(&[2; 32]));
+
+        pending.failed_request_ids.push([2; 32]);
+        assert!(pending.siblings_failed(&[1; 32]));
+    }
+}
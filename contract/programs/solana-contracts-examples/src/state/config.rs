@@ -1,8 +1,331 @@
 use anchor_lang::prelude::*;
 
-#[account]
-#[derive(InitSpace)]
-pub struct VaultConfig {
+use crate::error::ErrorCode;
+use crate::state::{
+    ProcessedRequest, RateLimitOverride, RateLimitSettings, RateLimitWindow, MAX_ADMIN_SIGNERS,
+    MAX_RATE_LIMIT_OVERRIDES,
+};
+
+/// Maximum number of MPC key versions retained in the vault config
+pub const MAX_KEY_VERSIONS: usize = 8;
+
+/// Maximum number of chains that can carry their own pause switches
+pub const MAX_PAUSED_CHAINS: usize = 16;
+
+/// Maximum length of a CAIP-2 chain identifier
+pub const MAX_CAIP2_ID_LEN: usize = 64;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
+pub struct MpcKeyVersion {
+    pub version: u32,
     /// The full 64-byte uncompressed secp256k1 public key (without 0x04 prefix)
     pub mpc_root_public_key: [u8; 64],
 }
+
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace,
+)]
+pub struct PauseFlags {
+    pub deposits: bool,
+    pub withdrawals: bool,
+}
+
+impl PauseFlags {
+    /// True if every switch set in `self` is also set in `other`.
+    pub fn is_subset_of(&self, other: &PauseFlags) -> bool {
+        (!self.deposits || other.deposits) && (!self.withdrawals || other.withdrawals)
+    }
+
+    pub fn is_paused(&self, direction: Direction) -> bool {
+        match direction {
+            Direction::Deposit => self.deposits,
+            Direction::Withdrawal => self.withdrawals,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
+pub struct ChainPause {
+    #[max_len(MAX_CAIP2_ID_LEN)]
+    pub caip2_id: String,
+    pub flags: PauseFlags,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
+pub enum PauseScope {
+    Global,
+    Chain {
+        #[max_len(MAX_CAIP2_ID_LEN)]
+        caip2_id: String,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Deposit,
+    Withdrawal,
+}
+
+#[account]
+#[derive(InitSpace, Default)]
+pub struct VaultConfig {
+    /// Members of the admin multisig
+    #[max_len(MAX_ADMIN_SIGNERS)]
+    pub admin_signers: Vec<Pubkey>,
+    /// Number of admin approvals required to execute a config proposal
+    pub admin_threshold: u8,
+    /// Number of config proposals created so far, used to seed the next one
+    pub proposal_count: u64,
+    /// Delay between a proposal reaching its threshold and becoming executable
+    pub timelock_slots: u64,
+    /// Authority allowed to pause (but not unpause) deposits and withdrawals
+    pub pause_guardian: Pubkey,
+    /// Owner of the balances protocol fees are collected into
+    pub treasury: Pubkey,
+    /// Pause switches applying to every chain
+    pub global_pause: PauseFlags,
+    /// Pause switches for individual chains, keyed by CAIP-2 ID
+    #[max_len(MAX_PAUSED_CHAINS)]
+    pub chain_pauses: Vec<ChainPause>,
+    /// Key version used for new signature requests
+    pub active_key_version: u32,
+    /// Root public keys of every MPC key version known to the vault
+    #[max_len(MAX_KEY_VERSIONS)]
+    pub key_versions: Vec<MpcKeyVersion>,
+    /// Chain ID that balances from before per-chain accounting are migrated to,
+    /// 0 while migration is disabled
+    pub legacy_erc20_chain_id: u64,
+    /// CAIP-2 ID that BTC balances from before per-chain accounting are migrated
+    /// to, empty while migration is disabled
+    #[max_len(MAX_CAIP2_ID_LEN)]
+    pub legacy_btc_caip2_id: String,
+    /// Limit on the number of withdrawal requests across all users and assets
+    pub global_withdrawal_rate_limit: RateLimitSettings,
+    /// Usage of `global_withdrawal_rate_limit`
+    pub global_withdrawal_window: RateLimitWindow,
+    /// Users currently exempt from withdrawal rate limits
+    #[max_len(MAX_RATE_LIMIT_OVERRIDES)]
+    pub rate_limit_overrides: Vec<RateLimitOverride>,
+    /// Accept response signatures with recovery IDs 2 and 3 (R.x ≥ n), which
+    /// honest signers practically never produce
+    pub allow_high_recovery_ids: bool,
+    /// Slots after which a pending withdrawal can be cancelled, 0 to disable
+    /// cancellation
+    pub withdrawal_timeout_slots: u64,
+    /// Lamports taken from a pending account's rent for whoever settles it
+    pub crank_bounty_lamports: u64,
+    /// Slots after which a pending deposit's reservation against the deposit cap
+    /// can be released, 0 to disable releasing
+    pub deposit_timeout_slots: u64,
+}
+
+impl VaultConfig {
+    pub fn is_admin_signer(&self, key: &Pubkey) -> bool {
+        self.admin_signers.contains(key)
+    }
+
+    pub fn has_rate_limit_override(&self, user: &Pubkey, slot: u64) -> bool {
+        self.rate_limit_overrides
+            .iter()
+            .any(|entry| &entry.user == user && slot < entry.expires_slot)
+    }
+
+    /// Counts approvals from keys that are still members of the admin multisig.
+    pub fn count_approvals(&self, approvals: &[Pubkey]) -> usize {
+        approvals
+            .iter()
+            .filter(|key| self.is_admin_signer(key))
+            .count()
+    }
+
+    /// Fails unless cancellation is enabled and a withdrawal requested at
+    /// `created_slot` has timed out.
+    pub fn require_withdrawal_timed_out(&self, created_slot: u64) -> Result<()> {
+        require!(
+            self.withdrawal_timeout_slots > 0,
+            ErrorCode::WithdrawalCancellationDisabled
+        );
+        require!(
+            Clock::get()?.slot >= created_slot.saturating_add(self.withdrawal_timeout_slots),
+            ErrorCode::WithdrawalNotTimedOut
+        );
+
+        Ok(())
+    }
+
+    /// Fails unless releasing is enabled and a deposit requested at `created_slot`
+    /// has timed out.
+    pub fn require_deposit_timed_out(&self, created_slot: u64) -> Result<()> {
+        require!(
+            self.deposit_timeout_slots > 0,
+            ErrorCode::DepositReleaseDisabled
+        );
+        require!(
+            Clock::get()?.slot >= created_slot.saturating_add(self.deposit_timeout_slots),
+            ErrorCode::DepositNotTimedOut
+        );
+
+        Ok(())
+    }
+
+    /// Pays the signer settling a request from the pending account being closed: the
+    /// rent of the `processed_request` tombstone the signer funded, plus the crank
+    /// bounty, capped at the account's balance. The rest of the rent goes back to the
+    /// account's rent payer when it is closed.
+    pub fn pay_settler(&self, pending: &AccountInfo, settler: &AccountInfo) -> Result<()> {
+        let tombstone_rent = Rent::get()?.minimum_balance(8 + ProcessedRequest::INIT_SPACE);
+        let amount = tombstone_rent
+            .saturating_add(self.crank_bounty_lamports)
+            .min(pending.lamports());
+        **pending.try_borrow_mut_lamports()? -= amount;
+        **settler.try_borrow_mut_lamports()? += amount;
+
+        Ok(())
+    }
+
+    /// Returns the root public key registered for `key_version`.
+    pub fn root_public_key(&self, key_version: u32) -> Result<&[u8; 64]> {
+        self.key_versions
+            .iter()
+            .find(|entry| entry.version == key_version)
+            .map(|entry| &entry.mpc_root_public_key)
+            .ok_or_else(|| error!(ErrorCode::UnknownKeyVersion))
+    }
+
+    /// Returns the pause switches currently set for `scope`.
+    pub fn pause_flags(&self, scope: &PauseScope) -> PauseFlags {
+        match scope {
+            PauseScope::Global => self.global_pause,
+            PauseScope::Chain { caip2_id } => self
+                .chain_pauses
+                .iter()
+                .find(|entry| &entry.caip2_id == caip2_id)
+                .map(|entry| entry.flags)
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Fails if `direction` is paused globally or for the given chain.
+    pub fn require_not_paused(&self, caip2_id: &str, direction: Direction) -> Result<()> {
+        let chain_paused = self
+            .chain_pauses
+            .iter()
+            .any(|entry| entry.caip2_id == caip2_id && entry.flags.is_paused(direction));
+
+        if self.global_pause.is_paused(direction) || chain_paused {
+            return Err(match direction {
+                Direction::Deposit => error!(ErrorCode::DepositsPaused),
+                Direction::Withdrawal => error!(ErrorCode::WithdrawalsPaused),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Records a withdrawal of `amount` against the user's and the asset's rate
+    /// limits, and one request against the global limit. Users with an active
+    /// override are recorded but never rejected.
+    pub fn consume_withdrawal_limits(
+        &mut self,
+        user: &Pubkey,
+        amount: u128,
+        user_limit: (&mut RateLimitWindow, &RateLimitSettings),
+        asset_limit: (&mut RateLimitWindow, &RateLimitSettings),
+    ) -> Result<()> {
+        let slot = Clock::get()?.slot;
+        let bypass = self.has_rate_limit_override(user, slot);
+
+        let (user_window, user_settings) = user_limit;
+        require!(
+            user_window.consume(user_settings, amount, slot) || bypass,
+            ErrorCode::UserRateLimitExceeded
+        );
+
+        let (asset_window, asset_settings) = asset_limit;
+        require!(
+            asset_window.consume(asset_settings, amount, slot) || bypass,
+            ErrorCode::AssetRateLimitExceeded
+        );
+
+        let global_settings = self.global_withdrawal_rate_limit;
+        require!(
+            self.global_withdrawal_window
+                .consume(&global_settings, 1, slot)
+                || bypass,
+            ErrorCode::GlobalRateLimitExceeded
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pause_scopes() {
+        let chain = "eip155:1";
+        let mut config = VaultConfig {
+            chain_pauses: vec![ChainPause {
+                caip2_id: chain.to_string(),
+                flags: PauseFlags {
+                    deposits: true,
+                    withdrawals: false,
+                },
+            }],
+            ..Default::default()
+        };
+
+        assert!(config
+            .require_not_paused(chain, Direction::Deposit)
+            .is_err());
+        assert!(config
+            .require_not_paused(chain, Direction::Withdrawal)
+            .is_ok());
+        assert!(config
+            .require_not_paused("eip155:10", Direction::Deposit)
+            .is_ok());
+        assert_eq!(
+            config.pause_flags(&PauseScope::Chain {
+                caip2_id: "eip155:10".to_string()
+            }),
+            PauseFlags::default()
+        );
+
+        // A global pause applies to every chain
+        config.global_pause.withdrawals = true;
+        assert!(config
+            .require_not_paused(chain, Direction::Withdrawal)
+            .is_err());
+        assert!(config
+            .require_not_paused("eip155:10", Direction::Withdrawal)
+            .is_err());
+        assert!(config
+            .require_not_paused("eip155:10", Direction::Deposit)
+            .is_ok());
+
+        // Pausing more is allowed without a proposal, unpausing is not
+        let current = config.pause_flags(&PauseScope::Global);
+        assert!(current.is_subset_of(&PauseFlags {
+            deposits: true,
+            withdrawals: true,
+        }));
+        assert!(!current.is_subset_of(&PauseFlags::default()));
+    }
+
+    #[test]
+    fn test_count_approvals() {
+        let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let removed = Pubkey::new_unique();
+        let config = VaultConfig {
+            admin_signers: signers.to_vec(),
+            admin_threshold: 2,
+            ..Default::default()
+        };
+
+        assert_eq!(config.count_approvals(&signers), 2);
+        // Approvals from keys no longer in the multisig are ignored
+        assert_eq!(config.count_approvals(&[signers[0], removed]), 1);
+    }
+}
@@ -1,6 +1,12 @@
 use alloy_sol_types::sol;
 use anchor_lang::prelude::*;
 
+use crate::error::ErrorCode;
+use crate::state::MAX_FAILURE_REASON_LEN;
+
+/// Maximum number of fee-bumped replacements signed for one withdrawal
+pub const MAX_WITHDRAWAL_REPLACEMENTS: usize = 3;
+
 sol! {
     #[sol(abi)]
     interface IERC20 {
@@ -14,9 +20,36 @@ pub struct PendingErc20Deposit {
     pub requester: Pubkey,
     pub amount: u128,
     pub erc20_address: [u8; 20],
+    pub chain_id: u64,
     #[max_len(64)]
     pub path: String,
     pub request_id: [u8; 32],
+    /// MPC key version the request was signed under
+    pub key_version: u32,
+    /// Protocol fee charged on `amount`
+    pub protocol_fee: u128,
+    /// Paid the account's rent and gets it back when the account is closed
+    pub rent_payer: Pubkey,
+    /// Slot the deposit was requested in, checked against the deposit timeout
+    pub created_slot: u64,
+    /// Set once the deposit's reservation against the deposit cap was released
+    /// by `release_stalled_deposit_erc20`
+    pub reservation_released: bool,
+}
+
+/// Receipt for a deposit the MPC network reported as failed, seeded by
+/// `[b"failed_erc20_deposit", request_id]`
+#[account]
+#[derive(InitSpace)]
+pub struct FailedErc20Deposit {
+    pub requester: Pubkey,
+    pub amount: u128,
+    pub erc20_address: [u8; 20],
+    pub chain_id: u64,
+    /// Failure reported in the signed response
+    #[max_len(MAX_FAILURE_REASON_LEN)]
+    pub reason: String,
+    pub failed_slot: u64,
 }
 
 #[account]
@@ -25,10 +58,108 @@ pub struct PendingErc20Withdrawal {
     pub requester: Pubkey,
     pub amount: u128,
     pub erc20_address: [u8; 20],
+    pub chain_id: u64,
     pub recipient_address: [u8; 20],
     #[max_len(64)]
     pub path: String,
     pub request_id: [u8; 32],
+    /// MPC key version the request was signed under
+    pub key_version: u32,
+    /// Protocol fee charged on `amount`
+    pub protocol_fee: u128,
+    /// Gas fee charged in token units, kept by the vault even if the transfer fails
+    pub gas_fee: u128,
+    /// Most gas the signed transactions may spend, `gas_limit * max_fee_per_gas`
+    /// in wei. `gas_fee` was charged for this much
+    pub gas_cost: u128,
+    /// Slot the withdrawal was requested in, checked against the withdrawal timeout
+    pub created_slot: u64,
+    /// Paid the account's rent and gets it back when the account is closed
+    pub rent_payer: Pubkey,
+    /// Vault nonce assigned to the transaction and reused by its replacements
+    pub nonce: u64,
+    /// Fees of the most recently signed transaction
+    pub max_fee_per_gas: u128,
+    pub max_priority_fee_per_gas: u128,
+    /// Request IDs of replacement transactions, which settle the withdrawal like `request_id`
+    #[max_len(MAX_WITHDRAWAL_REPLACEMENTS)]
+    pub replacement_request_ids: Vec<[u8; 32]>,
+    /// Signed transactions the MPC network reported as failed
+    #[max_len(MAX_WITHDRAWAL_REPLACEMENTS + 2)]
+    pub failed_request_ids: Vec<[u8; 32]>,
+    /// Request ID of the zero-value self-transfer signed by `cancel_withdraw_erc20`.
+    /// Its success refunds the withdrawal, since it consumed the shared nonce
+    pub cancel_request_id: Option<[u8; 32]>,
+}
+
+impl PendingErc20Withdrawal {
+    /// Request IDs of every transaction signed for this withdrawal, including its
+    /// cancellation. They share a nonce, so at most one of them can execute.
+    pub fn signed_request_ids(&self) -> impl Iterator<Item = &[u8; 32]> {
+        std::iter::once(&self.request_id)
+            .chain(self.replacement_request_ids.iter())
+            .chain(self.cancel_request_id.iter())
+    }
+
+    /// Gas fee owed for a replacement that may spend up to `gas_cost`: the fee
+    /// already charged scaled by the increase over `self.gas_cost`, rounded up.
+    pub fn replacement_gas_fee(&self, gas_cost: u128) -> Result<u128> {
+        if gas_cost <= self.gas_cost || self.gas_cost == 0 {
+            return Ok(0);
+        }
+
+        let increase = gas_cost - self.gas_cost;
+        Ok(self
+            .gas_fee
+            .checked_mul(increase)
+            .ok_or(ErrorCode::Overflow)?
+            .div_ceil(self.gas_cost))
+    }
+
+    /// Fails unless `tx_params` outbids the most recently signed transaction.
+    /// Both fees must rise, except that a `max_fee_per_gas` already at the
+    /// chain's `max_fee_cap` may stay there, so a withdrawal signed at the cap
+    /// can still be replaced or cancelled by raising its priority fee.
+    pub fn require_outbid(
+        &self,
+        tx_params: &EvmTransactionParams,
+        max_fee_cap: u128,
+    ) -> Result<()> {
+        let max_fee_raised = tx_params.max_fee_per_gas > self.max_fee_per_gas
+            || (self.max_fee_per_gas >= max_fee_cap && tx_params.max_fee_per_gas == max_fee_cap);
+        require!(
+            max_fee_raised && tx_params.max_priority_fee_per_gas > self.max_priority_fee_per_gas,
+            ErrorCode::ReplacementUnderpriced
+        );
+
+        Ok(())
+    }
+
+    /// True if `request_id` is the withdrawal's cancellation transaction.
+    pub fn is_cancellation(&self, request_id: &[u8; 32]) -> bool {
+        self.cancel_request_id.as_ref() == Some(request_id)
+    }
+
+    /// True once every signed transaction other than `request_id` was reported
+    /// failed. Until then a sibling may still execute with the shared nonce, so a
+    /// failure alone doesn't mean the withdrawal can be refunded.
+    pub fn siblings_failed(&self, request_id: &[u8; 32]) -> bool {
+        self.signed_request_ids()
+            .all(|id| id == request_id || self.failed_request_ids.contains(id))
+    }
+}
+
+/// Next nonce of the vault address on an EVM chain, seeded by
+/// `[b"evm_vault_nonce", chain_id, key_version]`. The vault address is derived
+/// from the MPC key, so each key version has its own nonce, starting at 0.
+/// Withdrawals take their nonce from here rather than from the caller; a
+/// SetEvmVaultNonce proposal re-syncs it.
+#[account]
+#[derive(InitSpace)]
+pub struct EvmVaultNonce {
+    pub chain_id: u64,
+    pub key_version: u32,
+    pub next_nonce: u64,
 }
 
 #[account]
@@ -46,3 +177,134 @@ pub struct EvmTransactionParams {
     pub nonce: u64,
     pub chain_id: u64,
 }
+
+impl EvmTransactionParams {
+    /// Most the transaction can spend on gas, in wei.
+    pub fn max_gas_cost(&self) -> Result<u128> {
+        self.gas_limit
+            .checked_mul(self.max_fee_per_gas)
+            .ok_or_else(|| error!(ErrorCode::Overflow))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_siblings_failed() {
+        let mut pending = PendingErc20Withdrawal {
+            requester: Pubkey::default(),
+            amount: 0,
+            erc20_address: [0; 20],
+            chain_id: 1,
+            recipient_address: [0; 20],
+            path: String::new(),
+            request_id: [1; 32],
+            key_version: 0,
+            protocol_fee: 0,
+            gas_fee: 0,
+            gas_cost: 0,
+            created_slot: 0,
+            rent_payer: Pubkey::default(),
+            nonce: 0,
+            max_fee_per_gas: 0,
+            max_priority_fee_per_gas: 0,
+            replacement_request_ids: Vec::new(),
+            failed_request_ids: Vec::new(),
+            cancel_request_id: None,
+        };
+        assert!(pending.siblings_failed(&[1; 32]));
+
+        pending.replacement_request_ids = vec![[2; 32], [3; 32]];
+        assert!(!pending.siblings_failed(&[3; 32]));
+
+        pending.failed_request_ids = vec![[1; 32], [2; 32]];
+        assert!(pending.siblings_failed(&[3; 32]));
+        assert!(!pending.siblings_failed(&[1; 32]));
+
+        // A signed cancellation is one more sibling
+        pending.cancel_request_id = Some([4; 32]);
+        assert!(!pending.siblings_failed(&[3; 32]));
+        assert!(pending.is_cancellation(&[4; 32]));
+        assert!(!pending.is_cancellation(&[3; 32]));
+    }
+
+    #[test]
+    fn test_replacement_gas_fee() {
+        let mut pending = PendingErc20Withdrawal {
+            requester: Pubkey::default(),
+            amount: 0,
+            erc20_address: [0; 20],
+            chain_id: 1,
+            recipient_address: [0; 20],
+            path: String::new(),
+            request_id: [1; 32],
+            key_version: 0,
+            protocol_fee: 0,
+            gas_fee: 100,
+            gas_cost: 3_000,
+            created_slot: 0,
+            rent_payer: Pubkey::default(),
+            nonce: 0,
+            max_fee_per_gas: 0,
+            max_priority_fee_per_gas: 0,
+            replacement_request_ids: Vec::new(),
+            failed_request_ids: Vec::new(),
+            cancel_request_id: None,
+        };
+        assert_eq!(pending.replacement_gas_fee(3_000).unwrap(), 0);
+        assert_eq!(pending.replacement_gas_fee(2_000).unwrap(), 0);
+        assert_eq!(pending.replacement_gas_fee(6_000).unwrap(), 100);
+        // Rounded up in the vault's favour
+        assert_eq!(pending.replacement_gas_fee(3_001).unwrap(), 1);
+
+        pending.gas_fee = u128::MAX;
+        assert!(pending.replacement_gas_fee(6_000).is_err());
+    }
+
+    #[test]
+    fn test_require_outbid() {
+        let pending = PendingErc20Withdrawal {
+            requester: Pubkey::default(),
+            amount: 0,
+            erc20_address: [0; 20],
+            chain_id: 1,
+            recipient_address: [0; 20],
+            path: String::new(),
+            request_id: [1; 32],
+            key_version: 0,
+            protocol_fee: 0,
+            gas_fee: 0,
+            gas_cost: 0,
+            created_slot: 0,
+            rent_payer: Pubkey::default(),
+            nonce: 0,
+            max_fee_per_gas: 100,
+            max_priority_fee_per_gas: 10,
+            replacement_request_ids: Vec::new(),
+            failed_request_ids: Vec::new(),
+            cancel_request_id: None,
+        };
+        let params = |max_fee_per_gas, max_priority_fee_per_gas| EvmTransactionParams {
+            value: 0,
+            gas_limit: 21_000,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            nonce: 0,
+            chain_id: 1,
+        };
+
+        assert!(pending.require_outbid(&params(101, 11), 200).is_ok());
+        assert!(pending.require_outbid(&params(100, 11), 200).is_err());
+        assert!(pending.require_outbid(&params(101, 10), 200).is_err());
+
+        // At the cap only the priority fee has to rise
+        assert!(pending.require_outbid(&params(100, 11), 100).is_ok());
+        assert!(pending.require_outbid(&params(100, 10), 100).is_err());
+        // A cap lowered since the withdrawal was signed can't be exceeded, but
+        // the bid may stay at the new cap
+        assert!(pending.require_outbid(&params(90, 11), 90).is_ok());
+        assert!(pending.require_outbid(&params(80, 11), 90).is_err());
+    }
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+
+/// Basis-point denominator; fees can't exceed the amount they are charged on
+pub const MAX_FEE_BPS: u16 = 10_000;
+
+/// Protocol fees charged on one asset, in basis points of the transferred amount
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, InitSpace)]
+pub struct FeeSettings {
+    /// Deducted from a deposit when it is claimed
+    pub deposit_fee_bps: u16,
+    /// Debited on top of a withdrawal and returned if it is refunded
+    pub withdrawal_fee_bps: u16,
+}
+
+impl FeeSettings {
+    pub fn is_valid(&self) -> bool {
+        self.deposit_fee_bps <= MAX_FEE_BPS && self.withdrawal_fee_bps <= MAX_FEE_BPS
+    }
+
+    pub fn deposit_fee(&self, amount: u128) -> Result<u128> {
+        fee_for(amount, self.deposit_fee_bps)
+    }
+
+    pub fn withdrawal_fee(&self, amount: u128) -> Result<u128> {
+        fee_for(amount, self.withdrawal_fee_bps)
+    }
+}
+
+fn fee_for(amount: u128, fee_bps: u16) -> Result<u128> {
+    Ok(amount
+        .checked_mul(fee_bps as u128)
+        .ok_or(ErrorCode::Overflow)?
+        / MAX_FEE_BPS as u128)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fee_settings() {
+        let fees = FeeSettings {
+            deposit_fee_bps: 30,
+            withdrawal_fee_bps: MAX_FEE_BPS,
+        };
+        assert!(fees.is_valid());
+        assert_eq!(fees.deposit_fee(1_000_000).unwrap(), 3_000);
+        // Fees round down
+        assert_eq!(fees.deposit_fee(333).unwrap(), 0);
+        assert_eq!(fees.withdrawal_fee(1_234).unwrap(), 1_234);
+        assert!(fees.deposit_fee(u128::MAX).is_err());
+
+        assert_eq!(FeeSettings::default().deposit_fee(u128::MAX).unwrap(), 0);
+        assert!(!FeeSettings {
+            deposit_fee_bps: 0,
+            withdrawal_fee_bps: MAX_FEE_BPS + 1,
+        }
+        .is_valid());
+    }
+}
@@ -0,0 +1,158 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{
+    BtcOutpoint, ChainSettings, PauseFlags, PauseScope, RateLimitSettings, TokenSettings,
+    VaultUtxo, MAX_CAIP2_ID_LEN, MAX_UTXO_CHANGES,
+};
+
+/// Maximum number of members of the admin multisig
+pub const MAX_ADMIN_SIGNERS: usize = 10;
+
+/// Upper bound on the config timelock (~30 days of 400ms slots) so a bad
+/// proposal can't lock the admins out of the vault
+pub const MAX_TIMELOCK_SLOTS: u64 = 6_480_000;
+
+/// A privileged change to the vault configuration. Every admin operation goes
+/// through a `ConfigProposal` carrying one of these actions.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
+pub enum ConfigAction {
+    AddKeyVersion {
+        key_version: u32,
+        mpc_root_public_key: [u8; 64],
+    },
+    SetActiveKeyVersion {
+        key_version: u32,
+    },
+    SetPauseGuardian {
+        pause_guardian: Pubkey,
+    },
+    SetTreasury {
+        treasury: Pubkey,
+    },
+    SetPause {
+        scope: PauseScope,
+        flags: PauseFlags,
+    },
+    SetAdminSigners {
+        #[max_len(MAX_ADMIN_SIGNERS)]
+        admin_signers: Vec<Pubkey>,
+        admin_threshold: u8,
+    },
+    SetTimelock {
+        timelock_slots: u64,
+    },
+    SetLegacyErc20ChainId {
+        chain_id: u64,
+    },
+    /// An empty CAIP-2 ID disables BTC balance migration
+    SetLegacyBtcCaip2Id {
+        #[max_len(MAX_CAIP2_ID_LEN)]
+        caip2_id: String,
+    },
+    SetGlobalWithdrawalRateLimit {
+        settings: RateLimitSettings,
+    },
+    /// Exempts `user` from withdrawal rate limits until `expires_slot`; a past
+    /// slot removes the override
+    SetRateLimitOverride {
+        user: Pubkey,
+        expires_slot: u64,
+    },
+    SetAllowHighRecoveryIds {
+        allowed: bool,
+    },
+    /// Slots before a `CancelWithdrawal` can be executed; 0 disables cancellation
+    SetWithdrawalTimeout {
+        timeout_slots: u64,
+    },
+    SetCrankBounty {
+        lamports: u64,
+    },
+    /// Executed with `execute_evm_nonce_change`. Re-syncs the vault nonce with the
+    /// chain, or skips nonces that will never be used
+    SetEvmVaultNonce {
+        chain_id: u64,
+        key_version: u32,
+        next_nonce: u64,
+    },
+    /// Executed with `execute_evm_native_transfer`, which signs a transfer of
+    /// `value` wei from the vault address
+    TransferEvmNative {
+        chain_id: u64,
+        recipient: [u8; 20],
+        value: u128,
+    },
+    /// Executed with `cancel_withdraw_erc20` or `cancel_withdraw_btc` once the
+    /// withdrawal timed out. Both sign a conflicting transaction; the withdrawal
+    /// is refunded only once that transaction's success is reported
+    CancelWithdrawal {
+        request_id: [u8; 32],
+    },
+    /// Executed with `execute_token_change`, which creates the registry entry if needed
+    SetToken {
+        chain_id: u64,
+        erc20_address: [u8; 20],
+        settings: TokenSettings,
+    },
+    /// Executed with `execute_chain_change`, which creates the chain registry if needed
+    SetChain {
+        #[max_len(MAX_CAIP2_ID_LEN)]
+        caip2_id: String,
+        settings: ChainSettings,
+    },
+    /// Executed with `execute_chain_change`
+    RemoveChain {
+        #[max_len(MAX_CAIP2_ID_LEN)]
+        caip2_id: String,
+    },
+    /// Executed with `execute_chain_change`. Adds BTC balances the chain's
+    /// liabilities never saw credited, e.g. from before the chain was registered
+    CreditChainLiabilities {
+        #[max_len(MAX_CAIP2_ID_LEN)]
+        caip2_id: String,
+        amount: u128,
+    },
+    /// Executed with `execute_utxo_change`. Registers vault outputs the program
+    /// did not see being created, e.g. from before the UTXO set existed
+    AddVaultUtxos {
+        #[max_len(MAX_CAIP2_ID_LEN)]
+        caip2_id: String,
+        #[max_len(MAX_UTXO_CHANGES)]
+        utxos: Vec<VaultUtxo>,
+    },
+    /// Executed with `execute_utxo_change`. Drops unreserved outputs that were
+    /// spent outside the program
+    RemoveVaultUtxos {
+        #[max_len(MAX_CAIP2_ID_LEN)]
+        caip2_id: String,
+        #[max_len(MAX_UTXO_CHANGES)]
+        outpoints: Vec<BtcOutpoint>,
+    },
+    /// Executed with `sweep_btc`, which signs a transaction merging vault UTXOs
+    /// under `key_version` into one output to the active key, paying `fee_rate`
+    /// sat/vB from the chain's accrued fees
+    SweepVaultUtxos {
+        #[max_len(MAX_CAIP2_ID_LEN)]
+        caip2_id: String,
+        key_version: u32,
+        fee_rate: u64,
+    },
+    /// Slots before a stalled deposit's reservation can be released; 0 disables
+    /// releasing
+    SetDepositTimeout {
+        timeout_slots: u64,
+    },
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ConfigProposal {
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub action: ConfigAction,
+    /// Admin signers that approved the proposal
+    #[max_len(MAX_ADMIN_SIGNERS)]
+    pub approvals: Vec<Pubkey>,
+    /// Slot from which the proposal can be executed, 0 until the threshold is reached
+    pub eta_slot: u64,
+}
@@ -0,0 +1,197 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+
+/// Maximum number of simultaneous rate limit overrides
+pub const MAX_RATE_LIMIT_OVERRIDES: usize = 8;
+
+/// A limit on how much may be consumed within `window_slots`. A zero limit or
+/// window disables it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, InitSpace)]
+pub struct RateLimitSettings {
+    pub limit: u128,
+    pub window_slots: u64,
+}
+
+impl RateLimitSettings {
+    pub fn is_enabled(&self) -> bool {
+        self.limit > 0 && self.window_slots > 0
+    }
+}
+
+/// Usage tracked against a `RateLimitSettings`.
+///
+/// Slots are split into buckets of `window_slots`. Usage over the rolling window
+/// ending at the current slot is estimated as the current bucket plus the share
+/// of the previous bucket that still overlaps the window.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, InitSpace)]
+pub struct RateLimitWindow {
+    pub window_start_slot: u64,
+    pub previous_used: u128,
+    pub current_used: u128,
+}
+
+impl RateLimitWindow {
+    /// Records `amount` at `slot` and returns whether the rolling usage is still
+    /// within the limit. Usage is recorded either way so overridden withdrawals
+    /// still count against later ones.
+    pub fn consume(&mut self, settings: &RateLimitSettings, amount: u128, slot: u64) -> bool {
+        if !settings.is_enabled() {
+            return true;
+        }
+
+        let window_slots = settings.window_slots;
+        let bucket_start = slot - slot % window_slots;
+        if bucket_start != self.window_start_slot {
+            self.previous_used =
+                if bucket_start == self.window_start_slot.saturating_add(window_slots) {
+                    self.current_used
+                } else {
+                    0
+                };
+            self.current_used = 0;
+            self.window_start_slot = bucket_start;
+        }
+
+        let remaining_overlap = (window_slots - (slot - bucket_start)) as u128;
+        let carried = self.previous_used.saturating_mul(remaining_overlap) / window_slots as u128;
+
+        self.current_used = self.current_used.saturating_add(amount);
+
+        carried.saturating_add(self.current_used) <= settings.limit
+    }
+}
+
+/// Outstanding obligations of the vault for one asset, checked against the
+/// asset's deposit cap.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, InitSpace)]
+pub struct Liabilities {
+    /// Balances credited to users
+    pub credited: u128,
+    /// Deposits requested but not yet claimed
+    pub pending_deposits: u128,
+}
+
+impl Liabilities {
+    /// Reserves room for a deposit, failing if it would take the asset over
+    /// `deposit_cap`. A zero cap disables the check.
+    pub fn reserve_deposit(&mut self, amount: u128, deposit_cap: u128) -> Result<()> {
+        let pending_deposits = self
+            .pending_deposits
+            .checked_add(amount)
+            .ok_or(ErrorCode::Overflow)?;
+        let total = self
+            .credited
+            .checked_add(pending_deposits)
+            .ok_or(ErrorCode::Overflow)?;
+        require!(
+            deposit_cap == 0 || total <= deposit_cap,
+            ErrorCode::DepositCapExceeded
+        );
+
+        self.pending_deposits = pending_deposits;
+
+        Ok(())
+    }
+
+    /// Moves a claimed deposit from pending to credited.
+    pub fn settle_deposit(&mut self, amount: u128) -> Result<()> {
+        self.release_deposit(amount);
+        self.credit(amount)
+    }
+
+    /// Drops the reservation of a deposit that failed.
+    pub fn release_deposit(&mut self, amount: u128) {
+        self.pending_deposits = self.pending_deposits.saturating_sub(amount);
+    }
+
+    /// Credits a balance, e.g. a refunded withdrawal.
+    pub fn credit(&mut self, amount: u128) -> Result<()> {
+        self.credited = self
+            .credited
+            .checked_add(amount)
+            .ok_or(ErrorCode::Overflow)?;
+
+        Ok(())
+    }
+
+    /// Debits a balance leaving the vault. Fails if more leaves than was credited,
+    /// which means a balance was credited without being tracked.
+    pub fn debit(&mut self, amount: u128) -> Result<()> {
+        self.credited = self
+            .credited
+            .checked_sub(amount)
+            .ok_or(ErrorCode::LiabilitiesUnderflow)?;
+
+        Ok(())
+    }
+}
+
+/// Per-user usage of an asset's `user_withdrawal_rate_limit`
+#[account]
+#[derive(InitSpace)]
+pub struct UserRateLimit {
+    pub window: RateLimitWindow,
+}
+
+/// Lets `user` withdraw past every rate limit until `expires_slot`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace)]
+pub struct RateLimitOverride {
+    pub user: Pubkey,
+    pub expires_slot: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rate_limit_window_rolls_over() {
+        let settings = RateLimitSettings {
+            limit: 100,
+            window_slots: 10,
+        };
+
+        let mut window = RateLimitWindow::default();
+        assert!(window.consume(&settings, 60, 10));
+        assert!(window.consume(&settings, 40, 19));
+        assert!(!window.consume(&settings, 1, 19));
+
+        // Halfway through the next bucket half of the previous usage still counts
+        let mut window = RateLimitWindow::default();
+        window.consume(&settings, 100, 10);
+        assert!(window.consume(&settings, 50, 25));
+        assert!(!window.consume(&settings, 1, 25));
+
+        // Two buckets later the previous usage has fully expired
+        let mut window = RateLimitWindow::default();
+        window.consume(&settings, 100, 10);
+        assert!(window.consume(&settings, 100, 30));
+
+        assert!(window.consume(&RateLimitSettings::default(), u128::MAX, 30));
+    }
+
+    #[test]
+    fn test_liabilities() {
+        let mut liabilities = Liabilities::default();
+        liabilities.reserve_deposit(60, 100).unwrap();
+        liabilities.reserve_deposit(40, 100).unwrap();
+        assert!(liabilities.reserve_deposit(1, 100).is_err());
+        assert!(liabilities.reserve_deposit(u128::MAX, 0).is_err());
+
+        liabilities.settle_deposit(60).unwrap();
+        assert_eq!(liabilities.credited, 60);
+        assert_eq!(liabilities.pending_deposits, 40);
+
+        // Credited balances count against the cap too
+        assert!(liabilities.reserve_deposit(1, 100).is_err());
+
+        liabilities.debit(50).unwrap();
+        assert!(liabilities.debit(11).is_err());
+        assert_eq!(liabilities.credited, 10);
+
+        liabilities.credit(1).unwrap();
+        liabilities.debit(11).unwrap();
+        assert_eq!(liabilities.credited, 0);
+    }
+}
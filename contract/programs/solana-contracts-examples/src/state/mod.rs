@@ -1,7 +1,17 @@
 pub mod btc;
 pub mod config;
 pub mod erc20;
+pub mod fees;
+pub mod governance;
+pub mod limits;
+pub mod registry;
+pub mod request;
 
 pub use btc::*;
 pub use config::*;
 pub use erc20::*;
+pub use fees::*;
+pub use governance::*;
+pub use limits::*;
+pub use registry::*;
+pub use request::*;
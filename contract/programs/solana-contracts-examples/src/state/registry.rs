@@ -0,0 +1,302 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+use crate::error::ErrorCode;
+use crate::state::{
+    EvmTransactionParams, FeeSettings, Liabilities, RateLimitSettings, RateLimitWindow,
+    MAX_CAIP2_ID_LEN,
+};
+
+/// Maximum length of a token symbol
+pub const MAX_TOKEN_SYMBOL_LEN: usize = 16;
+
+/// Maximum number of chains in the chain registry
+pub const MAX_CHAINS: usize = 16;
+
+const EIP155_PREFIX: &str = "eip155:";
+const BIP122_PREFIX: &str = "bip122:";
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
+pub struct TokenSettings {
+    /// Deposits and withdrawals are rejected while disabled
+    pub enabled: bool,
+    pub decimals: u8,
+    #[max_len(MAX_TOKEN_SYMBOL_LEN)]
+    pub symbol: String,
+    /// Smallest amount accepted per deposit or withdrawal, in token units
+    pub min_amount: u128,
+    /// Largest amount accepted per deposit or withdrawal, in token units
+    pub max_amount: u128,
+    /// Gas limit clients should use for `transfer` transactions of this token
+    pub default_gas_limit: u128,
+    /// Flat charge for the gas of a withdrawal transaction, in token units.
+    /// Debited with the withdrawal and kept even if the transfer fails
+    pub withdrawal_gas_fee: u128,
+    /// Limit on withdrawals of this token across all users, in token units
+    pub withdrawal_rate_limit: RateLimitSettings,
+    /// Limit on withdrawals of this token by a single user, in token units
+    pub user_withdrawal_rate_limit: RateLimitSettings,
+    /// Cap on `liabilities` enforced when deposits are requested, 0 for no cap
+    pub deposit_cap: u128,
+    pub fees: FeeSettings,
+}
+
+/// Registry entry for one ERC20 token on one EVM chain. Entries are
+/// self-describing so clients can list supported tokens from program accounts.
+#[account]
+#[derive(InitSpace)]
+pub struct TokenRegistry {
+    pub chain_id: u64,
+    pub erc20_address: [u8; 20],
+    pub settings: TokenSettings,
+    /// Usage of `settings.withdrawal_rate_limit`
+    pub withdrawal_window: RateLimitWindow,
+    pub liabilities: Liabilities,
+    /// Protocol fees not yet moved to the treasury balance
+    pub accrued_fees: u128,
+}
+
+impl TokenRegistry {
+    /// Fails unless the token is enabled and `amount` is within its bounds.
+    pub fn require_transferable(&self, amount: u128) -> Result<()> {
+        require!(self.settings.enabled, ErrorCode::TokenDisabled);
+        require!(
+            amount >= self.settings.min_amount,
+            ErrorCode::AmountBelowMinimum
+        );
+        require!(
+            amount <= self.settings.max_amount,
+            ErrorCode::AmountAboveMaximum
+        );
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
+pub struct ChainSettings {
+    /// Deposits and withdrawals are rejected while disabled
+    pub enabled: bool,
+    /// Limit on withdrawals of the chain's native asset across all users
+    pub withdrawal_rate_limit: RateLimitSettings,
+    /// Limit on withdrawals of the chain's native asset by a single user
+    pub user_withdrawal_rate_limit: RateLimitSettings,
+    /// Cap on the native asset's `liabilities`, 0 for no cap
+    pub deposit_cap: u128,
+    /// Protocol fees on the native asset
+    pub fees: FeeSettings,
+    /// Largest network fee a BTC deposit may spend from the requester's inputs.
+    /// There is no default: while it is 0 every deposit paying a fee is rejected
+    pub max_deposit_network_fee: u64,
+    /// Bounds on the gas of EVM withdrawals signed from the vault address
+    pub max_gas_limit: u128,
+    pub max_fee_per_gas: u128,
+    pub max_priority_fee_per_gas: u128,
+}
+
+impl ChainSettings {
+    /// Fails unless the transaction's gas stays within the chain's bounds.
+    pub fn require_gas_within_bounds(&self, tx_params: &EvmTransactionParams) -> Result<()> {
+        require!(
+            tx_params.gas_limit <= self.max_gas_limit,
+            ErrorCode::GasLimitTooHigh
+        );
+        require!(
+            tx_params.max_fee_per_gas <= self.max_fee_per_gas,
+            ErrorCode::MaxFeePerGasTooHigh
+        );
+        require!(
+            tx_params.max_priority_fee_per_gas <= self.max_priority_fee_per_gas
+                && tx_params.max_priority_fee_per_gas <= tx_params.max_fee_per_gas,
+            ErrorCode::PriorityFeeTooHigh
+        );
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
+pub struct ChainEntry {
+    #[max_len(MAX_CAIP2_ID_LEN)]
+    pub caip2_id: String,
+    pub settings: ChainSettings,
+    /// Usage of `settings.withdrawal_rate_limit`
+    pub withdrawal_window: RateLimitWindow,
+    /// Liabilities in the chain's native asset
+    pub liabilities: Liabilities,
+    /// Native asset protocol fees not yet moved to the treasury balance
+    pub accrued_fees: u128,
+    /// ERC20 tokens registered on the chain, each tracking its own liabilities
+    /// in its `TokenRegistry` entry
+    pub token_count: u32,
+}
+
+impl ChainEntry {
+    /// True while users hold balances or pending deposits on the chain, its fees
+    /// are uncollected, or it has registered tokens. Such a chain can be disabled
+    /// but not removed.
+    pub fn has_liabilities(&self) -> bool {
+        self.liabilities.credited > 0
+            || self.liabilities.pending_deposits > 0
+            || self.accrued_fees > 0
+            || self.token_count > 0
+    }
+}
+
+/// Chains the vault is allowed to request signatures for, keyed by CAIP-2 ID.
+#[account]
+#[derive(InitSpace)]
+pub struct ChainRegistry {
+    #[max_len(MAX_CHAINS)]
+    pub chains: Vec<ChainEntry>,
+}
+
+impl ChainRegistry {
+    pub fn get(&self, caip2_id: &str) -> Option<&ChainEntry> {
+        self.chains.iter().find(|entry| entry.caip2_id == caip2_id)
+    }
+
+    pub fn get_mut(&mut self, caip2_id: &str) -> Option<&mut ChainEntry> {
+        self.chains
+            .iter_mut()
+            .find(|entry| entry.caip2_id == caip2_id)
+    }
+
+    /// Returns the chain's entry, failing if it is unknown or disabled.
+    pub fn require_enabled(&self, caip2_id: &str) -> Result<&ChainEntry> {
+        let entry = self
+            .get(caip2_id)
+            .ok_or_else(|| error!(ErrorCode::UnsupportedChain))?;
+        require!(entry.settings.enabled, ErrorCode::ChainDisabled);
+
+        Ok(entry)
+    }
+
+    /// Mutable variant of `require_enabled`.
+    pub fn require_enabled_mut(&mut self, caip2_id: &str) -> Result<&mut ChainEntry> {
+        let entry = self
+            .get_mut(caip2_id)
+            .ok_or_else(|| error!(ErrorCode::UnsupportedChain))?;
+        require!(entry.settings.enabled, ErrorCode::ChainDisabled);
+
+        Ok(entry)
+    }
+}
+
+/// PDA seed for per-chain accounts; CAIP-2 IDs can be longer than a seed allows.
+pub fn caip2_id_seed(caip2_id: &str) -> [u8; 32] {
+    hash(caip2_id.as_bytes()).to_bytes()
+}
+
+/// Accepts `eip155:<decimal chain id>` and `bip122:<32 hex chars of the genesis hash>`.
+pub fn is_supported_caip2_id(caip2_id: &str) -> bool {
+    if let Some(reference) = caip2_id.strip_prefix(EIP155_PREFIX) {
+        !reference.is_empty()
+            && reference.len() <= 20
+            && !reference.starts_with('0')
+            && reference.bytes().all(|b| b.is_ascii_digit())
+    } else if let Some(reference) = caip2_id.strip_prefix(BIP122_PREFIX) {
+        reference.len() == 32
+            && reference
+                .bytes()
+                .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b))
+    } else {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_supported_caip2_id() {
+        assert!(is_supported_caip2_id("eip155:1"));
+        assert!(is_supported_caip2_id("eip155:11155111"));
+        assert!(is_supported_caip2_id(
+            "bip122:000000000933ea01ad0ee984209779ba"
+        ));
+
+        assert!(!is_supported_caip2_id("eip155:"));
+        assert!(!is_supported_caip2_id("eip155:01"));
+        assert!(!is_supported_caip2_id("eip155:0x1"));
+        assert!(!is_supported_caip2_id(
+            "bip122:000000000933EA01AD0EE984209779BA"
+        ));
+        assert!(!is_supported_caip2_id("bip122:000000000933ea01"));
+        assert!(!is_supported_caip2_id(
+            "solana:5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp"
+        ));
+    }
+
+    fn chain_settings() -> ChainSettings {
+        ChainSettings {
+            enabled: true,
+            withdrawal_rate_limit: RateLimitSettings::default(),
+            user_withdrawal_rate_limit: RateLimitSettings::default(),
+            deposit_cap: 0,
+            fees: FeeSettings::default(),
+            max_deposit_network_fee: 0,
+            max_gas_limit: 100_000,
+            max_fee_per_gas: 50,
+            max_priority_fee_per_gas: 5,
+        }
+    }
+
+    #[test]
+    fn test_require_gas_within_bounds() {
+        let settings = chain_settings();
+        let params = |gas_limit, max_fee_per_gas, max_priority_fee_per_gas| EvmTransactionParams {
+            value: 0,
+            gas_limit,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            nonce: 0,
+            chain_id: 1,
+        };
+
+        assert!(settings
+            .require_gas_within_bounds(&params(100_000, 50, 5))
+            .is_ok());
+        assert!(settings
+            .require_gas_within_bounds(&params(100_001, 50, 5))
+            .is_err());
+        assert!(settings
+            .require_gas_within_bounds(&params(100_000, 51, 5))
+            .is_err());
+        assert!(settings
+            .require_gas_within_bounds(&params(100_000, 50, 6))
+            .is_err());
+        assert!(settings
+            .require_gas_within_bounds(&params(100_000, 4, 5))
+            .is_err());
+    }
+
+    #[test]
+    fn test_has_liabilities() {
+        let mut entry = ChainEntry {
+            caip2_id: "bip122:000000000933ea01ad0ee984209779ba".to_string(),
+            settings: chain_settings(),
+            withdrawal_window: RateLimitWindow::default(),
+            liabilities: Liabilities::default(),
+            accrued_fees: 0,
+            token_count: 0,
+        };
+        assert!(!entry.has_liabilities());
+
+        entry.liabilities.pending_deposits = 1;
+        assert!(entry.has_liabilities());
+
+        entry.liabilities.pending_deposits = 0;
+        entry.accrued_fees = 1;
+        assert!(entry.has_liabilities());
+
+        entry.accrued_fees = 0;
+        entry.liabilities.credited = 1;
+        assert!(entry.has_liabilities());
+
+        entry.liabilities.credited = 0;
+        entry.token_count = 1;
+        assert!(entry.has_liabilities());
+    }
+}
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use borsh::BorshDeserialize;
+
+use crate::constants::ERROR_PREFIX;
+use crate::error::ErrorCode;
+
+/// Maximum length of the failure reason kept in a failed-deposit receipt
+pub const MAX_FAILURE_REASON_LEN: usize = 64;
+
+/// Tombstone for a settled request ID, seeded by `[b"processed_request", request_id]`.
+///
+/// Pending accounts are closed once a request settles, which would let the same
+/// request ID be initiated and settled again. Tombstones are never closed, so
+/// `deposit_*`/`withdraw_*` refuse a request ID that has one and the instructions
+/// settling a request (`claim_*`, `complete_withdraw_*`, `cancel_withdraw_*`,
+/// `finalize_failed_deposit_*`) can create it only once.
+#[account]
+#[derive(InitSpace)]
+pub struct ProcessedRequest {}
+
+/// Reads a signed transfer response. Returns `None` if the transfer succeeded, or the
+/// failure reason: the error message (see `error_reason`) or a fixed reason if the
+/// transfer returned false.
+pub fn transfer_failure_reason(serialized_output: &[u8]) -> Result<Option<String>> {
+    if let Some(reason) = error_reason(serialized_output) {
+        return Ok(Some(reason));
+    }
+
+    let success: bool = BorshDeserialize::try_from_slice(serialized_output)
+        .map_err(|_| ErrorCode::InvalidOutput)?;
+
+    Ok((!success).then(|| "Transfer returned false".to_string()))
+}
+
+/// Returns the message after `ERROR_PREFIX`, truncated to `MAX_FAILURE_REASON_LEN`
+/// bytes, if the response reports an error. Responses to transactions without
+/// return data, such as value transfers, carry nothing else.
+pub fn error_reason(serialized_output: &[u8]) -> Option<String> {
+    let message = serialized_output.strip_prefix(&ERROR_PREFIX)?;
+    let mut reason = String::from_utf8_lossy(message).into_owned();
+    let mut len = reason.len().min(MAX_FAILURE_REASON_LEN);
+    while !reason.is_char_boundary(len) {
+        len -= 1;
+    }
+    reason.truncate(len);
+
+    Some(reason)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transfer_failure_reason() {
+        assert_eq!(transfer_failure_reason(&[1]).unwrap(), None);
+        assert_eq!(
+            transfer_failure_reason(&[0]).unwrap().as_deref(),
+            Some("Transfer returned false")
+        );
+
+        let mut output = ERROR_PREFIX.to_vec();
+        output.extend_from_slice(b"nonce too low");
+        assert_eq!(
+            transfer_failure_reason(&output).unwrap().as_deref(),
+            Some("nonce too low")
+        );
+
+        // Long reasons are cut on a character boundary
+        let mut output = ERROR_PREFIX.to_vec();
+        output.extend_from_slice("é".repeat(MAX_FAILURE_REASON_LEN).as_bytes());
+        let reason = transfer_failure_reason(&output).unwrap().unwrap();
+        assert_eq!(reason, "é".repeat(MAX_FAILURE_REASON_LEN / 2));
+
+        assert!(transfer_failure_reason(&[]).is_err());
+        assert!(transfer_failure_reason(&[2]).is_err());
+
+        // Without return data only the error prefix marks a failure
+        assert_eq!(error_reason(&[]), None);
+        assert_eq!(error_reason(&[0]), None);
+    }
+}
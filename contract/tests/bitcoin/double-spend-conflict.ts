@@ -13,6 +13,9 @@ import {
   computeSignatureRequestIds,
   COMPUTE_UNITS,
   createFundedAuthority,
+  deriveUserBalancePda,
+  deriveUserRateLimitPda,
+  deriveVaultUtxoSetPda,
   executeSyntheticDeposit,
   fetchUserBalance,
   getBitcoinTestContext,
@@ -74,7 +77,6 @@ describe("BTC Withdrawal Double-Spend Conflict", () => {
     const withdrawTx = await program.methods
       .withdrawBtc(
         planRequestIdBytes(withdrawalPlan),
-        withdrawalPlan.btcInputs,
         withdrawalPlan.amount,
         withdrawalPlan.recipient.address,
         withdrawalPlan.txParams,
@@ -83,6 +85,9 @@ describe("BTC Withdrawal Double-Spend Conflict", () => {
         authority: authority.publicKey,
         feePayer: provider.wallet.publicKey,
         instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        vaultUtxoSet: deriveVaultUtxoSetPda(),
+        userBalance: deriveUserBalancePda(authority.publicKey),
+        userRateLimit: deriveUserRateLimitPda(authority.publicKey),
       })
       .signers([authority])
       .rpc();
@@ -210,6 +215,8 @@ describe("BTC Withdrawal Double-Spend Conflict", () => {
       )
       .accounts({
         payer: provider.wallet.publicKey,
+        vaultUtxoSet: deriveVaultUtxoSetPda(),
+        userBalance: deriveUserBalancePda(authority.publicKey),
       })
       .preInstructions([
         ComputeBudgetProgram.setComputeUnitLimit({ units: COMPUTE_UNITS }),
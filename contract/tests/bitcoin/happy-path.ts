@@ -12,14 +12,16 @@ import {
   COMPUTE_UNITS,
   createFundedAuthority,
   deriveUserBalancePda,
+  deriveUserRateLimitPda,
+  deriveVaultUtxoSetPda,
   executeSyntheticDeposit,
   fetchUserBalance,
+  fetchVaultUtxos,
   getBitcoinTestContext,
   planRequestIdBytes,
   setupBitcoinTestContext,
   startBtcEventListeners,
   teardownBitcoinTestContext,
-  WITHDRAW_FEE_BUDGET,
 } from "./utils";
 
 describe("BTC Happy Path", () => {
@@ -66,7 +68,7 @@ describe("BTC Happy Path", () => {
         planRequestIdBytes(plan),
         plan.requester,
         plan.btcInputs,
-        plan.btcOutputs,
+        plan.creditedAmount,
         plan.txParams,
       )
       .accounts({
@@ -112,6 +114,10 @@ describe("BTC Happy Path", () => {
         Buffer.from(readEvent.serializedOutput),
         readEvent.signature,
       )
+      .accountsPartial({
+        vaultUtxoSet: deriveVaultUtxoSetPda(),
+        userBalance: deriveUserBalancePda(plan.requester),
+      })
       .preInstructions([
         ComputeBudgetProgram.setComputeUnitLimit({ units: COMPUTE_UNITS }),
       ])
@@ -160,7 +166,7 @@ describe("BTC Happy Path", () => {
         planRequestIdBytes(plan),
         plan.requester,
         plan.btcInputs,
-        plan.btcOutputs,
+        plan.creditedAmount,
         plan.txParams,
       )
       .accounts({
@@ -203,6 +209,10 @@ describe("BTC Happy Path", () => {
         Buffer.from(readEvent.serializedOutput),
         readEvent.signature,
       )
+      .accountsPartial({
+        vaultUtxoSet: deriveVaultUtxoSetPda(),
+        userBalance: deriveUserBalancePda(plan.requester),
+      })
       .preInstructions([
         ComputeBudgetProgram.setComputeUnitLimit({ units: COMPUTE_UNITS }),
       ])
@@ -224,8 +234,6 @@ describe("BTC Happy Path", () => {
     const depositor = await createFundedAuthority();
     await executeSyntheticDeposit(7_500, depositor.publicKey);
 
-    const feeBudget = WITHDRAW_FEE_BUDGET;
-
     const userBalancePda = deriveUserBalancePda(depositor.publicKey);
     const { amount: startingBalance } = await fetchUserBalance(
       depositor.publicKey,
@@ -238,7 +246,6 @@ describe("BTC Happy Path", () => {
     const plan = await buildWithdrawalPlan({
       mode: "live",
       authority: depositor,
-      feeBudget,
     });
 
     console.log("\n🚀 [btc] Starting withdrawal flow");
@@ -281,7 +288,6 @@ describe("BTC Happy Path", () => {
     const withdrawTx = await program.methods
       .withdrawBtc(
         planRequestIdBytes(plan),
-        plan.btcInputs,
         plan.amount,
         plan.recipient.address,
         plan.txParams,
@@ -290,6 +296,9 @@ describe("BTC Happy Path", () => {
         authority: depositor.publicKey,
         feePayer: provider.wallet.publicKey,
         instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        vaultUtxoSet: deriveVaultUtxoSetPda(),
+        userBalance: deriveUserBalancePda(depositor.publicKey),
+        userRateLimit: deriveUserRateLimitPda(depositor.publicKey),
       })
       .signers([depositor])
       .rpc();
@@ -341,6 +350,10 @@ describe("BTC Happy Path", () => {
         Buffer.from(readEvent.serializedOutput),
         readEvent.signature,
       )
+      .accountsPartial({
+        vaultUtxoSet: deriveVaultUtxoSetPda(),
+        userBalance: deriveUserBalancePda(depositor.publicKey),
+      })
       .preInstructions([
         ComputeBudgetProgram.setComputeUnitLimit({ units: COMPUTE_UNITS }),
       ])
@@ -370,5 +383,19 @@ describe("BTC Happy Path", () => {
       balanceAfterInitiation.toString(),
     );
     console.log("📍 Step 6: Withdrawal balance checks passed");
+
+    // Spent inputs leave the vault UTXO set and the change output joins it
+    const vaultUtxos = (await fetchVaultUtxos()).map(
+      ({ outpoint }) =>
+        `${Buffer.from(outpoint.txid).toString("hex")}:${outpoint.vout}`,
+    );
+    for (const input of plan.btcInputs) {
+      expect(vaultUtxos).to.not.include(
+        `${Buffer.from(input.txid).toString("hex")}:${input.vout}`,
+      );
+    }
+    if (plan.btcOutputs.length > 1) {
+      expect(vaultUtxos).to.include(`${plan.txidExplorerHex}:1`);
+    }
   });
 });
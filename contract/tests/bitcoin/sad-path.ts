@@ -4,12 +4,20 @@ import { expect } from "chai";
 import BN from "bn.js";
 import {
   buildDepositPlan,
+  buildSweepPlan,
   buildWithdrawalPlan,
+  composeCancellationPlan,
   computeMessageHash,
   COMPUTE_UNITS,
+  deriveUserBalancePda,
+  deriveUserRateLimitPda,
+  deriveVaultUtxoSetPda,
   expectAnchorError,
   executeSyntheticDeposit,
+  fetchAccruedFees,
+  fetchChainLiabilities,
   fetchUserBalance,
+  fetchVaultUtxos,
   getBitcoinTestContext,
   planRequestIdBytes,
   setupBitcoinTestContext,
@@ -17,7 +25,24 @@ import {
   signHashWithMpcForWithdrawal,
   teardownBitcoinTestContext,
   createFundedAuthority,
+  DEFAULT_DEPOSIT_AMOUNT,
+  P2WPKH_DUST_LIMIT,
+  WITHDRAW_FEE_BUDGET,
+  WITHDRAW_FEE_RATE,
 } from "./utils";
+import {
+  applyConfigChange,
+  findConfigProposalPda,
+  MAX_DEPOSIT_NETWORK_FEE,
+  proposeConfigChange,
+  setChainFees,
+} from "../../utils/governance";
+import { CONFIG } from "../../utils/envConfig";
+
+const SECP256K1_ORDER = new BN(
+  "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
+  "hex"
+);
 
 describe("BTC Sad Path", () => {
   before(async function () {
@@ -40,7 +65,7 @@ describe("BTC Sad Path", () => {
           tamperedRequestId,
           plan.requester,
           plan.btcInputs,
-          plan.btcOutputs,
+          plan.creditedAmount,
           plan.txParams
         )
         .accounts({
@@ -53,11 +78,13 @@ describe("BTC Sad Path", () => {
     );
   });
 
-  it("rejects deposits that omit a vault-directed output", async function () {
+  it("rejects deposits whose network fee exceeds the chain's bound", async function () {
     const { provider, program } = getBitcoinTestContext();
+    const fee = MAX_DEPOSIT_NETWORK_FEE + 1;
     const plan = await buildDepositPlan({
       mode: "mock",
-      includeVaultOutput: false,
+      fee,
+      inputValue: DEFAULT_DEPOSIT_AMOUNT + fee + WITHDRAW_FEE_BUDGET,
     });
 
     await expectAnchorError(
@@ -66,7 +93,7 @@ describe("BTC Sad Path", () => {
           planRequestIdBytes(plan),
           plan.requester,
           plan.btcInputs,
-          plan.btcOutputs,
+          plan.creditedAmount,
           plan.txParams
         )
         .accounts({
@@ -75,7 +102,7 @@ describe("BTC Sad Path", () => {
           instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
         })
         .rpc(),
-      /No vault outputs found in transaction/
+      /Network fee exceeds the chain's bound/
     );
   });
 
@@ -88,7 +115,7 @@ describe("BTC Sad Path", () => {
         planRequestIdBytes(plan),
         plan.requester,
         plan.btcInputs,
-        plan.btcOutputs,
+        plan.creditedAmount,
         plan.txParams
       )
       .accounts({
@@ -111,11 +138,16 @@ describe("BTC Sad Path", () => {
     const invalidSignature = JSON.parse(
       JSON.stringify(validSignature)
     ) as typeof validSignature;
-    invalidSignature.s[0] ^= 0xff;
+    // Flip a low bit so s stays canonical but no longer matches the message
+    invalidSignature.s[31] ^= 0x01;
 
     await expectAnchorError(
       program.methods
         .claimBtc(planRequestIdBytes(plan), serializedOutput, invalidSignature)
+        .accountsPartial({
+          vaultUtxoSet: deriveVaultUtxoSetPda(),
+          userBalance: deriveUserBalancePda(plan.requester),
+        })
         .preInstructions([
           ComputeBudgetProgram.setComputeUnitLimit({ units: COMPUTE_UNITS }),
         ])
@@ -125,6 +157,10 @@ describe("BTC Sad Path", () => {
 
     const claimTx = await program.methods
       .claimBtc(planRequestIdBytes(plan), serializedOutput, validSignature)
+      .accountsPartial({
+        vaultUtxoSet: deriveVaultUtxoSetPda(),
+        userBalance: deriveUserBalancePda(plan.requester),
+      })
       .preInstructions([
         ComputeBudgetProgram.setComputeUnitLimit({ units: COMPUTE_UNITS }),
       ])
@@ -132,6 +168,108 @@ describe("BTC Sad Path", () => {
     await provider.connection.confirmTransaction(claimTx);
   });
 
+  it("rejects malleated response signatures", async function () {
+    const { provider, program } = getBitcoinTestContext();
+    const plan = await buildDepositPlan({ mode: "mock" });
+
+    const depositTx = await program.methods
+      .depositBtc(
+        planRequestIdBytes(plan),
+        plan.requester,
+        plan.btcInputs,
+        plan.creditedAmount,
+        plan.txParams
+      )
+      .accounts({
+        payer: provider.wallet.publicKey,
+        feePayer: provider.wallet.publicKey,
+        instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+      })
+      .rpc();
+    await provider.connection.confirmTransaction(depositTx);
+
+    const serializedOutput = Buffer.from([1]);
+    const validSignature = await signHashWithMpcForDeposit(
+      computeMessageHash(planRequestIdBytes(plan), serializedOutput),
+      plan.requester
+    );
+    const claim = (signature: typeof validSignature) =>
+      program.methods
+        .claimBtc(planRequestIdBytes(plan), serializedOutput, signature)
+        .accountsPartial({
+          vaultUtxoSet: deriveVaultUtxoSetPda(),
+          userBalance: deriveUserBalancePda(plan.requester),
+        })
+        .preInstructions([
+          ComputeBudgetProgram.setComputeUnitLimit({ units: COMPUTE_UNITS }),
+        ])
+        .rpc();
+
+    // (r, n - s) with the flipped recovery ID recovers the same key
+    const s = new BN(Buffer.from(validSignature.s));
+    const highS = SECP256K1_ORDER.sub(s).toArrayLike(Buffer, "be", 32);
+    await expectAnchorError(
+      claim({
+        ...validSignature,
+        s: Array.from(highS),
+        recoveryId: validSignature.recoveryId ^ 1,
+      }),
+      /Signature r or s is out of range, or s is not in low form/
+    );
+
+    await expectAnchorError(
+      claim({ ...validSignature, recoveryId: validSignature.recoveryId | 2 }),
+      /Signature recovery ID is not accepted/
+    );
+
+    const claimTx = await claim(validSignature);
+    await provider.connection.confirmTransaction(claimTx);
+  });
+
+  it("rejects re-initiating a request ID that has already been settled", async function () {
+    const { provider, program } = getBitcoinTestContext();
+    const plan = await buildDepositPlan({ mode: "mock" });
+
+    const deposit = () =>
+      program.methods
+        .depositBtc(
+          planRequestIdBytes(plan),
+          plan.requester,
+          plan.btcInputs,
+          plan.creditedAmount,
+          plan.txParams
+        )
+        .accounts({
+          payer: provider.wallet.publicKey,
+          feePayer: provider.wallet.publicKey,
+          instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        })
+        .rpc();
+
+    const depositTx = await deposit();
+    await provider.connection.confirmTransaction(depositTx);
+
+    const serializedOutput = Buffer.from([1]);
+    const signature = await signHashWithMpcForDeposit(
+      computeMessageHash(planRequestIdBytes(plan), serializedOutput),
+      plan.requester
+    );
+    const claimTx = await program.methods
+      .claimBtc(planRequestIdBytes(plan), serializedOutput, signature)
+      .accountsPartial({
+        vaultUtxoSet: deriveVaultUtxoSetPda(),
+        userBalance: deriveUserBalancePda(plan.requester),
+      })
+      .preInstructions([
+        ComputeBudgetProgram.setComputeUnitLimit({ units: COMPUTE_UNITS }),
+      ])
+      .rpc();
+    await provider.connection.confirmTransaction(claimTx);
+
+    // The pending deposit is closed, but the request ID stays tombstoned
+    await expectAnchorError(deposit(), /Request ID has already been processed/);
+  });
+
   it("rejects claims when serialized outputs cannot be decoded", async function () {
     const { provider, program } = getBitcoinTestContext();
     const plan = await buildDepositPlan({ mode: "mock" });
@@ -141,7 +279,7 @@ describe("BTC Sad Path", () => {
         planRequestIdBytes(plan),
         plan.requester,
         plan.btcInputs,
-        plan.btcOutputs,
+        plan.creditedAmount,
         plan.txParams
       )
       .accounts({
@@ -161,6 +299,10 @@ describe("BTC Sad Path", () => {
     await expectAnchorError(
       program.methods
         .claimBtc(planRequestIdBytes(plan), malformedOutput, malformedSignature)
+        .accountsPartial({
+          vaultUtxoSet: deriveVaultUtxoSetPda(),
+          userBalance: deriveUserBalancePda(plan.requester),
+        })
         .preInstructions([
           ComputeBudgetProgram.setComputeUnitLimit({ units: COMPUTE_UNITS }),
         ])
@@ -169,7 +311,7 @@ describe("BTC Sad Path", () => {
     );
   });
 
-  it("refunds deposits when MPC output indicates transfer failure", async function () {
+  it("finalizes deposits when MPC output indicates transfer failure", async function () {
     const { provider, program } = getBitcoinTestContext();
     const plan = await buildDepositPlan({ mode: "mock" });
 
@@ -178,7 +320,7 @@ describe("BTC Sad Path", () => {
         planRequestIdBytes(plan),
         plan.requester,
         plan.btcInputs,
-        plan.btcOutputs,
+        plan.creditedAmount,
         plan.txParams
       )
       .accounts({
@@ -198,57 +340,225 @@ describe("BTC Sad Path", () => {
     await expectAnchorError(
       program.methods
         .claimBtc(planRequestIdBytes(plan), failedOutput, failedSig)
+        .accountsPartial({
+          vaultUtxoSet: deriveVaultUtxoSetPda(),
+          userBalance: deriveUserBalancePda(plan.requester),
+        })
         .preInstructions([
           ComputeBudgetProgram.setComputeUnitLimit({ units: COMPUTE_UNITS }),
         ])
         .rpc(),
       /Transfer failed/
     );
+    const finalizeTx = await program.methods
+      .finalizeFailedDepositBtc(planRequestIdBytes(plan), failedOutput, failedSig)
+      .preInstructions([
+        ComputeBudgetProgram.setComputeUnitLimit({ units: COMPUTE_UNITS }),
+      ])
+      .rpc();
+    await provider.connection.confirmTransaction(finalizeTx);
+
+    const [failedDepositPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("failed_btc_deposit"), Buffer.from(planRequestIdBytes(plan))],
+      program.programId
+    );
+    const receipt = await program.account.failedBtcDeposit.fetch(
+      failedDepositPda
+    );
+    expect(receipt.requester.toBase58()).to.equal(plan.requester.toBase58());
+    expect(receipt.amount.toString()).to.equal(plan.creditedAmount.toString());
+    expect(receipt.reason).to.equal("Transfer returned false");
   });
 
-  it("rejects withdrawals when provided inputs do not cover the requested debit", async function () {
+  it("releases the cap reservation of a stalled deposit after the timeout", async function () {
     const { provider, program } = getBitcoinTestContext();
-    const authority = await createFundedAuthority();
-    await executeSyntheticDeposit(6_000, authority.publicKey);
+    const plan = await buildDepositPlan({ mode: "mock" });
+    const requestIdBytes = planRequestIdBytes(plan);
 
-    const withdrawPlan = await buildWithdrawalPlan({
-      mode: "mock",
-      amount: 1_500,
-      fee: 25,
-      inputValue: 2_000,
+    const before = await fetchChainLiabilities();
+    const depositTx = await program.methods
+      .depositBtc(
+        requestIdBytes,
+        plan.requester,
+        plan.btcInputs,
+        plan.creditedAmount,
+        plan.txParams
+      )
+      .accounts({
+        payer: provider.wallet.publicKey,
+        feePayer: provider.wallet.publicKey,
+        instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+      })
+      .rpc();
+    await provider.connection.confirmTransaction(depositTx);
+
+    const release = () =>
+      program.methods.releaseStalledDepositBtc(requestIdBytes).rpc();
+
+    await expectAnchorError(release(), /Releasing stalled deposits is disabled/);
+
+    await applyConfigChange(program, provider, {
+      setDepositTimeout: { timeoutSlots: new BN(1_000_000) },
     });
+    await expectAnchorError(release(), /Deposit has not timed out yet/);
 
-    if (!withdrawPlan.btcInputs.length) {
-      throw new Error("Mock withdrawal plan should include at least one input");
+    await applyConfigChange(program, provider, {
+      setDepositTimeout: { timeoutSlots: new BN(1) },
+    });
+    try {
+      const releaseTx = await release();
+      await provider.connection.confirmTransaction(releaseTx);
+      await expectAnchorError(
+        release(),
+        /Deposit reservation was already released/
+      );
+    } finally {
+      await applyConfigChange(program, provider, {
+        setDepositTimeout: { timeoutSlots: new BN(0) },
+      });
     }
-    withdrawPlan.btcInputs = [
-      {
-        ...withdrawPlan.btcInputs[0],
-        value: new BN(500),
-      },
-      ...withdrawPlan.btcInputs.slice(1),
-    ];
 
-    await expectAnchorError(
-      program.methods
+    const released = await fetchChainLiabilities();
+    expect(released.pendingDeposits.toString()).to.equal(
+      before.pendingDeposits.toString()
+    );
+
+    // A late response still settles the deposit and counts it as a liability
+    const serializedOutput = Buffer.from([1]);
+    const signature = await signHashWithMpcForDeposit(
+      computeMessageHash(requestIdBytes, serializedOutput),
+      plan.requester
+    );
+    const claimTx = await program.methods
+      .claimBtc(requestIdBytes, serializedOutput, signature)
+      .accountsPartial({
+        vaultUtxoSet: deriveVaultUtxoSetPda(),
+        userBalance: deriveUserBalancePda(plan.requester),
+      })
+      .preInstructions([
+        ComputeBudgetProgram.setComputeUnitLimit({ units: COMPUTE_UNITS }),
+      ])
+      .rpc();
+    await provider.connection.confirmTransaction(claimTx);
+
+    const after = await fetchChainLiabilities();
+    expect(after.pendingDeposits.toString()).to.equal(
+      before.pendingDeposits.toString()
+    );
+    expect(after.credited.toString()).to.equal(
+      before.credited.add(plan.creditedAmount).toString()
+    );
+  });
+
+  it("rejects withdrawals below the minimum fee rate or the dust limit", async function () {
+    const { provider, program } = getBitcoinTestContext();
+    const authority = await createFundedAuthority();
+    await executeSyntheticDeposit(6_000, authority.publicKey);
+
+    const withdraw = async (amount: number, feeRate: number) => {
+      const plan = await buildWithdrawalPlan({ mode: "mock", amount, feeRate });
+      return program.methods
         .withdrawBtc(
-          planRequestIdBytes(withdrawPlan),
-          withdrawPlan.btcInputs,
-          withdrawPlan.amount,
-          withdrawPlan.recipient.address,
-          withdrawPlan.txParams
+          planRequestIdBytes(plan),
+          plan.amount,
+          plan.recipient.address,
+          plan.txParams
         )
         .accounts({
           authority: authority.publicKey,
           feePayer: provider.wallet.publicKey,
           instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+          vaultUtxoSet: deriveVaultUtxoSetPda(),
+          userBalance: deriveUserBalancePda(authority.publicKey),
+          userRateLimit: deriveUserRateLimitPda(authority.publicKey),
         })
         .signers([authority])
-        .rpc(),
-      /Provided inputs do not cover requested amount \+ fee/
+        .rpc();
+    };
+
+    await expectAnchorError(
+      withdraw(1_500, 0),
+      /Fee rate is below the minimum relay fee rate/
+    );
+    await expectAnchorError(
+      withdraw(P2WPKH_DUST_LIMIT - 1, WITHDRAW_FEE_RATE),
+      /Output is below the P2WPKH dust limit/
     );
   });
 
+  it("reserves the vault UTXOs it selects until the withdrawal settles", async function () {
+    const { provider, program } = getBitcoinTestContext();
+    await executeSyntheticDeposit(7_500);
+
+    const plan = await buildWithdrawalPlan({ mode: "mock", amount: 2_000 });
+    const requestId = planRequestIdBytes(plan);
+    const outpointKeys = (outpoints: { txid: number[]; vout: number }[]) =>
+      outpoints.map(
+        ({ txid, vout }) => `${Buffer.from(txid).toString("hex")}:${vout}`
+      );
+
+    const withdrawTx = await program.methods
+      .withdrawBtc(
+        requestId,
+        plan.amount,
+        plan.recipient.address,
+        plan.txParams
+      )
+      .accounts({
+        authority: provider.wallet.publicKey,
+        feePayer: provider.wallet.publicKey,
+        instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        vaultUtxoSet: deriveVaultUtxoSetPda(),
+        userBalance: deriveUserBalancePda(provider.wallet.publicKey),
+        userRateLimit: deriveUserRateLimitPda(provider.wallet.publicKey),
+      })
+      .rpc();
+    await provider.connection.confirmTransaction(withdrawTx);
+
+    // The pending withdrawal records the inputs the program selected
+    const [pendingPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("pending_btc_withdrawal"), Buffer.from(requestId)],
+      program.programId
+    );
+    const pending = await program.account.pendingBtcWithdrawal.fetch(pendingPda);
+    const selected = outpointKeys(plan.btcInputs);
+    expect(outpointKeys(pending.inputs)).to.deep.equal(selected);
+
+    const reservedBy = async (id: number[] | null) =>
+      (await fetchVaultUtxos())
+        .filter(({ reservedFor }) =>
+          id === null
+            ? reservedFor === null
+            : reservedFor !== null &&
+              Buffer.from(reservedFor).equals(Buffer.from(id))
+        )
+        .map(({ outpoint }) => outpoint);
+    expect(outpointKeys(await reservedBy(requestId))).to.deep.equal(selected);
+
+    // A failed withdrawal releases its inputs for later withdrawals
+    const serializedOutput = Buffer.from([0xde, 0xad, 0xbe, 0xef, 0x00]);
+    const completeTx = await program.methods
+      .completeWithdrawBtc(
+        requestId,
+        serializedOutput,
+        await signHashWithMpcForWithdrawal(
+          computeMessageHash(requestId, serializedOutput)
+        )
+      )
+      .accountsPartial({
+        vaultUtxoSet: deriveVaultUtxoSetPda(),
+        userBalance: deriveUserBalancePda(provider.wallet.publicKey),
+      })
+      .preInstructions([
+        ComputeBudgetProgram.setComputeUnitLimit({ units: COMPUTE_UNITS }),
+      ])
+      .rpc();
+    await provider.connection.confirmTransaction(completeTx);
+
+    expect(outpointKeys(await reservedBy(requestId))).to.be.empty;
+    expect(outpointKeys(await reservedBy(null))).to.include.members(selected);
+  });
+
   it("rejects withdrawals when user balance cannot cover amount plus fee", async function () {
     const { provider, program } = getBitcoinTestContext();
     const authority = await createFundedAuthority();
@@ -257,15 +567,13 @@ describe("BTC Sad Path", () => {
     const withdrawPlan = await buildWithdrawalPlan({
       mode: "mock",
       amount: 900,
-      fee: 200,
-      inputValue: 1_300,
+      feeRate: 2,
     });
 
     await expectAnchorError(
       program.methods
         .withdrawBtc(
           planRequestIdBytes(withdrawPlan),
-          withdrawPlan.btcInputs,
           withdrawPlan.amount,
           withdrawPlan.recipient.address,
           withdrawPlan.txParams
@@ -274,6 +582,9 @@ describe("BTC Sad Path", () => {
           authority: authority.publicKey,
           feePayer: provider.wallet.publicKey,
           instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+          vaultUtxoSet: deriveVaultUtxoSetPda(),
+          userBalance: deriveUserBalancePda(authority.publicKey),
+          userRateLimit: deriveUserRateLimitPda(authority.publicKey),
         })
         .signers([authority])
         .rpc(),
@@ -292,14 +603,11 @@ describe("BTC Sad Path", () => {
     const withdrawPlan = await buildWithdrawalPlan({
       mode: "mock",
       amount: 2_000,
-      fee: 50,
-      inputValue: 2_300,
     });
 
     const withdrawTx = await program.methods
       .withdrawBtc(
         planRequestIdBytes(withdrawPlan),
-        withdrawPlan.btcInputs,
         withdrawPlan.amount,
         withdrawPlan.recipient.address,
         withdrawPlan.txParams
@@ -308,6 +616,9 @@ describe("BTC Sad Path", () => {
         authority: provider.wallet.publicKey,
         feePayer: provider.wallet.publicKey,
         instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        vaultUtxoSet: deriveVaultUtxoSetPda(),
+        userBalance: deriveUserBalancePda(provider.wallet.publicKey),
+        userRateLimit: deriveUserRateLimitPda(provider.wallet.publicKey),
       })
       .rpc();
     await provider.connection.confirmTransaction(withdrawTx);
@@ -323,6 +634,10 @@ describe("BTC Sad Path", () => {
         serializedOutput,
         refundSignature
       )
+      .accountsPartial({
+        vaultUtxoSet: deriveVaultUtxoSetPda(),
+        userBalance: deriveUserBalancePda(provider.wallet.publicKey),
+      })
       .preInstructions([
         ComputeBudgetProgram.setComputeUnitLimit({ units: COMPUTE_UNITS }),
       ])
@@ -335,4 +650,223 @@ describe("BTC Sad Path", () => {
 
     expect(balanceAfter.toString()).to.equal(balanceBefore.toString());
   });
+
+  it("cancels timed-out withdrawals through a governance proposal", async function () {
+    const { provider, program } = getBitcoinTestContext();
+    await executeSyntheticDeposit(7_500);
+
+    const { amount: balanceBefore } = await fetchUserBalance(
+      provider.wallet.publicKey
+    );
+
+    const withdrawPlan = await buildWithdrawalPlan({
+      mode: "mock",
+      amount: 2_000,
+    });
+
+    const withdrawTx = await program.methods
+      .withdrawBtc(
+        planRequestIdBytes(withdrawPlan),
+        withdrawPlan.amount,
+        withdrawPlan.recipient.address,
+        withdrawPlan.txParams
+      )
+      .accounts({
+        authority: provider.wallet.publicKey,
+        feePayer: provider.wallet.publicKey,
+        instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        vaultUtxoSet: deriveVaultUtxoSetPda(),
+        userBalance: deriveUserBalancePda(provider.wallet.publicKey),
+        userRateLimit: deriveUserRateLimitPda(provider.wallet.publicKey),
+      })
+      .rpc();
+    await provider.connection.confirmTransaction(withdrawTx);
+
+    const requestIdBytes = planRequestIdBytes(withdrawPlan);
+    const proposalId = await proposeConfigChange(program, provider, {
+      cancelWithdrawal: { requestId: requestIdBytes },
+    });
+    const cancel = (feeRate: number) => {
+      const cancellation = composeCancellationPlan(withdrawPlan, feeRate);
+      return program.methods
+        .cancelWithdrawBtc(
+          proposalId,
+          requestIdBytes,
+          planRequestIdBytes(cancellation),
+          new BN(feeRate)
+        )
+        .accountsPartial({
+          executor: provider.wallet.publicKey,
+          proposal: findConfigProposalPda(program.programId, proposalId),
+          proposer: provider.wallet.publicKey,
+          vaultUtxoSet: deriveVaultUtxoSetPda(),
+          feePayer: provider.wallet.publicKey,
+          instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        })
+        .preInstructions([
+          ComputeBudgetProgram.setComputeUnitLimit({ units: COMPUTE_UNITS }),
+        ])
+        .rpc();
+    };
+    // Outbids the withdrawal plus the relay fee on the cancellation's own size
+    const cancelFeeRate = withdrawPlan.feeRate * 4;
+
+    await expectAnchorError(
+      cancel(cancelFeeRate),
+      /Withdrawal cancellation is disabled/
+    );
+
+    await applyConfigChange(program, provider, {
+      setWithdrawalTimeout: { timeoutSlots: new BN(1_000_000) },
+    });
+    await expectAnchorError(
+      cancel(cancelFeeRate),
+      /Withdrawal has not timed out yet/
+    );
+
+    await applyConfigChange(program, provider, {
+      setWithdrawalTimeout: { timeoutSlots: new BN(1) },
+    });
+    try {
+      // The cancellation must outbid the withdrawal for nodes to replace it
+      await expectAnchorError(
+        cancel(withdrawPlan.feeRate),
+        /Replacement fees must exceed/
+      );
+      const cancelTx = await cancel(cancelFeeRate);
+      await provider.connection.confirmTransaction(cancelTx);
+    } finally {
+      await applyConfigChange(program, provider, {
+        setWithdrawalTimeout: { timeoutSlots: new BN(0) },
+      });
+    }
+
+    // Signing the cancellation refunds nothing until its success is reported
+    const { amount: balancePending } = await fetchUserBalance(
+      provider.wallet.publicKey
+    );
+    expect(balancePending.lt(balanceBefore)).to.equal(true);
+
+    const cancellation = composeCancellationPlan(withdrawPlan, cancelFeeRate);
+    const cancelRequestId = planRequestIdBytes(cancellation);
+    const serializedOutput = Buffer.from([1]);
+    const completeTx = await program.methods
+      .completeWithdrawBtc(
+        requestIdBytes,
+        serializedOutput,
+        await signHashWithMpcForWithdrawal(
+          computeMessageHash(cancelRequestId, serializedOutput)
+        )
+      )
+      .accountsPartial({
+        vaultUtxoSet: deriveVaultUtxoSetPda(),
+        userBalance: deriveUserBalancePda(provider.wallet.publicKey),
+      })
+      .preInstructions([
+        ComputeBudgetProgram.setComputeUnitLimit({ units: COMPUTE_UNITS }),
+      ])
+      .rpc();
+    await provider.connection.confirmTransaction(completeTx);
+
+    // The user bears the cancellation's network fee instead of the withdrawal's
+    const { amount: balanceAfter } = await fetchUserBalance(
+      provider.wallet.publicKey
+    );
+    expect(balanceAfter.toString()).to.equal(
+      balanceBefore.sub(cancellation.fee).toString()
+    );
+
+    // The inputs are spent and the cancellation's output joins the vault
+    const vaultUtxos = (await fetchVaultUtxos()).map(
+      ({ outpoint }) =>
+        `${Buffer.from(outpoint.txid).toString("hex")}:${outpoint.vout}`
+    );
+    for (const input of withdrawPlan.btcInputs) {
+      expect(vaultUtxos).to.not.include(
+        `${Buffer.from(input.txid).toString("hex")}:${input.vout}`
+      );
+    }
+    expect(vaultUtxos).to.include(`${cancellation.txidExplorerHex}:0`);
+  });
+
+  it("sweeps vault UTXOs into one output through a governance proposal", async function () {
+    const { provider, program } = getBitcoinTestContext();
+    const caip2Id = CONFIG.BITCOIN_CAIP2_ID;
+
+    // The sweep's network fee comes out of the chain's accrued fees
+    await setChainFees(program, provider, caip2Id, {
+      depositFeeBps: 1_000,
+      withdrawalFeeBps: 0,
+    });
+    try {
+      await executeSyntheticDeposit(10_000);
+    } finally {
+      await setChainFees(program, provider, caip2Id, {
+        depositFeeBps: 0,
+        withdrawalFeeBps: 0,
+      });
+    }
+
+    const feeRate = 1;
+    const plan = await buildSweepPlan(feeRate);
+    const requestId = planRequestIdBytes(plan);
+    const accruedBefore = await fetchAccruedFees();
+
+    const proposalId = await proposeConfigChange(program, provider, {
+      sweepVaultUtxos: {
+        caip2Id,
+        keyVersion: CONFIG.KEY_VERSION,
+        feeRate: new BN(feeRate),
+      },
+    });
+    const sweepTx = await program.methods
+      .sweepBtc(proposalId, requestId, caip2Id)
+      .accountsPartial({
+        executor: provider.wallet.publicKey,
+        proposal: findConfigProposalPda(program.programId, proposalId),
+        proposer: provider.wallet.publicKey,
+        vaultUtxoSet: deriveVaultUtxoSetPda(),
+        feePayer: provider.wallet.publicKey,
+        instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+      })
+      .preInstructions([
+        ComputeBudgetProgram.setComputeUnitLimit({ units: COMPUTE_UNITS }),
+      ])
+      .rpc();
+    await provider.connection.confirmTransaction(sweepTx);
+
+    expect((await fetchAccruedFees()).toString()).to.equal(
+      accruedBefore.sub(plan.fee).toString()
+    );
+
+    const serializedOutput = Buffer.from([1]);
+    const completeTx = await program.methods
+      .completeSweepBtc(
+        requestId,
+        serializedOutput,
+        await signHashWithMpcForWithdrawal(
+          computeMessageHash(requestId, serializedOutput)
+        )
+      )
+      .accountsPartial({
+        vaultUtxoSet: deriveVaultUtxoSetPda(),
+      })
+      .preInstructions([
+        ComputeBudgetProgram.setComputeUnitLimit({ units: COMPUTE_UNITS }),
+      ])
+      .rpc();
+    await provider.connection.confirmTransaction(completeTx);
+
+    // The swept inputs leave the set and the merged output joins it
+    const vaultUtxos = (await fetchVaultUtxos()).map(
+      ({ outpoint }) =>
+        `${Buffer.from(outpoint.txid).toString("hex")}:${outpoint.vout}`
+    );
+    for (const input of plan.btcInputs) {
+      expect(vaultUtxos).to.not.include(
+        `${Buffer.from(input.txid).toString("hex")}:${input.vout}`
+      );
+    }
+    expect(vaultUtxos).to.include(`${plan.txidExplorerHex}:0`);
+  });
 });
@@ -19,6 +19,14 @@ import {
 
 const { getRequestIdBidirectional } = contracts.solana;
 import { CONFIG, SERVER_CONFIG } from "../../utils/envConfig";
+import {
+  addVaultUtxos,
+  ensureChainRegistered,
+  findUserBtcBalancePda,
+  findUserBtcRateLimitPda,
+  findVaultUtxoSetPda,
+  removeVaultUtxos,
+} from "../../utils/governance";
 import { randomBytes } from "crypto";
 
 export interface UTXO {
@@ -45,12 +53,14 @@ export interface BtcOutput {
 export interface BtcDepositParams {
   lockTime: number;
   caip2Id: string;
-  vaultScriptPubkey: Buffer;
+  fee: BN;
 }
 
-export interface BtcWithdrawParams extends BtcDepositParams {
+export interface BtcWithdrawParams {
+  lockTime: number;
+  caip2Id: string;
   recipientScriptPubkey: Buffer;
-  fee: BN;
+  feeRate: BN;
 }
 
 export type AffinePoint = {
@@ -128,8 +138,16 @@ const DEFAULT_LOCK_TIME = 0;
 // Deposit/withdrawal amounts and fees
 export const DEFAULT_DEPOSIT_AMOUNT = 5_000;
 export const WITHDRAW_FEE_BUDGET = 500;
+export const WITHDRAW_FEE_RATE = 2;
 export const SYNTHETIC_TX_FEE = 200;
-const MIN_WITHDRAW_CHANGE_SATS = 600;
+// Mirrors P2WPKH_DUST_LIMIT in the program
+export const P2WPKH_DUST_LIMIT = 294;
+
+// Mirror the withdrawal coin selection in the program
+const MAX_WITHDRAWAL_INPUTS = 10;
+const TX_OVERHEAD_WEIGHT = 42;
+const P2WPKH_INPUT_WEIGHT = 272;
+const P2WPKH_SCRIPT_LEN = 22;
 
 // Multi-input deposit configuration
 const MULTI_INPUT_TARGET = 4;
@@ -172,6 +190,7 @@ export async function setupBitcoinTestContext(): Promise<BitcoinTestContext> {
       .SolanaCoreContracts as Program<SolanaCoreContracts>;
 
     await ensureVaultConfigInitialized(program, provider);
+    await ensureChainRegistered(program, provider, CONFIG.BITCOIN_CAIP2_ID);
 
     btcUtils = new BitcoinUtils(CONFIG.BITCOIN_NETWORK);
     bitcoinAdapter = await BitcoinAdapterFactory.create(CONFIG.BITCOIN_NETWORK);
@@ -237,11 +256,6 @@ export async function teardownBitcoinTestContext(): Promise<void> {
   }
 }
 
-type SimpleOutputPlan = {
-  script: Buffer;
-  value: number;
-};
-
 export type BtcTarget = {
   pda: anchor.web3.PublicKey;
   address: string;
@@ -265,7 +279,6 @@ export type DepositPlan = {
   creditedAmount: BN;
   vaultAuthority: BtcTarget;
   globalVault: BtcTarget;
-  changeScript?: Buffer;
 };
 
 export type WithdrawalPlan = {
@@ -279,7 +292,7 @@ export type WithdrawalPlan = {
   requestIdHex: Hex;
   globalVault: BtcTarget;
   selectedUtxos: UTXO[];
-  feeBudget: number;
+  feeRate: number;
 };
 
 // Deposit plan flavors used across integration tests:
@@ -301,25 +314,24 @@ type DepositBuildOptions =
       mode: "mock";
       requester?: anchor.web3.PublicKey;
       amount?: number;
-      includeVaultOutput?: boolean;
+      fee?: number;
       inputValue?: number;
-      extraOutputs?: SimpleOutputPlan[];
     };
 
-// Withdrawal plan flavors:
-// - live: withdraws real UTXOs from the on-chain global vault (regtest adapter funding).
-// - mock: fabricates a withdrawal PSBT for validation/error paths without Bitcoin RPC.
+// Withdrawal plan flavors. Both predict the program's coin selection over the
+// on-chain vault UTXO set:
+// - live: syncs the set with the global vault's real UTXOs first, so the withdrawal can be broadcast.
+// - mock: selects from the set as is, for validation/error paths without Bitcoin RPC.
 type WithdrawalBuildOptions =
   | {
       mode: "live";
       authority: anchor.web3.Keypair;
-      feeBudget?: number;
+      feeRate?: number;
     }
   | {
       mode: "mock";
       amount?: number;
-      fee?: number;
-      inputValue?: number;
+      feeRate?: number;
     };
 
 type GlobalVaultContext = {
@@ -354,6 +366,7 @@ const buildTransaction = (
     sender: string;
     caip2Id: string;
     path: string;
+    keyVersion?: number;
   },
 ): {
   tx: bitcoin.Transaction;
@@ -380,7 +393,7 @@ const buildTransaction = (
     sender: requestIdParams.sender,
     payload: Array.from(Buffer.from(txidExplorerHex, "hex")),
     caip2Id: requestIdParams.caip2Id,
-    keyVersion: CONFIG.KEY_VERSION,
+    keyVersion: requestIdParams.keyVersion ?? CONFIG.KEY_VERSION,
     path: requestIdParams.path,
     algo: "ECDSA",
     dest: "bitcoin",
@@ -398,22 +411,42 @@ export const planRequestIdBytes = (plan: { requestIdHex: string }): number[] =>
   requestIdToBytes(plan.requestIdHex as Hex);
 
 /**
- * Builds a fully-resolved deposit plan: tx params, request id, credited amount, and destination metadata used by tests.
- * Throws if the request id cannot be deterministically recomputed.
+ * Builds a fully-resolved deposit plan: canonical outputs, tx params, request id, credited amount, and destination metadata used by tests.
+ * Mirrors the program, which sends `amount` to the global vault and any change back to the requester's deposit address.
+ * Throws if the inputs cannot cover amount + fee or the request id cannot be deterministically recomputed.
  * Note: lockTime is always 0 and caip2Id is always CONFIG.BITCOIN_CAIP2_ID for Bitcoin deposits.
  */
 export const composeDepositPlan = (params: {
   requester: anchor.web3.PublicKey;
   btcInputs: BtcInput[];
-  btcOutputs: BtcOutput[];
+  amount: number;
+  fee: number;
   path: string;
   vaultAuthority: BtcTarget;
   globalVault: BtcTarget;
-  changeScript?: Buffer;
 }): DepositPlan => {
+  const totalInputValue = params.btcInputs.reduce(
+    (acc, cur) => acc.add(cur.value),
+    new BN(0),
+  );
+  const changeValue = totalInputValue.subn(params.amount).subn(params.fee);
+  if (changeValue.isNeg()) {
+    throw new Error("Provided inputs do not cover amount + fee");
+  }
+
+  const btcOutputs: BtcOutput[] = [
+    { scriptPubkey: params.globalVault.script, value: new BN(params.amount) },
+  ];
+  if (changeValue.gt(new BN(0))) {
+    btcOutputs.push({
+      scriptPubkey: params.vaultAuthority.script,
+      value: changeValue,
+    });
+  }
+
   const { txidExplorerHex, requestIdHex } = buildTransaction(
     params.btcInputs,
-    params.btcOutputs,
+    btcOutputs,
     0, // lockTime is always 0
     {
       sender: params.vaultAuthority.pda.toString(),
@@ -426,35 +459,28 @@ export const composeDepositPlan = (params: {
     throw new Error("Failed to compute deposit request id");
   }
 
-  const vaultScriptHex = params.globalVault.script.toString("hex");
-  const creditedAmount = params.btcOutputs.reduce((acc, output) => {
-    const scriptHex = Buffer.from(output.scriptPubkey).toString("hex");
-    return scriptHex === vaultScriptHex ? acc.add(output.value) : acc;
-  }, new BN(0));
-
   const txParams: BtcDepositParams = {
     lockTime: 0,
     caip2Id: CONFIG.BITCOIN_CAIP2_ID,
-    vaultScriptPubkey: params.globalVault.script,
+    fee: new BN(params.fee),
   };
 
   return {
     requester: params.requester,
     btcInputs: params.btcInputs,
-    btcOutputs: params.btcOutputs,
+    btcOutputs,
     txParams,
     path: params.path,
     txidExplorerHex,
     requestIdHex,
-    creditedAmount,
+    creditedAmount: new BN(params.amount),
     vaultAuthority: params.vaultAuthority,
     globalVault: params.globalVault,
-    changeScript: params.changeScript,
   };
 };
 
 /**
- * Compose a withdrawal plan with derived change, request id, and tx params validated against provided inputs.
+ * Compose a withdrawal plan with derived change, request id, and tx params from the selected vault UTXOs.
  * Computes the deterministic request id for signature collection.
  * Note: lockTime is always 0 and caip2Id is always CONFIG.BITCOIN_CAIP2_ID for Bitcoin withdrawals.
  */
@@ -465,7 +491,7 @@ export const composeWithdrawalPlan = (params: {
   recipient: BtcDestination;
   globalVault: BtcTarget;
   selectedUtxos: UTXO[];
-  feeBudget: number;
+  feeRate: number;
 }): WithdrawalPlan => {
   const totalInputValue = params.btcInputs.reduce(
     (acc, cur) => acc.add(cur.value),
@@ -504,9 +530,8 @@ export const composeWithdrawalPlan = (params: {
   const txParams: BtcWithdrawParams = {
     lockTime: 0,
     caip2Id: CONFIG.BITCOIN_CAIP2_ID,
-    vaultScriptPubkey: params.globalVault.script,
     recipientScriptPubkey: params.recipient.script,
-    fee: params.fee,
+    feeRate: new BN(params.feeRate),
   };
 
   return {
@@ -520,10 +545,104 @@ export const composeWithdrawalPlan = (params: {
     requestIdHex,
     globalVault: params.globalVault,
     selectedUtxos: params.selectedUtxos,
-    feeBudget: params.feeBudget,
+    feeRate: params.feeRate,
   };
 };
 
+export type CancellationPlan = {
+  output: BN;
+  fee: BN;
+  txidExplorerHex: string;
+  requestIdHex: Hex;
+};
+
+/**
+ * Mirrors `cancel_withdraw_btc`: spends a withdrawal's inputs back to the global vault script in a single output, paying `feeRate` per vbyte.
+ */
+export const composeCancellationPlan = (
+  plan: WithdrawalPlan,
+  feeRate: number,
+): CancellationPlan => {
+  const totalInputValue = plan.btcInputs.reduce(
+    (acc, cur) => acc.add(cur.value),
+    new BN(0),
+  );
+  const fee = new BN(
+    feeRate * withdrawalVsize(plan.btcInputs.length, P2WPKH_SCRIPT_LEN, false),
+  );
+  const output = totalInputValue.sub(fee);
+
+  const { txidExplorerHex, requestIdHex } = buildTransaction(
+    plan.btcInputs,
+    [{ scriptPubkey: plan.globalVault.script, value: output }],
+    0,
+    {
+      sender: plan.globalVault.pda.toString(),
+      caip2Id: CONFIG.BITCOIN_CAIP2_ID,
+      path: CONFIG.BITCOIN_WITHDRAW_PATH,
+    },
+  );
+
+  return { output, fee, txidExplorerHex, requestIdHex };
+};
+
+export type SweepPlan = {
+  btcInputs: BtcInput[];
+  output: BN;
+  fee: BN;
+  txidExplorerHex: string;
+  requestIdHex: Hex;
+};
+
+/**
+ * Mirrors `sweep_btc` and `VaultUtxoSet::select_sweep`: the smallest unreserved UTXOs under `keyVersion`, ties broken by outpoint, merged into one output to the global vault script of the active key at `feeRate` per vbyte.
+ */
+export const buildSweepPlan = async (
+  feeRate: number,
+  keyVersion = CONFIG.KEY_VERSION,
+): Promise<SweepPlan> => {
+  const { globalVault } = deriveGlobalVaultContext();
+  const selected: UTXO[] = (await fetchVaultUtxos())
+    .filter(
+      (entry) =>
+        entry.reservedFor === null && entry.keyVersion === keyVersion,
+    )
+    .map((entry) => ({
+      txid: Buffer.from(entry.outpoint.txid).toString("hex"),
+      vout: entry.outpoint.vout,
+      value: entry.value.toNumber(),
+    }))
+    .sort(
+      (a, b) =>
+        a.value - b.value || a.txid.localeCompare(b.txid) || a.vout - b.vout,
+    )
+    .slice(0, MAX_WITHDRAWAL_INPUTS);
+
+  const btcInputs = selected.map((utxo) =>
+    toBtcInput(utxo, globalVault.script),
+  );
+  const fee = new BN(
+    feeRate * withdrawalVsize(btcInputs.length, P2WPKH_SCRIPT_LEN, false),
+  );
+  const output = btcInputs
+    .reduce((acc, cur) => acc.add(cur.value), new BN(0))
+    .sub(fee);
+
+  const { txidExplorerHex, requestIdHex } = buildTransaction(
+    btcInputs,
+    [{ scriptPubkey: globalVault.script, value: output }],
+    0,
+    {
+      sender: globalVault.pda.toString(),
+      caip2Id: CONFIG.BITCOIN_CAIP2_ID,
+      path: CONFIG.BITCOIN_WITHDRAW_PATH,
+      keyVersion,
+    },
+  );
+
+  return { btcInputs, output, fee, txidExplorerHex, requestIdHex };
+};
+
 // Constants for Bitcoin Chain Signatures
 const CHAIN_SIG_ALGO = "ECDSA";
 const CHAIN_SIG_DEST = "bitcoin";
@@ -619,7 +738,6 @@ export const buildDepositPlan = async (
 
       const utxo = utxos.find((u) => u.value >= minValue) ?? utxos[0];
       const btcInputs: BtcInput[] = [toBtcInput(utxo, vaultAuthority.script)];
-      const btcOutputs: BtcOutput[] = [];
 
       const changeValue = utxo.value - amount - fee;
       if (changeValue < 0) {
@@ -627,21 +745,13 @@ export const buildDepositPlan = async (
           `UTXO value ${utxo.value} sats cannot cover amount ${amount} + fee ${fee}`,
         );
       }
-      btcOutputs.push({
-        scriptPubkey: globalVault.script,
-        value: new BN(amount),
-      });
-      if (changeValue > 0) {
-        btcOutputs.push({
-          scriptPubkey: vaultAuthority.script,
-          value: new BN(changeValue),
-        });
-      }
 
       return composeDepositPlan({
         requester,
         btcInputs,
-        btcOutputs,
+        amount,
+        // Dust change is rejected on-chain, so it goes to the miner instead
+        fee: changeValue < P2WPKH_DUST_LIMIT ? fee + changeValue : fee,
         path,
         vaultAuthority,
         globalVault,
@@ -653,8 +763,6 @@ export const buildDepositPlan = async (
         requester.publicKey,
       );
 
-      const changeScript = deriveChangeScript(vaultAuthority, path);
-
       const inventory = await ensureUtxos(
         bitcoinAdapter,
         vaultAuthority.address,
@@ -684,28 +792,14 @@ export const buildDepositPlan = async (
         toBtcInput(utxo, vaultAuthority.script),
       );
 
-      const btcOutputs: BtcOutput[] = [
-        {
-          scriptPubkey: globalVault.script,
-          value: new BN(vaultValue),
-        },
-      ];
-
-      if (changeValue > 0) {
-        btcOutputs.push({
-          scriptPubkey: changeScript,
-          value: new BN(changeValue),
-        });
-      }
-
       return composeDepositPlan({
         requester: requester.publicKey,
         btcInputs,
-        btcOutputs,
+        amount: vaultValue,
+        fee: MULTI_INPUT_BASE_FEE,
         path,
         vaultAuthority,
         globalVault,
-        changeScript,
       });
     }
     case "mock": {
@@ -716,18 +810,7 @@ export const buildDepositPlan = async (
       const { path, vaultAuthority, globalVault } =
         deriveVaultContext(requester);
 
-      const primaryOutputScript =
-        options.includeVaultOutput === false
-          ? vaultAuthority.script
-          : globalVault.script;
-
-      const plannedOutputs: SimpleOutputPlan[] = [
-        {
-          script: primaryOutputScript,
-          value: amount,
-        },
-        ...(options.extraOutputs ?? []),
-      ];
+      const fee = options.fee ?? DEFAULT_MOCK_FEE;
 
       const mockTxid = randomBytes(32);
       const btcInputs: BtcInput[] = [
@@ -739,15 +822,11 @@ export const buildDepositPlan = async (
         },
       ];
 
-      const btcOutputs: BtcOutput[] = plannedOutputs.map((output) => ({
-        scriptPubkey: output.script,
-        value: new BN(output.value),
-      }));
-
       return composeDepositPlan({
         requester,
         btcInputs,
-        btcOutputs,
+        amount,
+        fee,
         path,
         vaultAuthority,
         globalVault,
@@ -766,83 +845,79 @@ export const buildWithdrawalPlan = async (
 
   switch (options.mode) {
     case "live": {
-      const feeBudget = options.feeBudget ?? WITHDRAW_FEE_BUDGET;
-      const balanceInfo = await fetchUserBalance(options.authority.publicKey);
-      if (balanceInfo.amount.lte(new BN(feeBudget))) {
-        throw new Error("Insufficient balance to cover withdrawal fee");
-      }
-
-      const withdrawAmountBn = balanceInfo.amount.sub(new BN(feeBudget));
+      const feeRate = options.feeRate ?? WITHDRAW_FEE_RATE;
+      const balance = (
+        await fetchUserBalance(options.authority.publicKey)
+      ).amount.toNumber();
 
       const { globalVault } = deriveGlobalVaultContext();
-
-      const globalVaultUtxos =
-        (await bitcoinAdapter.getAddressUtxos(globalVault.address)) ?? [];
-      const targetTotal = withdrawAmountBn.toNumber() + feeBudget;
-      const { selected: selectedUtxos, total } = selectUtxosForTarget(
-        globalVaultUtxos,
-        targetTotal,
-        { minChange: MIN_WITHDRAW_CHANGE_SATS },
+      await syncVaultUtxoSet(
+        (await bitcoinAdapter.getAddressUtxos(globalVault.address)) ?? [],
       );
+      const vaultUtxos = await fetchVaultUtxos();
+      const recipient = buildExternalDestination();
 
-      if (selectedUtxos.length === 0 || total < targetTotal) {
-        throw new Error(
-          `Unable to collect sufficient global vault liquidity for ${targetTotal} sats`,
+      // The fee depends on the selected inputs, so shrink the amount until
+      // amount + fee fits the balance
+      let amount = balance - WITHDRAW_FEE_BUDGET;
+      let selection = selectVaultUtxos(
+        vaultUtxos,
+        amount,
+        feeRate,
+        recipient.script.length,
+      );
+      for (let attempt = 0; amount + selection.fee > balance; attempt++) {
+        if (attempt === MAX_WITHDRAWAL_INPUTS) {
+          throw new Error("Unable to fit withdrawal amount + fee in balance");
+        }
+        amount = balance - selection.fee;
+        selection = selectVaultUtxos(
+          vaultUtxos,
+          amount,
+          feeRate,
+          recipient.script.length,
         );
       }
-
-      const changeValue = total - targetTotal;
-      if (changeValue > 0 && changeValue < MIN_WITHDRAW_CHANGE_SATS) {
-        throw new Error(
-          `Unable to construct withdrawal with non-dust change (change=${changeValue} sats). Add liquidity or increase fee budget.`,
-        );
+      if (amount < P2WPKH_DUST_LIMIT) {
+        throw new Error("Insufficient balance to cover withdrawal fee");
       }
 
-      const recipient = buildExternalDestination();
-
-      const btcInputs = selectedUtxos.map((utxo) =>
-        toBtcInput(utxo, globalVault.script),
-      );
-
-      // Change is guaranteed to be zero or above the dust threshold.
       return composeWithdrawalPlan({
-        btcInputs,
-        amount: withdrawAmountBn,
-        fee: new BN(feeBudget),
+        btcInputs: selection.selected.map((utxo) =>
+          toBtcInput(utxo, globalVault.script),
+        ),
+        amount: new BN(amount),
+        fee: new BN(selection.fee),
         recipient,
         globalVault,
-        selectedUtxos,
-        feeBudget,
+        selectedUtxos: selection.selected,
+        feeRate,
       });
     }
     case "mock": {
-      const amountValue = options.amount ?? 2_000;
-      const feeValue = options.fee ?? DEFAULT_MOCK_FEE;
-      const inputValue =
-        options.inputValue ?? amountValue + feeValue + DEFAULT_MOCK_FEE;
+      const amount = options.amount ?? 2_000;
+      const feeRate = options.feeRate ?? WITHDRAW_FEE_RATE;
 
       const { globalVault } = deriveGlobalVaultContext();
-
       const recipient = buildExternalDestination();
 
-      const mockTxid = randomBytes(32);
-      const btcInputs: BtcInput[] = [
-        {
-          txid: Array.from(mockTxid),
-          vout: 0,
-          scriptPubkey: globalVault.script,
-          value: new BN(inputValue),
-        },
-      ];
+      const selection = selectVaultUtxos(
+        await fetchVaultUtxos(),
+        amount,
+        feeRate,
+        recipient.script.length,
+      );
 
       return composeWithdrawalPlan({
-        btcInputs,
-        amount: new BN(amountValue),
-        fee: new BN(feeValue),
+        btcInputs: selection.selected.map((utxo) =>
+          toBtcInput(utxo, globalVault.script),
+        ),
+        amount: new BN(amount),
+        fee: new BN(selection.fee),
         recipient,
         globalVault,
-        selectedUtxos: [],
-        feeBudget: feeValue,
+        selectedUtxos: selection.selected,
+        feeRate,
       });
     }
   }
@@ -1018,10 +1093,14 @@ export function startBtcEventListeners(
     return map;
   };
 
-  return {
-    waitForSignatureMap,
-    readRespond: respondPromise as BtcEventListeners["readRespond"],
-  };
+  const readRespond = (
+    respondPromise as BtcEventListeners["readRespond"]
+  ).then((event) => ({
+    ...event,
+    signature: normalizeResponseSignature(event.signature),
+  }));
+
+  return { waitForSignatureMap, readRespond };
 }
 
 /**
@@ -1049,7 +1128,7 @@ export async function executeSyntheticDeposit(
       requestIdToBytes(requestIdHex),
       preparedPlan.requester,
       preparedPlan.btcInputs,
-      preparedPlan.btcOutputs,
+      preparedPlan.creditedAmount,
       preparedPlan.txParams,
     )
     .accounts({
@@ -1090,6 +1169,10 @@ export async function executeSyntheticDeposit(
       Buffer.from(readEvent.serializedOutput),
       readEvent.signature,
     )
+    .accountsPartial({
+      vaultUtxoSet: deriveVaultUtxoSetPda(),
+      userBalance: deriveUserBalancePda(preparedPlan.requester),
+    })
     .preInstructions([
       ComputeBudgetProgram.setComputeUnitLimit({ units: COMPUTE_UNITS }),
     ])
@@ -1177,6 +1260,29 @@ export function prepareSignatureWitness(
   return { sigWithHashType, witness };
 }
 
+/**
+ * Returns an MPC response signature in low-S form, flipping the recovery ID when `s` is negated. The program rejects high-S response signatures.
+ * Same rule as `normalizeResponseSignature` in the frontend relayer (frontend/lib/utils/signature.ts).
+ */
+export function normalizeResponseSignature(
+  signature: ChainSignaturePayload,
+): ChainSignaturePayload {
+  const s = BigInt("0x" + Buffer.from(signature.s).toString("hex"));
+  if (s <= SECP256K1_HALF_ORDER) {
+    return signature;
+  }
+
+  const lowS = Buffer.from(
+    (SECP256K1_ORDER - s).toString(16).padStart(64, "0"),
+    "hex",
+  );
+  return {
+    ...signature,
+    s: Array.from(lowS),
+    recoveryId: signature.recoveryId ^ 1,
+  };
+}
+
 const sleep = (ms: number) =>
   new Promise<void>((resolve) => setTimeout(resolve, ms));
 
@@ -1326,29 +1432,179 @@ const toBtcInput = (utxo: UTXO, script: Buffer): BtcInput => ({
 });
 
 /**
- * Greedy-selects UTXOs until the target is met while optionally enforcing a minimum change threshold.
+ * Mirrors `withdrawal_vsize` in the program: virtual size of a withdrawal spending `inputs` vault UTXOs.
  */
-const selectUtxosForTarget = (
-  utxos: UTXO[] | null | undefined,
-  target: number,
-  options?: { minChange?: number },
-): { selected: UTXO[]; total: number } => {
-  const sorted = [...(utxos ?? [])].sort((a, b) => b.value - a.value);
+const withdrawalVsize = (
+  inputs: number,
+  recipientScriptLen: number,
+  withChange: boolean,
+): number => {
+  const outputWeight = (scriptLen: number) =>
+    4 * (8 + (scriptLen < 0xfd ? 1 : 3) + scriptLen);
+  const weight =
+    TX_OVERHEAD_WEIGHT +
+    inputs * P2WPKH_INPUT_WEIGHT +
+    outputWeight(recipientScriptLen) +
+    (withChange ? outputWeight(P2WPKH_SCRIPT_LEN) : 0);
+  return Math.ceil(weight / 4);
+};
+
+/**
+ * Mirrors `VaultUtxoSet::select` in the program: largest-first over unreserved UTXOs under the active key version, ties broken by outpoint, with change below the dust limit left to the fee.
+ */
+const selectVaultUtxos = (
+  vaultUtxos: Awaited<ReturnType<typeof fetchVaultUtxos>>,
+  amount: number,
+  feeRate: number,
+  recipientScriptLen: number,
+): { selected: UTXO[]; fee: number } => {
+  const candidates: UTXO[] = vaultUtxos
+    .filter(
+      (entry) =>
+        entry.reservedFor === null && entry.keyVersion === CONFIG.KEY_VERSION,
+    )
+    .map((entry) => ({
+      txid: Buffer.from(entry.outpoint.txid).toString("hex"),
+      vout: entry.outpoint.vout,
+      value: entry.value.toNumber(),
+    }))
+    .sort(
+      (a, b) =>
+        b.value - a.value || a.txid.localeCompare(b.txid) || a.vout - b.vout,
+    );
+
   const selected: UTXO[] = [];
   let total = 0;
-  const minChange = options?.minChange ?? 0;
-
-  for (const utxo of sorted) {
+  for (const utxo of candidates) {
+    if (selected.length === MAX_WITHDRAWAL_INPUTS) {
+      throw new Error("Withdrawal needs too many vault UTXOs");
+    }
     selected.push(utxo);
     total += utxo.value;
-    const change = total - target;
 
-    if (total >= target && (change === 0 || change >= minChange)) {
-      break;
+    const spare = total - amount;
+    if (spare < 0) {
+      continue;
     }
+    const feeWithChange =
+      feeRate * withdrawalVsize(selected.length, recipientScriptLen, true);
+    if (spare >= feeWithChange + P2WPKH_DUST_LIMIT) {
+      return { selected, fee: feeWithChange };
+    }
+    if (
+      spare >=
+      feeRate * withdrawalVsize(selected.length, recipientScriptLen, false)
+    ) {
+      return { selected, fee: spare };
+    }
+  }
+
+  throw new Error(`Vault UTXOs cannot cover ${amount} sats + fee`);
+};
+
+/**
+ * Derives the PDA of the program's vault UTXO set for the Bitcoin test chain.
+ */
+export const deriveVaultUtxoSetPda = (): anchor.web3.PublicKey => {
+  const { program } = requireContext();
+  return findVaultUtxoSetPda(program.programId, CONFIG.BITCOIN_CAIP2_ID);
+};
+
+/**
+ * Fetches the outputs in the program's vault UTXO set, empty before the set exists.
+ */
+export const fetchVaultUtxos = async () => {
+  const { program } = requireContext();
+  const utxoSet = await program.account.vaultUtxoSet.fetchNullable(
+    deriveVaultUtxoSetPda(),
+  );
+  return utxoSet?.utxos ?? [];
+};
+
+/**
+ * Fetches the protocol fees accrued on the Bitcoin test chain.
+ */
+export const fetchAccruedFees = async (): Promise<BN> => {
+  const { program } = requireContext();
+  const [chainRegistryPda] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("chain_registry")],
+    program.programId,
+  );
+  const registry = await program.account.chainRegistry.fetch(chainRegistryPda);
+  const chain = registry.chains.find(
+    (entry) => entry.caip2Id === CONFIG.BITCOIN_CAIP2_ID,
+  );
+  return chain?.accruedFees ?? new BN(0);
+};
+
+/**
+ * Fetches the liabilities tracked for the Bitcoin test chain.
+ */
+export const fetchChainLiabilities = async () => {
+  const { program } = requireContext();
+  const [chainRegistryPda] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("chain_registry")],
+    program.programId,
+  );
+  const registry = await program.account.chainRegistry.fetch(chainRegistryPda);
+  const chain = registry.chains.find(
+    (entry) => entry.caip2Id === CONFIG.BITCOIN_CAIP2_ID,
+  );
+  if (!chain) {
+    throw new Error(`Chain ${CONFIG.BITCOIN_CAIP2_ID} is not registered`);
   }
+  return chain.liabilities;
+};
+
+/**
+ * Registers vault outputs under the active key version through governance.
+ */
+const registerVaultUtxos = async (utxos: UTXO[]): Promise<void> => {
+  const { program, provider } = requireContext();
+  if (utxos.length === 0) {
+    return;
+  }
+
+  await addVaultUtxos(
+    program,
+    provider,
+    CONFIG.BITCOIN_CAIP2_ID,
+    utxos.map((utxo) => ({
+      outpoint: {
+        txid: Array.from(Buffer.from(utxo.txid, "hex")),
+        vout: utxo.vout,
+      },
+      value: new BN(utxo.value),
+      keyVersion: CONFIG.KEY_VERSION,
+      reservedFor: null,
+    })),
+  );
+};
 
-  return { selected, total };
+/**
+ * Makes the program's vault UTXO set match the vault's UTXOs on Bitcoin: registers untracked ones and drops unreserved entries with no UTXO behind them (e.g. from mock deposits), which the program would otherwise select.
+ */
+const syncVaultUtxoSet = async (utxos: UTXO[]): Promise<void> => {
+  const { program, provider } = requireContext();
+  const registered = await fetchVaultUtxos();
+  const entryKey = ({ outpoint }: (typeof registered)[number]) =>
+    `${Buffer.from(outpoint.txid).toString("hex")}:${outpoint.vout}`;
+  const utxoKeys = new Set(utxos.map((utxo) => `${utxo.txid}:${utxo.vout}`));
+  const registeredKeys = new Set(registered.map(entryKey));
+
+  await removeVaultUtxos(
+    program,
+    provider,
+    CONFIG.BITCOIN_CAIP2_ID,
+    registered
+      .filter(
+        (entry) => entry.reservedFor === null && !utxoKeys.has(entryKey(entry)),
+      )
+      .map((entry) => entry.outpoint),
+  );
+  await registerVaultUtxos(
+    utxos.filter((utxo) => !registeredKeys.has(`${utxo.txid}:${utxo.vout}`)),
+  );
 };
 
 /**
@@ -1409,17 +1665,6 @@ const deriveVaultContext = (
   };
 };
 
-/**
- * Derives the change output script for a depositor by appending a ::change path to the vault authority derivation.
- */
-const deriveChangeScript = (
-  vaultAuthority: BtcTarget,
-  path: string,
-): Buffer => {
-  const changePath = `${path}::change`;
-  return deriveBtcTarget(vaultAuthority.pda, changePath).script;
-};
-
 /**
  * Generates a fresh external P2WPKH destination (used as the withdrawal recipient in tests).
  */
@@ -1438,17 +1683,31 @@ const buildExternalDestination = (): BtcDestination => {
 };
 
 /**
- * Derives the user BTC balance PDA for a given authority.
+ * Derives the user BTC balance PDA for a given authority on the test chain.
  */
 export const deriveUserBalancePda = (
   authority: anchor.web3.PublicKey,
 ): anchor.web3.PublicKey => {
   const { program } = requireContext();
-  const [pda] = anchor.web3.PublicKey.findProgramAddressSync(
-    [Buffer.from("user_btc_balance"), authority.toBuffer()],
+  return findUserBtcBalancePda(
     program.programId,
+    authority,
+    CONFIG.BITCOIN_CAIP2_ID,
+  );
+};
+
+/**
+ * Derives the user BTC withdrawal rate-limit PDA for a given authority on the test chain.
+ */
+export const deriveUserRateLimitPda = (
+  authority: anchor.web3.PublicKey,
+): anchor.web3.PublicKey => {
+  const { program } = requireContext();
+  return findUserBtcRateLimitPda(
+    program.programId,
+    authority,
+    CONFIG.BITCOIN_CAIP2_ID,
   );
-  return pda;
 };
 
 /**
@@ -1639,11 +1898,19 @@ async function ensureVaultConfigInitialized(
   const accountInfo = await provider.connection.getAccountInfo(vaultConfigPda);
 
   if (!accountInfo) {
+    // initialize_config is restricted to the program's upgrade authority
+    const [programDataPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [program.programId.toBuffer()],
+      anchor.web3.BPF_LOADER_UPGRADEABLE_PROGRAM_ID,
+    );
+
     await program.methods
-      .initializeConfig(publicKeyBytes)
+      .initializeConfig(publicKeyBytes, CONFIG.KEY_VERSION)
       .accountsStrict({
         payer: provider.wallet.publicKey,
         config: vaultConfigPda,
+        program: program.programId,
+        programData: programDataPda,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
       .rpc();
@@ -6,13 +6,32 @@ import { SolanaCoreContracts } from "../target/types/solana_core_contracts";
 import { expect } from "chai";
 import { ethers } from "ethers";
 import { contracts, utils as signetUtils } from "signet.js";
-import { ChainSignatureServer } from "fakenet-signer";
+import { ChainSignatureServer, CryptoUtils } from "fakenet-signer";
 
 const { getRequestIdBidirectional } = contracts.solana;
 import { CONFIG, SERVER_CONFIG } from "../utils/envConfig";
+import {
+  applyConfigChange,
+  ensureChainRegistered,
+  ensureTokenRegistered,
+  findConfigProposalPda,
+  findEvmVaultNoncePda,
+  MAX_FEE_PER_GAS,
+  MAX_GAS_LIMIT,
+  proposeConfigChange,
+  syncEvmVaultNonce,
+} from "../utils/governance";
+import {
+  computeMessageHash,
+  expectAnchorError,
+  normalizeResponseSignature,
+} from "./bitcoin/utils";
 
 const COMPUTE_UNITS = 1_400_000;
 
+// Mirrors ERROR_PREFIX in the program
+const ERROR_PREFIX = Buffer.from([0xde, 0xad, 0xbe, 0xef]);
+
 interface TransactionParams {
   nonce: BN;
   value: BN;
@@ -153,11 +172,19 @@ async function ensureVaultConfigInitialized(
   const accountInfo = await provider.connection.getAccountInfo(vaultConfigPda);
 
   if (!accountInfo) {
+    // initialize_config is restricted to the program's upgrade authority
+    const [programDataPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [program.programId.toBuffer()],
+      anchor.web3.BPF_LOADER_UPGRADEABLE_PROGRAM_ID,
+    );
+
     await program.methods
-      .initializeConfig(publicKeyBytes)
+      .initializeConfig(publicKeyBytes, CONFIG.KEY_VERSION)
       .accountsStrict({
         payer: provider.wallet.publicKey,
         config: vaultConfigPda,
+        program: program.programId,
+        programData: programDataPda,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
       .rpc();
@@ -212,6 +239,15 @@ describe("🏦 ERC20 Deposit, Withdraw and Withdraw with refund Flow", () => {
       .SolanaCoreContracts as Program<SolanaCoreContracts>;
 
     await ensureVaultConfigInitialized(program, provider);
+    await ensureChainRegistered(program, provider, CONFIG.ETHEREUM_CAIP2_ID);
+    await ensureTokenRegistered(
+      program,
+      provider,
+      CONFIG.SEPOLIA_CHAIN_ID,
+      CONFIG.USDC_ADDRESS_SEPOLIA,
+      "USDC",
+      CONFIG.DECIMALS,
+    );
 
     ethUtils = new EthereumUtils();
 
@@ -367,16 +403,13 @@ describe("🏦 ERC20 Deposit, Withdraw and Withdraw with refund Flow", () => {
       accounts.userBalance,
     );
 
-    const recipientAddressBytes = Array.from(
-      Buffer.from(signerAddress.slice(2), "hex"),
-    );
-
+    // The program derives the sweep recipient (the vault address) itself, so the
+    // transaction built above must transfer to signerAddress.
     const depositTx = await program.methods
       .depositErc20(
         requestIdBytes,
         provider.wallet.publicKey,
         erc20AddressBytes,
-        recipientAddressBytes,
         amountBN,
         txParams,
       )
@@ -468,7 +501,7 @@ describe("🏦 ERC20 Deposit, Withdraw and Withdraw with refund Flow", () => {
       .claimErc20(
         requestIdBytes,
         Buffer.from(respondBidirectionalEvent.serializedOutput),
-        respondBidirectionalEvent.signature,
+        normalizeResponseSignature(respondBidirectionalEvent.signature),
       )
       .accounts({
         userBalance: accounts.userBalance,
@@ -518,6 +551,7 @@ describe("🏦 ERC20 Deposit, Withdraw and Withdraw with refund Flow", () => {
       [
         Buffer.from("user_erc20_balance"),
         provider.wallet.publicKey.toBuffer(),
+        new BN(CONFIG.SEPOLIA_CHAIN_ID).toArrayLike(Buffer, "le", 8),
         Buffer.from(erc20AddressBytes),
       ],
       program.programId,
@@ -582,6 +616,14 @@ describe("🏦 ERC20 Deposit, Withdraw and Withdraw with refund Flow", () => {
     const ethprovider = ethUtils.getProvider();
     const nonce = await ethprovider.getTransactionCount(signerAddress);
 
+    // The program assigns vault nonces; keep it in step with the chain
+    await syncEvmVaultNonce(
+      program,
+      provider,
+      CONFIG.SEPOLIA_CHAIN_ID,
+      nonce,
+    );
+
     // Build withdrawal transaction
     const transferInterface = new ethers.Interface([
       "function transfer(address to, uint256 amount) returns (bool)",
@@ -774,7 +816,7 @@ describe("🏦 ERC20 Deposit, Withdraw and Withdraw with refund Flow", () => {
       .completeWithdrawErc20(
         requestIdBytes,
         Buffer.from(respondBidirectionalEvent.serializedOutput),
-        respondBidirectionalEvent.signature,
+        normalizeResponseSignature(respondBidirectionalEvent.signature),
       )
       .accounts({
         userBalance,
@@ -834,6 +876,7 @@ describe("🏦 ERC20 Deposit, Withdraw and Withdraw with refund Flow", () => {
       [
         Buffer.from("user_erc20_balance"),
         provider.wallet.publicKey.toBuffer(),
+        new BN(CONFIG.SEPOLIA_CHAIN_ID).toArrayLike(Buffer, "le", 8),
         Buffer.from(erc20AddressBytes),
       ],
       program.programId,
@@ -899,6 +942,14 @@ describe("🏦 ERC20 Deposit, Withdraw and Withdraw with refund Flow", () => {
       currentNonce + ")",
     );
 
+    // Rewind the program's vault nonce so it assigns the old one
+    await syncEvmVaultNonce(
+      program,
+      provider,
+      CONFIG.SEPOLIA_CHAIN_ID,
+      oldNonce,
+    );
+
     // Build withdrawal transaction with OLD nonce
     const transferInterface = new ethers.Interface([
       "function transfer(address to, uint256 amount) returns (bool)",
@@ -1074,7 +1125,7 @@ describe("🏦 ERC20 Deposit, Withdraw and Withdraw with refund Flow", () => {
       .completeWithdrawErc20(
         requestIdBytes,
         Buffer.from(respondBidirectionalEvent.serializedOutput),
-        respondBidirectionalEvent.signature,
+        normalizeResponseSignature(respondBidirectionalEvent.signature),
       )
       .accounts({
         userBalance,
@@ -1102,6 +1153,410 @@ describe("🏦 ERC20 Deposit, Withdraw and Withdraw with refund Flow", () => {
 
     console.log("\n🎉 Failed withdrawal handled correctly!");
   });
+
+  describe("Withdrawal gas, replacement and cancellation", () => {
+    const erc20AddressBytes = Array.from(
+      Buffer.from(CONFIG.USDC_ADDRESS_SEPOLIA.slice(2), "hex"),
+    );
+    const recipientAddress = CONFIG.WITHDRAWAL_RECIPIENT_ADDRESS;
+    const recipientAddressBytes = Array.from(
+      Buffer.from(recipientAddress.slice(2), "hex"),
+    );
+    const chainIdBN = new BN(CONFIG.SEPOLIA_CHAIN_ID);
+    const withdrawAmount = new BN(1);
+    const callData = new ethers.Interface([
+      "function transfer(address to, uint256 amount) returns (bool)",
+    ]).encodeFunctionData("transfer", [
+      recipientAddress,
+      withdrawAmount.toString(),
+    ]);
+
+    let userBalance: anchor.web3.PublicKey;
+    let globalVaultAuthority: anchor.web3.PublicKey;
+    let vaultAddress: string;
+    let nonce: number;
+    let balanceBefore: BN;
+
+    beforeEach(async function () {
+      [userBalance] = anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("user_erc20_balance"),
+          provider.wallet.publicKey.toBuffer(),
+          chainIdBN.toArrayLike(Buffer, "le", 8),
+          Buffer.from(erc20AddressBytes),
+        ],
+        program.programId,
+      );
+      [globalVaultAuthority] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("global_vault_authority")],
+        program.programId,
+      );
+      vaultAddress = ethers.computeAddress(
+        "0x" +
+          signetUtils.cryptography.deriveChildPublicKey(
+            CONFIG.MPC_ROOT_PUBLIC_KEY as `04${string}`,
+            globalVaultAuthority.toString(),
+            "root",
+            CONFIG.SOLANA_CAIP2_ID,
+            CONFIG.KEY_VERSION,
+          ),
+      );
+
+      const vaultNonce = await program.account.evmVaultNonce.fetchNullable(
+        findEvmVaultNoncePda(
+          program.programId,
+          CONFIG.SEPOLIA_CHAIN_ID,
+          CONFIG.KEY_VERSION,
+        ),
+      );
+      nonce = vaultNonce ? vaultNonce.nextNonce.toNumber() : 0;
+
+      const balance =
+        await program.account.userErc20Balance.fetchNullable(userBalance);
+      balanceBefore = balance ? (balance.amount as BN) : new BN(0);
+    });
+
+    // Every transaction signed for a withdrawal shares its nonce
+    const signedTransaction = (
+      maxFeePerGas: bigint,
+      maxPriorityFeePerGas: bigint,
+      to: string,
+      data: string,
+    ) => {
+      const gasLimit = BigInt(100_000);
+      const txParams: TransactionParams = {
+        nonce: new BN(nonce),
+        value: new BN(0),
+        maxPriorityFeePerGas: new BN(maxPriorityFeePerGas.toString()),
+        maxFeePerGas: new BN(maxFeePerGas.toString()),
+        gasLimit: new BN(gasLimit.toString()),
+        chainId: chainIdBN,
+      };
+      const rlpEncodedTx = ethers.Transaction.from({
+        type: 2,
+        chainId: CONFIG.SEPOLIA_CHAIN_ID,
+        nonce,
+        maxPriorityFeePerGas,
+        maxFeePerGas,
+        gasLimit,
+        to,
+        value: BigInt(0),
+        data,
+      }).unsignedSerialized;
+      const requestId = getRequestIdBidirectional({
+        sender: globalVaultAuthority.toString(),
+        payload: Array.from(ethers.getBytes(rlpEncodedTx)),
+        caip2Id: CONFIG.ETHEREUM_CAIP2_ID,
+        keyVersion: CONFIG.KEY_VERSION,
+        path: "root",
+        algo: "ECDSA",
+        dest: "ethereum",
+        params: "",
+      });
+
+      return {
+        txParams,
+        requestIdBytes: Array.from(Buffer.from(requestId.slice(2), "hex")),
+      };
+    };
+    const transferTransaction = (
+      maxFeePerGas: bigint,
+      maxPriorityFeePerGas: bigint,
+    ) =>
+      signedTransaction(
+        maxFeePerGas,
+        maxPriorityFeePerGas,
+        CONFIG.USDC_ADDRESS_SEPOLIA,
+        callData,
+      );
+    // A zero-value self-transfer that takes the nonce without paying out
+    const cancellationTransaction = (
+      maxFeePerGas: bigint,
+      maxPriorityFeePerGas: bigint,
+    ) =>
+      signedTransaction(maxFeePerGas, maxPriorityFeePerGas, vaultAddress, "0x");
+
+    const withdraw = (requestIdBytes: number[], txParams: TransactionParams) =>
+      program.methods
+        .withdrawErc20(
+          requestIdBytes,
+          erc20AddressBytes,
+          withdrawAmount,
+          recipientAddressBytes,
+          txParams,
+        )
+        .accounts({
+          authority: provider.wallet.publicKey,
+          feePayer: provider.wallet.publicKey,
+          instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        })
+        .rpc();
+
+    const cancel = async (
+      requestIdBytes: number[],
+      cancellation: ReturnType<typeof signedTransaction>,
+    ) => {
+      const proposalId = await proposeConfigChange(program, provider, {
+        cancelWithdrawal: { requestId: requestIdBytes },
+      });
+      await applyConfigChange(program, provider, {
+        setWithdrawalTimeout: { timeoutSlots: new BN(1) },
+      });
+      try {
+        await program.methods
+          .cancelWithdrawErc20(
+            proposalId,
+            requestIdBytes,
+            cancellation.requestIdBytes,
+            cancellation.txParams,
+          )
+          .accountsPartial({
+            executor: provider.wallet.publicKey,
+            proposal: findConfigProposalPda(program.programId, proposalId),
+            proposer: provider.wallet.publicKey,
+            feePayer: provider.wallet.publicKey,
+            instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+          })
+          .rpc();
+      } finally {
+        await applyConfigChange(program, provider, {
+          setWithdrawalTimeout: { timeoutSlots: new BN(0) },
+        });
+      }
+    };
+
+    const complete = (
+      requestIdBytes: number[],
+      serializedOutput: Buffer,
+      signature: Awaited<ReturnType<typeof signWithdrawalResponse>>,
+    ) =>
+      program.methods
+        .completeWithdrawErc20(requestIdBytes, serializedOutput, signature)
+        .accounts({
+          userBalance,
+        })
+        .preInstructions([
+          ComputeBudgetProgram.setComputeUnitLimit({ units: COMPUTE_UNITS }),
+        ])
+        .rpc();
+
+    const findPendingWithdrawalPda = (requestIdBytes: number[]) =>
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("pending_erc20_withdrawal"),
+          Buffer.from(requestIdBytes),
+        ],
+        program.programId,
+      )[0];
+
+    it("Should reject withdrawals with unbounded gas or native value", async function () {
+      // These checks run before the request ID one, so any ID will do
+      const withdrawWith = (overrides: Partial<TransactionParams>) =>
+        withdraw(Array.from(ethers.randomBytes(32)), {
+          nonce: new BN(0),
+          value: new BN(0),
+          maxPriorityFeePerGas: new BN(1),
+          maxFeePerGas: new BN(1),
+          gasLimit: new BN(21_000),
+          chainId: chainIdBN,
+          ...overrides,
+        });
+
+      await expectAnchorError(
+        withdrawWith({ gasLimit: new BN(MAX_GAS_LIMIT + 1) }),
+        /Gas limit exceeds the chain's bound/,
+      );
+      await expectAnchorError(
+        withdrawWith({ maxFeePerGas: new BN(MAX_FEE_PER_GAS + 1) }),
+        /Max fee per gas exceeds the chain's bound/,
+      );
+      await expectAnchorError(
+        withdrawWith({ maxPriorityFeePerGas: new BN(2) }),
+        /Priority fee exceeds the chain's bound or the max fee per gas/,
+      );
+      await expectAnchorError(
+        withdrawWith({ value: new BN(1) }),
+        /ERC20 transactions must not carry native value/,
+      );
+    });
+
+    it("Should settle a replaced and cancelled withdrawal on the replacement's success", async function () {
+      console.log("\n🚀 Starting Replaced ERC20 Withdrawal Test\n");
+
+      if (balanceBefore.isZero()) {
+        console.log(
+          "  ⚠️ No balance to test withdrawal replacement. Skipping test.",
+        );
+        return;
+      }
+
+      const original = transferTransaction(
+        ethers.parseUnits("2", "gwei"),
+        ethers.parseUnits("1", "gwei"),
+      );
+      const replacement = transferTransaction(
+        ethers.parseUnits("3", "gwei"),
+        ethers.parseUnits("2", "gwei"),
+      );
+
+      await withdraw(original.requestIdBytes, original.txParams);
+
+      const pendingWithdrawal = findPendingWithdrawalPda(
+        original.requestIdBytes,
+      );
+      const balanceAfterWithdraw = await program.account.userErc20Balance.fetch(
+        userBalance,
+      );
+      const { gasFee: originalGasFee } =
+        await program.account.pendingErc20Withdrawal.fetch(pendingWithdrawal);
+
+      await program.methods
+        .replaceWithdrawErc20(
+          original.requestIdBytes,
+          replacement.requestIdBytes,
+          replacement.txParams,
+        )
+        .accounts({
+          authority: provider.wallet.publicKey,
+          feePayer: provider.wallet.publicKey,
+          instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+          userBalance,
+        })
+        .rpc();
+
+      // The replacement may spend 50% more gas, so the gas fee grows by half
+      const { gasFee: replacedGasFee } =
+        await program.account.pendingErc20Withdrawal.fetch(pendingWithdrawal);
+      expect(replacedGasFee.toString()).to.equal(
+        originalGasFee.add(originalGasFee.addn(1).divn(2)).toString(),
+      );
+      const balanceAfterReplace = await program.account.userErc20Balance.fetch(
+        userBalance,
+      );
+      expect(balanceAfterReplace.amount.toString()).to.equal(
+        balanceAfterWithdraw.amount
+          .sub(replacedGasFee.sub(originalGasFee))
+          .toString(),
+      );
+
+      // The original lost the nonce to its replacement, so its failure alone
+      // must not refund the withdrawal
+      const failureOutput = Buffer.concat([
+        ERROR_PREFIX,
+        Buffer.from("nonce too low"),
+      ]);
+      const failureSignature = await signWithdrawalResponse(
+        program,
+        original.requestIdBytes,
+        failureOutput,
+      );
+      await expectAnchorError(
+        complete(original.requestIdBytes, failureOutput, failureSignature),
+        /Another transaction of this withdrawal may still execute/,
+      );
+
+      await program.methods
+        .recordFailedWithdrawErc20(
+          original.requestIdBytes,
+          failureOutput,
+          failureSignature,
+        )
+        .preInstructions([
+          ComputeBudgetProgram.setComputeUnitLimit({ units: COMPUTE_UNITS }),
+        ])
+        .rpc();
+
+      // Governance cancels the withdrawal with a self-transfer taking the nonce
+      const cancellation = cancellationTransaction(
+        ethers.parseUnits("4", "gwei"),
+        ethers.parseUnits("3", "gwei"),
+      );
+      await cancel(original.requestIdBytes, cancellation);
+
+      // The cancellation lost the nonce to the replacement, so its failure must
+      // not refund the withdrawal either
+      const cancellationFailure = await signWithdrawalResponse(
+        program,
+        cancellation.requestIdBytes,
+        failureOutput,
+      );
+      await expectAnchorError(
+        complete(original.requestIdBytes, failureOutput, cancellationFailure),
+        /Another transaction of this withdrawal may still execute/,
+      );
+
+      // The replacement executed and settles the withdrawal without a refund
+      const successOutput = Buffer.from([1]);
+      await complete(
+        original.requestIdBytes,
+        successOutput,
+        await signWithdrawalResponse(
+          program,
+          replacement.requestIdBytes,
+          successOutput,
+        ),
+      );
+
+      const finalBalance = await program.account.userErc20Balance.fetch(
+        userBalance,
+      );
+      expect(finalBalance.amount.toString()).to.equal(
+        balanceAfterReplace.amount.toString(),
+      );
+      expect(
+        await program.account.pendingErc20Withdrawal.fetchNullable(
+          pendingWithdrawal,
+        ),
+      ).to.equal(null);
+
+      console.log("\n🎉 Replaced withdrawal settled by its replacement!");
+    });
+
+    it("Should cancel a withdrawal signed at the max fee cap", async function () {
+      if (balanceBefore.isZero()) {
+        console.log(
+          "  ⚠️ No balance to test withdrawal cancellation. Skipping test.",
+        );
+        return;
+      }
+
+      const original = transferTransaction(
+        BigInt(MAX_FEE_PER_GAS),
+        ethers.parseUnits("1", "gwei"),
+      );
+      await withdraw(original.requestIdBytes, original.txParams);
+
+      const { gasFee } = await program.account.pendingErc20Withdrawal.fetch(
+        findPendingWithdrawalPda(original.requestIdBytes),
+      );
+
+      // The max fee can't rise past the cap, so only the priority fee does
+      const cancellation = cancellationTransaction(
+        BigInt(MAX_FEE_PER_GAS),
+        ethers.parseUnits("2", "gwei"),
+      );
+      await cancel(original.requestIdBytes, cancellation);
+
+      const successOutput = Buffer.from([1]);
+      await complete(
+        original.requestIdBytes,
+        successOutput,
+        await signWithdrawalResponse(
+          program,
+          cancellation.requestIdBytes,
+          successOutput,
+        ),
+      );
+
+      // Refunded except for the gas fee, which pays for the cancellation
+      const finalBalance = await program.account.userErc20Balance.fetch(
+        userBalance,
+      );
+      expect(finalBalance.amount.toString()).to.equal(
+        balanceBefore.sub(gasFee).toString(),
+      );
+    });
+  });
 });
 
 /**
@@ -1122,6 +1577,7 @@ async function getDepositAccounts(
     [
       Buffer.from("user_erc20_balance"),
       provider.wallet.publicKey.toBuffer(),
+      new BN(CONFIG.SEPOLIA_CHAIN_ID).toArrayLike(Buffer, "le", 8),
       Buffer.from(erc20AddressBytes),
     ],
     program.programId,
@@ -1151,3 +1607,36 @@ async function getInitialBalance(
     return new BN(0);
   }
 }
+
+/**
+ * Signs a withdrawal response the way the MPC network does, with the key
+ * derived for the global vault authority on the respond path.
+ */
+async function signWithdrawalResponse(
+  program: Program<SolanaCoreContracts>,
+  requestIdBytes: number[],
+  serializedOutput: Buffer,
+) {
+  const [globalVaultAuthority] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("global_vault_authority")],
+    program.programId,
+  );
+  const derivedKeyHex = await CryptoUtils.deriveSigningKey(
+    CONFIG.SOLANA_RESPOND_BIDIRECTIONAL_PATH,
+    globalVaultAuthority.toString(),
+    CONFIG.MPC_ROOT_PRIVATE_KEY,
+  );
+
+  const signature = new ethers.SigningKey(derivedKeyHex).sign(
+    computeMessageHash(requestIdBytes, serializedOutput),
+  );
+
+  return {
+    bigR: {
+      x: Array.from(ethers.getBytes(signature.r)),
+      y: Array(32).fill(0),
+    },
+    s: Array.from(ethers.getBytes(signature.s)),
+    recoveryId: signature.yParity,
+  };
+}
@@ -0,0 +1,388 @@
+import * as anchor from "@coral-xyz/anchor";
+import { IdlTypes, Program } from "@coral-xyz/anchor";
+import BN from "bn.js";
+import { createHash } from "crypto";
+import { SolanaCoreContracts } from "../target/types/solana_core_contracts";
+
+type ConfigAction = Parameters<
+  Program<SolanaCoreContracts>["methods"]["proposeConfigChange"]
+>[0];
+
+// A zero limit disables the rate limit
+const NO_RATE_LIMIT = { limit: new BN(0), windowSlots: new BN(0) };
+
+// Network fee bound for BTC deposits on chains registered by the tests
+export const MAX_DEPOSIT_NETWORK_FEE = 10_000;
+
+// Gas bounds for EVM withdrawals on chains registered by the tests
+export const MAX_GAS_LIMIT = 500_000;
+export const MAX_FEE_PER_GAS = 500_000_000_000; // 500 gwei
+export const MAX_PRIORITY_FEE_PER_GAS = 50_000_000_000; // 50 gwei
+
+// Mirrors MAX_UTXO_CHANGES in the program
+export const MAX_UTXO_CHANGES = 8;
+
+type VaultUtxo = IdlTypes<SolanaCoreContracts>["vaultUtxo"];
+type BtcOutpoint = IdlTypes<SolanaCoreContracts>["btcOutpoint"];
+
+export const findVaultConfigPda = (programId: anchor.web3.PublicKey) =>
+  anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("vault_config")],
+    programId,
+  )[0];
+
+export const findConfigProposalPda = (
+  programId: anchor.web3.PublicKey,
+  proposalId: BN,
+) =>
+  anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("config_proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+    programId,
+  )[0];
+
+// One nonce account per chain and MPC key version, since each key version has
+// its own vault address
+export const findEvmVaultNoncePda = (
+  programId: anchor.web3.PublicKey,
+  chainId: number,
+  keyVersion: number,
+) =>
+  anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("evm_vault_nonce"),
+      new BN(chainId).toArrayLike(Buffer, "le", 8),
+      new BN(keyVersion).toArrayLike(Buffer, "le", 4),
+    ],
+    programId,
+  )[0];
+
+/**
+ * Opens a config proposal as the provider wallet and returns its id.
+ * In the test setup the wallet is the 1-of-1 admin with no timelock, so the
+ * proposal is executable right away.
+ */
+export async function proposeConfigChange(
+  program: Program<SolanaCoreContracts>,
+  provider: anchor.AnchorProvider,
+  action: ConfigAction,
+): Promise<BN> {
+  const config = await program.account.vaultConfig.fetch(
+    findVaultConfigPda(program.programId),
+  );
+  const proposalId = config.proposalCount;
+
+  await program.methods
+    .proposeConfigChange(action)
+    .accountsPartial({
+      proposer: provider.wallet.publicKey,
+      proposal: findConfigProposalPda(program.programId, proposalId),
+    })
+    .rpc();
+
+  return proposalId;
+}
+
+/**
+ * Proposes and immediately executes a config-only action with `execute_config_change`.
+ */
+export async function applyConfigChange(
+  program: Program<SolanaCoreContracts>,
+  provider: anchor.AnchorProvider,
+  action: ConfigAction,
+): Promise<void> {
+  const proposalId = await proposeConfigChange(program, provider, action);
+
+  await program.methods
+    .executeConfigChange(proposalId)
+    .accountsPartial({
+      executor: provider.wallet.publicKey,
+      proposal: findConfigProposalPda(program.programId, proposalId),
+      proposer: provider.wallet.publicKey,
+    })
+    .rpc();
+}
+
+/**
+ * Points the program's EVM vault nonce for `chainId` at `nextNonce`, the next
+ * nonce the vault address of the active key version will use on that chain.
+ */
+export async function syncEvmVaultNonce(
+  program: Program<SolanaCoreContracts>,
+  provider: anchor.AnchorProvider,
+  chainId: number,
+  nextNonce: number,
+) {
+  const chainIdBN = new BN(chainId);
+  const { activeKeyVersion } = await program.account.vaultConfig.fetch(
+    findVaultConfigPda(program.programId),
+  );
+  const vaultNoncePda = findEvmVaultNoncePda(
+    program.programId,
+    chainId,
+    activeKeyVersion,
+  );
+
+  const vaultNonce =
+    await program.account.evmVaultNonce.fetchNullable(vaultNoncePda);
+  if (vaultNonce?.nextNonce.eqn(nextNonce)) {
+    return;
+  }
+
+  const proposalId = await proposeConfigChange(program, provider, {
+    setEvmVaultNonce: {
+      chainId: chainIdBN,
+      keyVersion: activeKeyVersion,
+      nextNonce: new BN(nextNonce),
+    },
+  });
+
+  await program.methods
+    .executeEvmNonceChange(proposalId, chainIdBN, activeKeyVersion)
+    .accountsPartial({
+      executor: provider.wallet.publicKey,
+      proposal: findConfigProposalPda(program.programId, proposalId),
+      proposer: provider.wallet.publicKey,
+      vaultNonce: vaultNoncePda,
+    })
+    .rpc();
+}
+
+// Mirrors caip2_id_seed in the program: the SHA-256 of the CAIP-2 ID
+const caip2IdSeed = (caip2Id: string) =>
+  createHash("sha256").update(caip2Id).digest();
+
+export const findVaultUtxoSetPda = (
+  programId: anchor.web3.PublicKey,
+  caip2Id: string,
+) =>
+  anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("vault_utxo_set"), caip2IdSeed(caip2Id)],
+    programId,
+  )[0];
+
+// BTC balances are kept per chain, like ERC20 balances
+export const findUserBtcBalancePda = (
+  programId: anchor.web3.PublicKey,
+  owner: anchor.web3.PublicKey,
+  caip2Id: string,
+) =>
+  anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("user_btc_balance"), owner.toBuffer(), caip2IdSeed(caip2Id)],
+    programId,
+  )[0];
+
+// Per-chain like the ERC20 user window
+export const findUserBtcRateLimitPda = (
+  programId: anchor.web3.PublicKey,
+  owner: anchor.web3.PublicKey,
+  caip2Id: string,
+) =>
+  anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("user_btc_rate_limit"),
+      owner.toBuffer(),
+      caip2IdSeed(caip2Id),
+    ],
+    programId,
+  )[0];
+
+/**
+ * Registers vault outputs in the chain's on-chain UTXO set, one proposal per
+ * MAX_UTXO_CHANGES outputs.
+ */
+export async function addVaultUtxos(
+  program: Program<SolanaCoreContracts>,
+  provider: anchor.AnchorProvider,
+  caip2Id: string,
+  utxos: VaultUtxo[],
+) {
+  for (let i = 0; i < utxos.length; i += MAX_UTXO_CHANGES) {
+    const proposalId = await proposeConfigChange(program, provider, {
+      addVaultUtxos: { caip2Id, utxos: utxos.slice(i, i + MAX_UTXO_CHANGES) },
+    });
+
+    await program.methods
+      .executeUtxoChange(proposalId, caip2Id)
+      .accountsPartial({
+        executor: provider.wallet.publicKey,
+        proposal: findConfigProposalPda(program.programId, proposalId),
+        proposer: provider.wallet.publicKey,
+        vaultUtxoSet: findVaultUtxoSetPda(program.programId, caip2Id),
+      })
+      .rpc();
+  }
+}
+
+/**
+ * Drops unreserved outputs from the chain's on-chain UTXO set, one proposal per
+ * MAX_UTXO_CHANGES outpoints.
+ */
+export async function removeVaultUtxos(
+  program: Program<SolanaCoreContracts>,
+  provider: anchor.AnchorProvider,
+  caip2Id: string,
+  outpoints: BtcOutpoint[],
+) {
+  for (let i = 0; i < outpoints.length; i += MAX_UTXO_CHANGES) {
+    const proposalId = await proposeConfigChange(program, provider, {
+      removeVaultUtxos: {
+        caip2Id,
+        outpoints: outpoints.slice(i, i + MAX_UTXO_CHANGES),
+      },
+    });
+
+    await program.methods
+      .executeUtxoChange(proposalId, caip2Id)
+      .accountsPartial({
+        executor: provider.wallet.publicKey,
+        proposal: findConfigProposalPda(program.programId, proposalId),
+        proposer: provider.wallet.publicKey,
+        vaultUtxoSet: findVaultUtxoSetPda(program.programId, caip2Id),
+      })
+      .rpc();
+  }
+}
+
+/**
+ * Idempotently registers an ERC20 token in the on-chain token registry.
+ */
+export async function ensureTokenRegistered(
+  program: Program<SolanaCoreContracts>,
+  provider: anchor.AnchorProvider,
+  chainId: number,
+  erc20Address: string,
+  symbol: string,
+  decimals: number,
+) {
+  const chainIdBN = new BN(chainId);
+  const erc20AddressBytes = Array.from(
+    Buffer.from(erc20Address.slice(2), "hex"),
+  );
+  const [tokenRegistryPda] = anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("token_registry"),
+      chainIdBN.toArrayLike(Buffer, "le", 8),
+      Buffer.from(erc20AddressBytes),
+    ],
+    program.programId,
+  );
+
+  if (await provider.connection.getAccountInfo(tokenRegistryPda)) {
+    return;
+  }
+
+  const proposalId = await proposeConfigChange(program, provider, {
+    setToken: {
+      chainId: chainIdBN,
+      erc20Address: erc20AddressBytes,
+      settings: {
+        enabled: true,
+        decimals,
+        symbol,
+        minAmount: new BN(1),
+        maxAmount: new BN("1000000000000000"),
+        defaultGasLimit: new BN(100_000),
+        withdrawalGasFee: new BN(0),
+        withdrawalRateLimit: NO_RATE_LIMIT,
+        userWithdrawalRateLimit: NO_RATE_LIMIT,
+        depositCap: new BN(0),
+        fees: { depositFeeBps: 0, withdrawalFeeBps: 0 },
+      },
+    },
+  });
+
+  await program.methods
+    .executeTokenChange(proposalId, chainIdBN, erc20AddressBytes)
+    .accountsPartial({
+      executor: provider.wallet.publicKey,
+      proposal: findConfigProposalPda(program.programId, proposalId),
+      proposer: provider.wallet.publicKey,
+      tokenRegistry: tokenRegistryPda,
+    })
+    .rpc();
+}
+
+/**
+ * Idempotently adds a CAIP-2 chain to the on-chain chain registry.
+ */
+export async function ensureChainRegistered(
+  program: Program<SolanaCoreContracts>,
+  provider: anchor.AnchorProvider,
+  caip2Id: string,
+) {
+  const [chainRegistryPda] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("chain_registry")],
+    program.programId,
+  );
+
+  const registry =
+    await program.account.chainRegistry.fetchNullable(chainRegistryPda);
+  if (
+    registry?.chains.some(
+      (entry) => entry.caip2Id === caip2Id && entry.settings.enabled,
+    )
+  ) {
+    return;
+  }
+
+  const proposalId = await proposeConfigChange(program, provider, {
+    setChain: {
+      caip2Id,
+      settings: {
+        enabled: true,
+        withdrawalRateLimit: NO_RATE_LIMIT,
+        userWithdrawalRateLimit: NO_RATE_LIMIT,
+        depositCap: new BN(0),
+        fees: { depositFeeBps: 0, withdrawalFeeBps: 0 },
+        maxDepositNetworkFee: new BN(MAX_DEPOSIT_NETWORK_FEE),
+        maxGasLimit: new BN(MAX_GAS_LIMIT),
+        maxFeePerGas: new BN(MAX_FEE_PER_GAS),
+        maxPriorityFeePerGas: new BN(MAX_PRIORITY_FEE_PER_GAS),
+      },
+    },
+  });
+
+  await program.methods
+    .executeChainChange(proposalId)
+    .accountsPartial({
+      executor: provider.wallet.publicKey,
+      proposal: findConfigProposalPda(program.programId, proposalId),
+      proposer: provider.wallet.publicKey,
+      chainRegistry: chainRegistryPda,
+    })
+    .rpc();
+}
+
+/**
+ * Replaces the protocol fees of a registered CAIP-2 chain, keeping its other settings.
+ */
+export async function setChainFees(
+  program: Program<SolanaCoreContracts>,
+  provider: anchor.AnchorProvider,
+  caip2Id: string,
+  fees: { depositFeeBps: number; withdrawalFeeBps: number },
+) {
+  const [chainRegistryPda] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("chain_registry")],
+    program.programId,
+  );
+  const registry = await program.account.chainRegistry.fetch(chainRegistryPda);
+  const entry = registry.chains.find((chain) => chain.caip2Id === caip2Id);
+  if (!entry) {
+    throw new Error(`Chain ${caip2Id} is not registered`);
+  }
+
+  const proposalId = await proposeConfigChange(program, provider, {
+    setChain: { caip2Id, settings: { ...entry.settings, fees } },
+  });
+
+  await program.methods
+    .executeChainChange(proposalId)
+    .accountsPartial({
+      executor: provider.wallet.publicKey,
+      proposal: findConfigProposalPda(program.programId, proposalId),
+      proposer: provider.wallet.publicKey,
+      chainRegistry: chainRegistryPda,
+    })
+    .rpc();
+}
@@ -6,7 +6,7 @@ import type {
   NetworkData,
   TokenConfig,
 } from '@/lib/constants/token-metadata';
-import { NETWORKS_WITH_TOKENS } from '@/lib/constants/token-metadata';
+import { useNetworksWithTokens } from '@/hooks/use-erc20-tokens';
 
 import { NetworkAccordionItem } from './network-accordion-item';
 
@@ -19,7 +19,7 @@ export function TokenSelection({ onTokenSelect }: TokenSelectionProps) {
     null,
   );
 
-  const networks = NETWORKS_WITH_TOKENS;
+  const { data: networks = [] } = useNetworksWithTokens();
 
   const handleNetworkClick = (networkId: string) => {
     setExpandedNetworkId(expandedNetworkId === networkId ? null : networkId);
@@ -1,5 +1,6 @@
 export { useDepositAddress } from './use-deposit-address';
 export { useUserBalances } from './use-user-balances';
+export { useErc20Tokens, useNetworksWithTokens } from './use-erc20-tokens';
 export { useDepositEvmMutation } from './use-deposit-evm-mutation';
 export { useWithdrawEvmMutation } from './use-withdraw-evm-mutation';
 export { useWithdrawSolMutation } from './use-withdraw-sol-mutation';
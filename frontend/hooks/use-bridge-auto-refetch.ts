@@ -9,19 +9,28 @@ import {
   deriveUserBalancePda,
   deriveVaultAuthorityPda,
 } from '@/lib/constants/addresses';
-import { ERC20_TOKENS } from '@/lib/constants/token-metadata';
+import { SERVICE_CONFIG } from '@/lib/constants/service.config';
+import type { TokenConfig } from '@/lib/constants/token-metadata';
 import { queryKeys } from '@/lib/query-client';
 import { PublicKey } from '@solana/web3.js';
 
+import { useErc20Tokens } from './use-erc20-tokens';
 import { useSolanaPublicKey } from './use-solana-public-key';
 
-function buildUserBalancePdaSet(publicKey: PublicKey | null) {
+function buildUserBalancePdaSet(
+  publicKey: PublicKey | null,
+  erc20Tokens: TokenConfig[],
+) {
   if (!publicKey) return new Set<string>();
   const set = new Set<string>();
-  for (const token of ERC20_TOKENS) {
+  for (const token of erc20Tokens) {
     try {
       const erc20Bytes = Buffer.from(token.erc20Address.replace('0x', ''), 'hex');
-      const [pda] = deriveUserBalancePda(publicKey, erc20Bytes);
+      const [pda] = deriveUserBalancePda(
+        publicKey,
+        SERVICE_CONFIG.ETHEREUM.CHAIN_ID,
+        erc20Bytes,
+      );
       set.add(pda.toBase58());
     } catch {}
   }
@@ -50,8 +59,9 @@ export function useBridgeAutoRefetch() {
   const { connection } = useConnection();
   const queryClient = useQueryClient();
   const publicKey = useSolanaPublicKey();
+  const { data: erc20Tokens = [] } = useErc20Tokens();
 
-  const userBalancePdaSet = buildUserBalancePdaSet(publicKey);
+  const userBalancePdaSet = buildUserBalancePdaSet(publicKey, erc20Tokens);
   const requesterPdaBase58 = getRequesterPdaBase58(publicKey);
 
   useEffect(() => {
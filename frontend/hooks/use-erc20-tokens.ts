@@ -0,0 +1,30 @@
+'use client';
+
+import { useQuery } from '@tanstack/react-query';
+
+import {
+  fetchErc20Tokens,
+  fetchNetworksWithTokens,
+} from '@/lib/constants/token-metadata';
+import { queryKeys } from '@/lib/query-client';
+
+const TOKEN_REGISTRY_STALE_TIME_MS = 60 * 1000;
+
+/**
+ * ERC20 tokens enabled in the program's token registry
+ */
+export function useErc20Tokens() {
+  return useQuery({
+    queryKey: queryKeys.solana.erc20Tokens(),
+    queryFn: fetchErc20Tokens,
+    staleTime: TOKEN_REGISTRY_STALE_TIME_MS,
+  });
+}
+
+export function useNetworksWithTokens() {
+  return useQuery({
+    queryKey: queryKeys.solana.networksWithTokens(),
+    queryFn: fetchNetworksWithTokens,
+    staleTime: TOKEN_REGISTRY_STALE_TIME_MS,
+  });
+}
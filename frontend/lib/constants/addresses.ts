@@ -37,9 +37,32 @@ const BRIDGE_PDA_SEEDS = {
   PENDING_ERC20_DEPOSIT: 'pending_erc20_deposit',
   PENDING_ERC20_WITHDRAWAL: 'pending_erc20_withdrawal',
   USER_ERC20_BALANCE: 'user_erc20_balance',
+  USER_ERC20_RATE_LIMIT: 'user_erc20_rate_limit',
   VAULT_CONFIG: 'vault_config',
+  TOKEN_REGISTRY: 'token_registry',
+  CHAIN_REGISTRY: 'chain_registry',
+  PROCESSED_REQUEST: 'processed_request',
+  EVM_VAULT_NONCE: 'evm_vault_nonce',
 } as const;
 
+/**
+ * Encodes a u64 seed (EVM chain IDs) as the program does: 8 bytes little-endian
+ */
+function u64Seed(value: number | bigint): Buffer {
+  const buffer = Buffer.alloc(8);
+  buffer.writeBigUInt64LE(BigInt(value));
+  return buffer;
+}
+
+/**
+ * Encodes a u32 seed (key versions) as 4 bytes little-endian
+ */
+function u32Seed(value: number): Buffer {
+  const buffer = Buffer.alloc(4);
+  buffer.writeUInt32LE(value);
+  return buffer;
+}
+
 /**
  * Centralized PDA derivation helpers
  */
@@ -76,20 +99,95 @@ export function derivePendingWithdrawalPda(
   );
 }
 
+/**
+ * Balances are kept per EVM chain, so the same token address on two chains
+ * maps to two accounts
+ */
 export function deriveUserBalancePda(
   userPublicKey: PublicKey,
+  chainId: number | bigint,
   erc20AddressBytes: Buffer,
 ): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
     [
       Buffer.from(BRIDGE_PDA_SEEDS.USER_ERC20_BALANCE),
       userPublicKey.toBuffer(),
+      u64Seed(chainId),
+      erc20AddressBytes,
+    ],
+    BRIDGE_PROGRAM_ID,
+  );
+}
+
+export function deriveUserRateLimitPda(
+  userPublicKey: PublicKey,
+  chainId: number | bigint,
+  erc20AddressBytes: Buffer,
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(BRIDGE_PDA_SEEDS.USER_ERC20_RATE_LIMIT),
+      userPublicKey.toBuffer(),
+      u64Seed(chainId),
       erc20AddressBytes,
     ],
     BRIDGE_PROGRAM_ID,
   );
 }
 
+export function deriveTokenRegistryPda(
+  chainId: number | bigint,
+  erc20AddressBytes: Buffer,
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(BRIDGE_PDA_SEEDS.TOKEN_REGISTRY),
+      u64Seed(chainId),
+      erc20AddressBytes,
+    ],
+    BRIDGE_PROGRAM_ID,
+  );
+}
+
+export function deriveChainRegistryPda(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(BRIDGE_PDA_SEEDS.CHAIN_REGISTRY)],
+    BRIDGE_PROGRAM_ID,
+  );
+}
+
+/**
+ * Marks a request ID as used so a closed pending account can't be recreated
+ */
+export function deriveProcessedRequestPda(
+  requestIdBytes: number[],
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(BRIDGE_PDA_SEEDS.PROCESSED_REQUEST),
+      Buffer.from(requestIdBytes),
+    ],
+    BRIDGE_PROGRAM_ID,
+  );
+}
+
+/**
+ * Next nonce of the vault address of a chain under one MPC key version
+ */
+export function deriveEvmVaultNoncePda(
+  chainId: number | bigint,
+  keyVersion: number,
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(BRIDGE_PDA_SEEDS.EVM_VAULT_NONCE),
+      u64Seed(chainId),
+      u32Seed(keyVersion),
+    ],
+    BRIDGE_PROGRAM_ID,
+  );
+}
+
 export function deriveConfigPda(): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
     [Buffer.from(BRIDGE_PDA_SEEDS.VAULT_CONFIG)],
@@ -1,6 +1,10 @@
-import { erc20Abi, type Hex } from 'viem';
+import { Program } from '@coral-xyz/anchor';
+import { Connection } from '@solana/web3.js';
+import { bytesToHex, getAddress } from 'viem';
 
-import { getEthereumProvider } from '@/lib/rpc';
+import { IDL, type SolanaDexContract } from '@/lib/program/idl-sol-dex';
+import { SERVICE_CONFIG } from '@/lib/constants/service.config';
+import { CONNECTION_CONFIG, getRpcEndpoint } from '@/lib/rpc';
 
 // Token display info - decimals come from on-chain fetching
 export interface TokenConfig {
@@ -14,45 +18,42 @@ export interface TokenConfig {
   faucetUrl?: string;
 }
 
-// ERC20 tokens on Sepolia
-export const ERC20_TOKENS: TokenConfig[] = [
-  {
-    erc20Address: '0x1c7D4B196Cb0C7B01d743Fbc6116a902379C7238',
-    symbol: 'USDC',
+export interface Erc20TokenConfig extends TokenConfig {
+  decimals: number;
+}
+
+// Display details for ERC20 tokens on Sepolia, keyed by lowercase address.
+// Which tokens are supported comes from the program's token registry; tokens
+// registered without an entry here are shown with their registry symbol only.
+const ERC20_TOKEN_DISPLAY: Record<
+  string,
+  Pick<TokenConfig, 'name' | 'acquireHint' | 'faucetUrl'>
+> = {
+  '0x1c7d4b196cb0c7b01d743fbc6116a902379c7238': {
     name: 'USD Coin',
-    chain: 'ethereum',
     acquireHint: 'Get testnet USDC from the Circle faucet.',
     faucetUrl: 'https://faucet.circle.com/',
   },
-  {
-    erc20Address: '0x08210F9170F89Ab7658F0B5E3fF39b0E03C594D4',
-    symbol: 'EURC',
+  '0x08210f9170f89ab7658f0b5e3ff39b0e03c594d4': {
     name: 'Euro Coin',
-    chain: 'ethereum',
     acquireHint: 'Get testnet EURC from the Circle faucet.',
     faucetUrl: 'https://faucet.circle.com/',
   },
-  {
-    erc20Address: '0xB4F1737Af37711e9A5890D9510c9bB60e170CB0D',
-    symbol: 'DAI',
+  '0xb4f1737af37711e9a5890d9510c9bb60e170cb0d': {
     name: 'Dai',
-    chain: 'ethereum',
     acquireHint:
       'Swap Sepolia ETH for DAI on CoW Swap. First get Sepolia ETH from a faucet, then swap.',
     faucetUrl:
       'https://swap.cow.fi/#/11155111/swap/ETH/0xB4F1737Af37711e9A5890D9510c9bB60e170CB0D',
   },
-  {
-    erc20Address: '0x0625aFB445C3B6B7B929342a04A22599fd5dBB59',
-    symbol: 'COW',
+  '0x0625afb445c3b6b7b929342a04a22599fd5dbb59': {
     name: 'CoW Protocol',
-    chain: 'ethereum',
     acquireHint:
       'Swap Sepolia ETH for COW on CoW Swap. First get Sepolia ETH from a faucet, then swap.',
     faucetUrl:
       'https://swap.cow.fi/#/11155111/swap/ETH/0x0625aFB445C3B6B7B929342a04A22599fd5dBB59',
   },
-];
+};
 
 // Solana tokens
 export const SOLANA_TOKENS: TokenConfig[] = [
@@ -81,62 +82,105 @@ export interface NetworkData {
   tokens: TokenConfig[];
 }
 
-export const NETWORKS_WITH_TOKENS: NetworkData[] = [
-  {
-    chain: 'ethereum',
-    chainName: 'Ethereum',
-    symbol: 'ethereum',
-    tokens: ERC20_TOKENS,
-  },
-  {
-    chain: 'solana',
-    chainName: 'Solana',
-    symbol: 'solana',
-    tokens: SOLANA_TOKENS,
-  },
-];
+// How long the token list read from the registry is reused before refetching
+const ERC20_TOKENS_TTL_MS = 60 * 1000;
 
-// ERC20 functions
-const ERC20_ALLOWLIST_SET = new Set(
-  ERC20_TOKENS.map(t => t.erc20Address.toLowerCase()),
-);
+let erc20TokensCache: {
+  fetchedAt: number;
+  tokens: Promise<Erc20TokenConfig[]>;
+} | null = null;
 
-export function isErc20Allowed(address: string): boolean {
-  return ERC20_ALLOWLIST_SET.has(address.toLowerCase());
-}
+let registryProgram: Program<SolanaDexContract> | null = null;
 
-const ERC20_TOKEN_MAP = new Map<string, TokenConfig>(
-  ERC20_TOKENS.map(token => [token.erc20Address.toLowerCase(), token]),
-);
-
-export function getErc20Token(address: string): TokenConfig | undefined {
-  return ERC20_TOKEN_MAP.get(address.toLowerCase());
-}
-
-// In-memory cache for token decimals (immutable, never expires)
-const decimalsCache = new Map<string, number>();
-
-// Fetch ERC20 decimals from chain
-export async function fetchErc20Decimals(address: string): Promise<number> {
-  if (!isErc20Allowed(address)) {
-    throw new Error(`Token not supported: ${address}`);
+function getRegistryProgram(): Program<SolanaDexContract> {
+  if (!registryProgram) {
+    const connection = new Connection(
+      getRpcEndpoint('client'),
+      CONNECTION_CONFIG.commitment,
+    );
+    registryProgram = new Program(IDL, { connection });
   }
+  return registryProgram;
+}
 
-  const normalizedAddress = address.toLowerCase();
+async function fetchRegisteredErc20Tokens(): Promise<Erc20TokenConfig[]> {
+  const registries = await getRegistryProgram().account.tokenRegistry.all();
+
+  return registries
+    .filter(
+      ({ account }) =>
+        account.chainId.toNumber() === SERVICE_CONFIG.ETHEREUM.CHAIN_ID &&
+        account.settings.enabled,
+    )
+    .map(({ account }) => {
+      const erc20Address = getAddress(
+        bytesToHex(Uint8Array.from(account.erc20Address)),
+      );
+      const display = ERC20_TOKEN_DISPLAY[erc20Address.toLowerCase()];
+      return {
+        erc20Address,
+        symbol: account.settings.symbol,
+        name: display?.name ?? account.settings.symbol,
+        chain: 'ethereum' as const,
+        acquireHint: display?.acquireHint,
+        faucetUrl: display?.faucetUrl,
+        decimals: account.settings.decimals,
+      };
+    });
+}
 
-  const cached = decimalsCache.get(normalizedAddress);
-  if (cached !== undefined) {
-    return cached;
+// ERC20 tokens enabled in the program's token registry for Sepolia
+export async function fetchErc20Tokens(): Promise<Erc20TokenConfig[]> {
+  if (
+    erc20TokensCache &&
+    Date.now() - erc20TokensCache.fetchedAt <= ERC20_TOKENS_TTL_MS
+  ) {
+    return erc20TokensCache.tokens;
   }
 
-  const client = getEthereumProvider();
-  const decimals = await client.readContract({
-    address: address as Hex,
-    abi: erc20Abi,
-    functionName: 'decimals',
+  const tokens = fetchRegisteredErc20Tokens();
+  erc20TokensCache = { fetchedAt: Date.now(), tokens };
+  // Don't keep a failed fetch around until the TTL expires
+  tokens.catch(() => {
+    if (erc20TokensCache?.tokens === tokens) {
+      erc20TokensCache = null;
+    }
   });
+  return tokens;
+}
+
+export async function fetchNetworksWithTokens(): Promise<NetworkData[]> {
+  return [
+    {
+      chain: 'ethereum',
+      chainName: 'Ethereum',
+      symbol: 'ethereum',
+      tokens: await fetchErc20Tokens(),
+    },
+    {
+      chain: 'solana',
+      chainName: 'Solana',
+      symbol: 'solana',
+      tokens: SOLANA_TOKENS,
+    },
+  ];
+}
 
-  decimalsCache.set(normalizedAddress, decimals);
+// ERC20 functions
+export async function getErc20Token(
+  address: string,
+): Promise<Erc20TokenConfig | undefined> {
+  const tokens = await fetchErc20Tokens();
+  return tokens.find(
+    token => token.erc20Address.toLowerCase() === address.toLowerCase(),
+  );
+}
 
-  return decimals;
+// Decimals are part of the token's registry entry
+export async function fetchErc20Decimals(address: string): Promise<number> {
+  const token = await getErc20Token(address);
+  if (!token) {
+    throw new Error(`Token not supported: ${address}`);
+  }
+  return token.decimals;
 }
@@ -17,8 +17,14 @@ import {
   CHAIN_SIGNATURES_CONFIG,
   deriveVaultAuthorityPda,
   deriveUserBalancePda,
+  deriveUserRateLimitPda,
   derivePendingDepositPda,
   derivePendingWithdrawalPda,
+  deriveTokenRegistryPda,
+  deriveChainRegistryPda,
+  deriveProcessedRequestPda,
+  deriveEvmVaultNoncePda,
+  deriveConfigPda,
 } from '@/lib/constants/addresses';
 import { isRateLimitError, wrapRateLimitError } from '@/lib/utils/rate-limit';
 
@@ -56,13 +62,39 @@ export class DexContract {
     return this.program;
   }
 
+  /**
+   * Next nonce the program will accept for a withdrawal from the vault address
+   * of `chainId` under the active key version
+   */
+  async fetchEvmVaultNonce(chainId: number): Promise<number> {
+    const program = this.getDexProgram();
+    const keyVersion = await this.fetchActiveKeyVersion();
+    const [vaultNoncePda] = deriveEvmVaultNoncePda(chainId, keyVersion);
+
+    const vaultNonce =
+      await program.account.evmVaultNonce.fetchNullable(vaultNoncePda);
+    return vaultNonce ? vaultNonce.nextNonce.toNumber() : 0;
+  }
+
+  private async fetchActiveKeyVersion(): Promise<number> {
+    const program = this.getDexProgram();
+    const [configPda] = deriveConfigPda();
+    const config = await program.account.vaultConfig.fetch(configPda);
+    return config.activeKeyVersion;
+  }
+
   async fetchUserBalance(
     userPublicKey: PublicKey,
+    chainId: number,
     erc20Address: string,
   ): Promise<string> {
     try {
       const erc20Bytes = Buffer.from(toBytes(erc20Address as `0x${string}`));
-      const [userBalancePda] = deriveUserBalancePda(userPublicKey, erc20Bytes);
+      const [userBalancePda] = deriveUserBalancePda(
+        userPublicKey,
+        chainId,
+        erc20Bytes,
+      );
       const program = this.getDexProgram();
 
       const userBalanceAccount =
@@ -92,7 +124,6 @@ export class DexContract {
     payer,
     requestIdBytes,
     erc20AddressBytes,
-    recipientAddressBytes,
     amount,
     evmParams,
   }: {
@@ -100,7 +131,6 @@ export class DexContract {
     payer?: PublicKey;
     requestIdBytes: number[];
     erc20AddressBytes: number[];
-    recipientAddressBytes: number[];
     amount: BN;
     evmParams: EvmTransactionProgramParams;
   }): Promise<string> {
@@ -118,6 +148,11 @@ export class DexContract {
     }
 
     const [pendingDepositPda] = derivePendingDepositPda(requestIdBytes);
+    const [processedRequestPda] = deriveProcessedRequestPda(requestIdBytes);
+    const [tokenRegistryPda] = deriveTokenRegistryPda(
+      BigInt(evmParams.chainId.toString()),
+      Buffer.from(erc20AddressBytes),
+    );
 
     let signature: string;
     try {
@@ -126,15 +161,17 @@ export class DexContract {
           requestIdBytes as unknown as number[],
           requester,
           erc20AddressBytes as unknown as number[],
-          recipientAddressBytes as unknown as number[],
           amount,
           evmParams,
         )
         .accountsPartial({
           payer: payerKey,
           pendingDeposit: pendingDepositPda,
+          processedRequest: processedRequestPda,
           feePayer: payerKey,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          chainRegistry: deriveChainRegistryPda()[0],
+          tokenRegistry: tokenRegistryPda,
         })
         .preInstructions([
           ComputeBudgetProgram.setComputeUnitLimit({
@@ -158,17 +195,25 @@ export class DexContract {
     requestIdBytes,
     serializedOutput,
     signature,
+    chainId,
     erc20AddressBytes,
     requester,
   }: {
     requestIdBytes: number[];
     serializedOutput: Buffer | number[];
     signature: RSVSignature;
+    chainId: number;
     erc20AddressBytes: number[];
     requester: PublicKey;
   }): Promise<string> {
     const erc20Bytes = Buffer.from(erc20AddressBytes);
-    const [userBalancePda] = deriveUserBalancePda(requester, erc20Bytes);
+    const [userBalancePda] = deriveUserBalancePda(
+      requester,
+      chainId,
+      erc20Bytes,
+    );
+    const [tokenRegistryPda] = deriveTokenRegistryPda(chainId, erc20Bytes);
+    const [processedRequestPda] = deriveProcessedRequestPda(requestIdBytes);
     const program = this.getDexProgram();
 
     try {
@@ -178,8 +223,10 @@ export class DexContract {
           Buffer.from(serializedOutput),
           signature,
         )
-        .accounts({
+        .accountsPartial({
+          processedRequest: processedRequestPda,
           userBalance: userBalancePda,
+          tokenRegistry: tokenRegistryPda,
         })
         .preInstructions([
           ComputeBudgetProgram.setComputeUnitLimit({
@@ -212,7 +259,19 @@ export class DexContract {
     lastValidBlockHeight: number;
   }> {
     const program = this.getDexProgram();
+    const chainId = BigInt(evmParams.chainId.toString());
+    const erc20Bytes = Buffer.from(erc20AddressBytes);
+    const keyVersion = await this.fetchActiveKeyVersion();
     const [pendingWithdrawalPda] = derivePendingWithdrawalPda(requestIdBytes);
+    const [processedRequestPda] = deriveProcessedRequestPda(requestIdBytes);
+    const [userBalancePda] = deriveUserBalancePda(authority, chainId, erc20Bytes);
+    const [userRateLimitPda] = deriveUserRateLimitPda(
+      authority,
+      chainId,
+      erc20Bytes,
+    );
+    const [tokenRegistryPda] = deriveTokenRegistryPda(chainId, erc20Bytes);
+    const [vaultNoncePda] = deriveEvmVaultNoncePda(chainId, keyVersion);
 
     const tx = await program.methods
       .withdrawErc20(
@@ -225,8 +284,14 @@ export class DexContract {
       .accountsPartial({
         authority,
         pendingWithdrawal: pendingWithdrawalPda,
+        processedRequest: processedRequestPda,
+        userBalance: userBalancePda,
         feePayer: authority,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        chainRegistry: deriveChainRegistryPda()[0],
+        tokenRegistry: tokenRegistryPda,
+        userRateLimit: userRateLimitPda,
+        vaultNonce: vaultNoncePda,
       })
       .preInstructions([
         ComputeBudgetProgram.setComputeUnitLimit({
@@ -270,17 +335,25 @@ export class DexContract {
     requestIdBytes,
     serializedOutput,
     signature,
+    chainId,
     erc20AddressBytes,
     requester,
   }: {
     requestIdBytes: number[];
     serializedOutput: Buffer | number[];
     signature: RSVSignature;
+    chainId: number;
     erc20AddressBytes: number[];
     requester: PublicKey;
   }): Promise<string> {
     const erc20Bytes = Buffer.from(erc20AddressBytes);
-    const [userBalancePda] = deriveUserBalancePda(requester, erc20Bytes);
+    const [userBalancePda] = deriveUserBalancePda(
+      requester,
+      chainId,
+      erc20Bytes,
+    );
+    const [tokenRegistryPda] = deriveTokenRegistryPda(chainId, erc20Bytes);
+    const [processedRequestPda] = deriveProcessedRequestPda(requestIdBytes);
     const program = this.getDexProgram();
 
     try {
@@ -290,8 +363,10 @@ export class DexContract {
           Buffer.from(serializedOutput),
           signature,
         )
-        .accounts({
+        .accountsPartial({
+          processedRequest: processedRequestPda,
           userBalance: userBalancePda,
+          tokenRegistry: tokenRegistryPda,
         })
         .preInstructions([
           ComputeBudgetProgram.setComputeUnitLimit({
@@ -78,13 +78,23 @@ export async function buildErc20TransferTx(params: {
   recipient: string;
   amount: bigint;
   fees?: { maxFeePerGas: bigint; maxPriorityFeePerGas: bigint };
+  /** Overrides the sender's transaction count, e.g. with a program-assigned vault nonce */
+  nonce?: number;
 }): Promise<EvmTransactionRequest> {
-  const { provider, from, erc20Address, recipient, amount, fees: precomputedFees } = params;
+  const {
+    provider,
+    from,
+    erc20Address,
+    recipient,
+    amount,
+    fees: precomputedFees,
+    nonce: nonceOverride,
+  } = params;
 
   const data = encodeErc20Transfer(recipient, amount);
 
   const [nonce, estimatedGas, fees] = await Promise.all([
-    provider.getTransactionCount({ address: from as Hex }),
+    nonceOverride ?? provider.getTransactionCount({ address: from as Hex }),
     provider.estimateGas({
       account: from as Hex,
       to: erc20Address as Hex,
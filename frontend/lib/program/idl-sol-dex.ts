@@ -14,96 +14,109 @@ export type SolanaDexContract = {
   },
   "instructions": [
     {
-      "name": "claimBtc",
+      "name": "approveConfigChange",
       "discriminator": [
-        171,
-        129,
-        74,
-        3,
-        215,
-        249,
-        44,
-        164
+        139,
+        238,
+        197,
+        168,
+        142,
+        167,
+        250,
+        213
       ],
       "accounts": [
         {
-          "name": "payer",
-          "writable": true,
+          "name": "signer",
           "signer": true
         },
         {
-          "name": "pendingDeposit",
-          "writable": true,
+          "name": "config",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  101,
-                  110,
-                  100,
-                  105,
-                  110,
-                  103,
-                  95,
-                  98,
+                  118,
+                  97,
+                  117,
+                  108,
                   116,
-                  99,
                   95,
-                  100,
-                  101,
-                  112,
+                  99,
                   111,
-                  115,
+                  110,
+                  102,
                   105,
-                  116
+                  103
                 ]
-              },
-              {
-                "kind": "arg",
-                "path": "requestId"
               }
             ]
           }
         },
         {
-          "name": "userBalance",
+          "name": "proposal",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  117,
-                  115,
-                  101,
-                  114,
-                  95,
-                  98,
-                  116,
                   99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
                   95,
-                  98,
-                  97,
-                  108,
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
                   97,
-                  110,
-                  99,
-                  101
+                  108
                 ]
               },
               {
-                "kind": "account",
-                "path": "pending_deposit.requester",
-                "account": "pendingBtcDeposit"
+                "kind": "arg",
+                "path": "proposalId"
               }
             ]
           }
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "proposalId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "cancelConfigChange",
+      "docs": [
+        "Lets the pause guardian drop a proposal during its timelock window."
+      ],
+      "discriminator": [
+        222,
+        114,
+        136,
+        167,
+        183,
+        86,
+        61,
+        158
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "Only the pause guardian; a single admin signer must not be able to veto a",
+            "proposal the multisig approved"
+          ],
+          "signer": true
         },
         {
           "name": "config",
@@ -128,95 +141,76 @@ export type SolanaDexContract = {
               }
             ]
           }
-        }
-      ],
-      "args": [
-        {
-          "name": "requestId",
-          "type": {
-            "array": [
-              "u8",
-              32
-            ]
-          }
-        },
-        {
-          "name": "serializedOutput",
-          "type": "bytes"
-        },
-        {
-          "name": "signature",
-          "type": {
-            "defined": {
-              "name": "signature"
-            }
-          }
-        }
-      ]
-    },
-    {
-      "name": "claimErc20",
-      "discriminator": [
-        137,
-        119,
-        173,
-        96,
-        103,
-        202,
-        227,
-        33
-      ],
-      "accounts": [
-        {
-          "name": "payer",
-          "writable": true,
-          "signer": true
         },
         {
-          "name": "pendingDeposit",
+          "name": "proposal",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  101,
+                  99,
+                  111,
                   110,
-                  100,
+                  102,
                   105,
-                  110,
                   103,
                   95,
-                  101,
+                  112,
                   114,
-                  99,
-                  50,
-                  48,
-                  95,
-                  100,
-                  101,
+                  111,
                   112,
                   111,
                   115,
-                  105,
-                  116
+                  97,
+                  108
                 ]
               },
               {
                 "kind": "arg",
-                "path": "requestId"
+                "path": "proposalId"
               }
             ]
           }
         },
         {
-          "name": "userBalance",
-          "writable": true
-        },
+          "name": "proposer",
+          "writable": true,
+          "relations": [
+            "proposal"
+          ]
+        }
+      ],
+      "args": [
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "proposalId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "cancelWithdrawBtc",
+      "docs": [
+        "Executes a `CancelWithdrawal` proposal, signing a transaction that spends a",
+        "timed-out withdrawal's inputs back to the vault. Its success response refunds",
+        "the withdrawal."
+      ],
+      "discriminator": [
+        228,
+        88,
+        63,
+        224,
+        51,
+        83,
+        71,
+        133
+      ],
+      "accounts": [
+        {
+          "name": "executor",
+          "writable": true,
+          "signer": true
         },
         {
           "name": "config",
@@ -241,193 +235,80 @@ export type SolanaDexContract = {
               }
             ]
           }
-        }
-      ],
-      "args": [
-        {
-          "name": "requestId",
-          "type": {
-            "array": [
-              "u8",
-              32
-            ]
-          }
-        },
-        {
-          "name": "serializedOutput",
-          "type": "bytes"
-        },
-        {
-          "name": "signature",
-          "type": {
-            "defined": {
-              "name": "signature"
-            }
-          }
-        }
-      ]
-    },
-    {
-      "name": "completeWithdrawBtc",
-      "discriminator": [
-        38,
-        128,
-        71,
-        114,
-        184,
-        50,
-        131,
-        32
-      ],
-      "accounts": [
-        {
-          "name": "payer",
-          "writable": true,
-          "signer": true
         },
         {
-          "name": "pendingWithdrawal",
+          "name": "proposal",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  101,
+                  99,
+                  111,
                   110,
-                  100,
+                  102,
                   105,
-                  110,
                   103,
                   95,
-                  98,
-                  116,
-                  99,
-                  95,
-                  119,
-                  105,
-                  116,
-                  104,
-                  100,
+                  112,
                   114,
-                  97,
-                  119,
+                  111,
+                  112,
+                  111,
+                  115,
                   97,
                   108
                 ]
               },
               {
                 "kind": "arg",
-                "path": "requestId"
+                "path": "proposalId"
               }
             ]
           }
         },
         {
-          "name": "userBalance",
+          "name": "proposer",
+          "writable": true,
+          "relations": [
+            "proposal"
+          ]
+        },
+        {
+          "name": "requester",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  117,
-                  115,
-                  101,
-                  114,
-                  95,
-                  98,
-                  116,
-                  99,
-                  95,
+                  103,
+                  108,
+                  111,
                   98,
                   97,
                   108,
-                  97,
-                  110,
-                  99,
-                  101
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "pending_withdrawal.requester",
-                "account": "pendingBtcWithdrawal"
-              }
-            ]
-          }
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        },
-        {
-          "name": "config",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
+                  95,
                   118,
                   97,
                   117,
                   108,
                   116,
                   95,
-                  99,
+                  97,
+                  117,
+                  116,
+                  104,
                   111,
-                  110,
-                  102,
+                  114,
                   105,
-                  103
+                  116,
+                  121
                 ]
               }
             ]
           }
-        }
-      ],
-      "args": [
-        {
-          "name": "requestId",
-          "type": {
-            "array": [
-              "u8",
-              32
-            ]
-          }
-        },
-        {
-          "name": "serializedOutput",
-          "type": "bytes"
-        },
-        {
-          "name": "signature",
-          "type": {
-            "defined": {
-              "name": "signature"
-            }
-          }
-        }
-      ]
-    },
-    {
-      "name": "completeWithdrawErc20",
-      "discriminator": [
-        108,
-        220,
-        227,
-        17,
-        212,
-        248,
-        163,
-        74
-      ],
-      "accounts": [
-        {
-          "name": "payer",
-          "writable": true,
-          "signer": true
         },
         {
           "name": "pendingWithdrawal",
@@ -445,11 +326,9 @@ export type SolanaDexContract = {
                   110,
                   103,
                   95,
-                  101,
-                  114,
+                  98,
+                  116,
                   99,
-                  50,
-                  48,
                   95,
                   119,
                   105,
@@ -471,39 +350,95 @@ export type SolanaDexContract = {
           }
         },
         {
-          "name": "userBalance",
-          "writable": true
+          "name": "vaultUtxoSet"
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "feePayer",
+          "writable": true,
+          "signer": true,
+          "optional": true
         },
         {
-          "name": "config",
+          "name": "chainSignaturesState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "chainSignaturesProgram"
+            }
+          }
+        },
+        {
+          "name": "eventAuthority",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  95,
+                  95,
+                  101,
                   118,
+                  101,
+                  110,
+                  116,
+                  95,
                   97,
                   117,
-                  108,
                   116,
-                  95,
-                  99,
+                  104,
                   111,
-                  110,
-                  102,
+                  114,
                   105,
-                  103
+                  116,
+                  121
                 ]
               }
-            ]
+            ],
+            "program": {
+              "kind": "account",
+              "path": "chainSignaturesProgram"
+            }
           }
+        },
+        {
+          "name": "chainSignaturesProgram",
+          "address": "H5tHfpYoEnarrrzcV7sWBcZhiKMvL2aRpUYvb1ydWkwS"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "instructions",
+          "optional": true
         }
       ],
       "args": [
+        {
+          "name": "proposalId",
+          "type": "u64"
+        },
         {
           "name": "requestId",
           "type": {
@@ -514,40 +449,45 @@ export type SolanaDexContract = {
           }
         },
         {
-          "name": "serializedOutput",
-          "type": "bytes"
-        },
-        {
-          "name": "signature",
+          "name": "cancelRequestId",
           "type": {
-            "defined": {
-              "name": "signature"
-            }
+            "array": [
+              "u8",
+              32
+            ]
           }
+        },
+        {
+          "name": "feeRate",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "depositBtc",
+      "name": "cancelWithdrawErc20",
+      "docs": [
+        "Executes a `CancelWithdrawal` proposal by signing a zero-value self-transfer",
+        "with the withdrawal's nonce. Its success response refunds the withdrawal",
+        "through `complete_withdraw_erc20`."
+      ],
       "discriminator": [
-        198,
-        160,
-        142,
-        68,
-        156,
-        232,
-        70,
-        202
+        153,
+        175,
+        15,
+        185,
+        250,
+        144,
+        3,
+        196
       ],
       "accounts": [
         {
-          "name": "payer",
+          "name": "executor",
           "writable": true,
           "signer": true
         },
         {
-          "name": "requesterPda",
-          "writable": true,
+          "name": "config",
           "pda": {
             "seeds": [
               {
@@ -559,109 +499,75 @@ export type SolanaDexContract = {
                   108,
                   116,
                   95,
-                  97,
-                  117,
-                  116,
-                  104,
+                  99,
                   111,
-                  114,
+                  110,
+                  102,
                   105,
-                  116,
-                  121
+                  103
                 ]
-              },
-              {
-                "kind": "arg",
-                "path": "requester"
               }
             ]
           }
         },
         {
-          "name": "pendingDeposit",
+          "name": "proposal",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  101,
+                  99,
+                  111,
                   110,
-                  100,
+                  102,
                   105,
-                  110,
                   103,
                   95,
-                  98,
-                  116,
-                  99,
-                  95,
-                  100,
-                  101,
+                  112,
+                  114,
+                  111,
                   112,
                   111,
                   115,
-                  105,
-                  116
+                  97,
+                  108
                 ]
               },
               {
                 "kind": "arg",
-                "path": "requestId"
+                "path": "proposalId"
               }
             ]
           }
         },
         {
-          "name": "feePayer",
+          "name": "proposer",
           "writable": true,
-          "signer": true,
-          "optional": true
+          "relations": [
+            "proposal"
+          ]
         },
         {
-          "name": "chainSignaturesState",
+          "name": "requester",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  114,
-                  111,
                   103,
-                  114,
+                  108,
+                  111,
+                  98,
                   97,
-                  109,
-                  45,
-                  115,
-                  116,
+                  108,
+                  95,
+                  118,
                   97,
-                  116,
-                  101
-                ]
-              }
-            ],
-            "program": {
-              "kind": "account",
-              "path": "chainSignaturesProgram"
-            }
-          }
-        },
-        {
-          "name": "eventAuthority",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  95,
-                  95,
-                  101,
-                  118,
-                  101,
-                  110,
+                  117,
+                  108,
                   116,
                   95,
                   97,
@@ -675,178 +581,72 @@ export type SolanaDexContract = {
                   121
                 ]
               }
-            ],
-            "program": {
-              "kind": "account",
-              "path": "chainSignaturesProgram"
-            }
-          }
-        },
-        {
-          "name": "chainSignaturesProgram",
-          "address": "H5tHfpYoEnarrrzcV7sWBcZhiKMvL2aRpUYvb1ydWkwS"
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        },
-        {
-          "name": "instructions",
-          "optional": true
-        },
-        {
-          "name": "config",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  118,
-                  97,
-                  117,
-                  108,
-                  116,
-                  95,
-                  99,
-                  111,
-                  110,
-                  102,
-                  105,
-                  103
-                ]
-              }
-            ]
-          }
-        }
-      ],
-      "args": [
-        {
-          "name": "requestId",
-          "type": {
-            "array": [
-              "u8",
-              32
             ]
           }
         },
         {
-          "name": "requester",
-          "type": "pubkey"
-        },
-        {
-          "name": "inputs",
-          "type": {
-            "vec": {
-              "defined": {
-                "name": "btcInput"
-              }
-            }
-          }
-        },
-        {
-          "name": "outputs",
-          "type": {
-            "vec": {
-              "defined": {
-                "name": "btcOutput"
-              }
-            }
-          }
-        },
-        {
-          "name": "txParams",
-          "type": {
-            "defined": {
-              "name": "btcDepositParams"
-            }
-          }
-        }
-      ]
-    },
-    {
-      "name": "depositErc20",
-      "discriminator": [
-        22,
-        2,
-        82,
-        3,
-        29,
-        137,
-        71,
-        85
-      ],
-      "accounts": [
-        {
-          "name": "payer",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "requesterPda",
+          "name": "pendingWithdrawal",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  118,
-                  97,
-                  117,
-                  108,
-                  116,
+                  112,
+                  101,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
                   95,
-                  97,
-                  117,
-                  116,
-                  104,
-                  111,
+                  101,
                   114,
+                  99,
+                  50,
+                  48,
+                  95,
+                  119,
                   105,
                   116,
-                  121
+                  104,
+                  100,
+                  114,
+                  97,
+                  119,
+                  97,
+                  108
                 ]
               },
               {
                 "kind": "arg",
-                "path": "requester"
+                "path": "requestId"
               }
             ]
           }
         },
         {
-          "name": "pendingDeposit",
-          "writable": true,
+          "name": "chainRegistry",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  101,
-                  110,
-                  100,
+                  99,
+                  104,
+                  97,
                   105,
                   110,
-                  103,
                   95,
-                  101,
                   114,
-                  99,
-                  50,
-                  48,
-                  95,
-                  100,
                   101,
-                  112,
-                  111,
-                  115,
+                  103,
                   105,
-                  116
+                  115,
+                  116,
+                  114,
+                  121
                 ]
-              },
-              {
-                "kind": "arg",
-                "path": "requestId"
               }
             ]
           }
@@ -931,68 +731,31 @@ export type SolanaDexContract = {
         {
           "name": "instructions",
           "optional": true
-        },
-        {
-          "name": "config",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  118,
-                  97,
-                  117,
-                  108,
-                  116,
-                  95,
-                  99,
-                  111,
-                  110,
-                  102,
-                  105,
-                  103
-                ]
-              }
-            ]
-          }
         }
       ],
       "args": [
         {
-          "name": "requestId",
-          "type": {
-            "array": [
-              "u8",
-              32
-            ]
-          }
-        },
-        {
-          "name": "requester",
-          "type": "pubkey"
+          "name": "proposalId",
+          "type": "u64"
         },
         {
-          "name": "erc20Address",
+          "name": "requestId",
           "type": {
             "array": [
               "u8",
-              20
+              32
             ]
           }
         },
         {
-          "name": "recipientAddress",
+          "name": "cancelRequestId",
           "type": {
             "array": [
               "u8",
-              20
+              32
             ]
           }
         },
-        {
-          "name": "amount",
-          "type": "u128"
-        },
         {
           "name": "txParams",
           "type": {
@@ -1004,16 +767,16 @@ export type SolanaDexContract = {
       ]
     },
     {
-      "name": "initializeConfig",
+      "name": "claimBtc",
       "discriminator": [
-        208,
-        127,
-        21,
-        1,
-        194,
-        190,
-        196,
-        70
+        171,
+        129,
+        74,
+        3,
+        215,
+        249,
+        44,
+        164
       ],
       "accounts": [
         {
@@ -1022,8 +785,95 @@ export type SolanaDexContract = {
           "signer": true
         },
         {
-          "name": "config",
+          "name": "pendingDeposit",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  101,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
+                  95,
+                  98,
+                  116,
+                  99,
+                  95,
+                  100,
+                  101,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "requestId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "rentPayer",
+          "writable": true,
+          "relations": [
+            "pendingDeposit"
+          ]
+        },
+        {
+          "name": "processedRequest",
+          "docs": [
+            "Marks `request_id` as settled; `init` fails if it already was"
+          ],
           "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  99,
+                  101,
+                  115,
+                  115,
+                  101,
+                  100,
+                  95,
+                  114,
+                  101,
+                  113,
+                  117,
+                  101,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "requestId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userBalance",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "config",
           "pda": {
             "seeds": [
               {
@@ -1047,77 +897,129 @@ export type SolanaDexContract = {
           }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "chainRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  104,
+                  97,
+                  105,
+                  110,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultUtxoSet",
+          "writable": true
         }
       ],
       "args": [
         {
-          "name": "mpcRootPublicKey",
+          "name": "requestId",
           "type": {
             "array": [
               "u8",
-              64
+              32
             ]
           }
+        },
+        {
+          "name": "serializedOutput",
+          "type": "bytes"
+        },
+        {
+          "name": "signature",
+          "type": {
+            "defined": {
+              "name": "signature"
+            }
+          }
         }
       ]
     },
     {
-      "name": "withdrawBtc",
+      "name": "claimErc20",
       "discriminator": [
-        22,
-        191,
-        230,
-        176,
-        149,
-        62,
-        236,
-        140
+        137,
+        119,
+        173,
+        96,
+        103,
+        202,
+        227,
+        33
       ],
       "accounts": [
         {
-          "name": "authority",
+          "name": "payer",
           "writable": true,
           "signer": true
         },
         {
-          "name": "requester",
+          "name": "pendingDeposit",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  112,
+                  101,
+                  110,
+                  100,
+                  105,
+                  110,
                   103,
-                  108,
-                  111,
-                  98,
-                  97,
-                  108,
                   95,
-                  118,
-                  97,
-                  117,
-                  108,
-                  116,
+                  101,
+                  114,
+                  99,
+                  50,
+                  48,
                   95,
-                  97,
-                  117,
-                  116,
-                  104,
+                  100,
+                  101,
+                  112,
                   111,
-                  114,
+                  115,
                   105,
-                  116,
-                  121
+                  116
                 ]
+              },
+              {
+                "kind": "arg",
+                "path": "requestId"
               }
             ]
           }
         },
         {
-          "name": "pendingWithdrawal",
+          "name": "rentPayer",
+          "writable": true,
+          "relations": [
+            "pendingDeposit"
+          ]
+        },
+        {
+          "name": "processedRequest",
+          "docs": [
+            "Marks `request_id` as settled; `init` fails if it already was"
+          ],
           "writable": true,
           "pda": {
             "seeds": [
@@ -1125,27 +1027,22 @@ export type SolanaDexContract = {
                 "kind": "const",
                 "value": [
                   112,
+                  114,
+                  111,
+                  99,
+                  101,
+                  115,
+                  115,
                   101,
-                  110,
                   100,
-                  105,
-                  110,
-                  103,
-                  95,
-                  98,
-                  116,
-                  99,
                   95,
-                  119,
-                  105,
-                  116,
-                  104,
-                  100,
                   114,
-                  97,
-                  119,
-                  97,
-                  108
+                  101,
+                  113,
+                  117,
+                  101,
+                  115,
+                  116
                 ]
               },
               {
@@ -1157,117 +1054,118 @@ export type SolanaDexContract = {
         },
         {
           "name": "userBalance",
-          "writable": true,
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "config",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  118,
+                  97,
                   117,
-                  115,
-                  101,
-                  114,
-                  95,
-                  98,
+                  108,
                   116,
-                  99,
                   95,
-                  98,
-                  97,
-                  108,
-                  97,
-                  110,
                   99,
-                  101
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "authority"
               }
             ]
           }
         },
         {
-          "name": "feePayer",
-          "writable": true,
-          "signer": true,
-          "optional": true
-        },
-        {
-          "name": "chainSignaturesState",
+          "name": "tokenRegistry",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  114,
-                  111,
-                  103,
-                  114,
-                  97,
-                  109,
-                  45,
-                  115,
-                  116,
-                  97,
                   116,
-                  101
-                ]
-              }
-            ],
-            "program": {
-              "kind": "account",
-              "path": "chainSignaturesProgram"
-            }
-          }
-        },
-        {
-          "name": "eventAuthority",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  95,
-                  95,
-                  101,
-                  118,
+                  111,
+                  107,
                   101,
                   110,
-                  116,
                   95,
-                  97,
-                  117,
-                  116,
-                  104,
-                  111,
                   114,
+                  101,
+                  103,
                   105,
+                  115,
                   116,
+                  114,
                   121
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "pending_deposit.chain_id",
+                "account": "pendingErc20Deposit"
+              },
+              {
+                "kind": "account",
+                "path": "pending_deposit.erc20_address",
+                "account": "pendingErc20Deposit"
               }
-            ],
-            "program": {
-              "kind": "account",
-              "path": "chainSignaturesProgram"
-            }
+            ]
           }
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "chainSignaturesProgram",
-          "address": "H5tHfpYoEnarrrzcV7sWBcZhiKMvL2aRpUYvb1ydWkwS"
+          "name": "requestId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "serializedOutput",
+          "type": "bytes"
         },
         {
-          "name": "instructions",
-          "optional": true
+          "name": "signature",
+          "type": {
+            "defined": {
+              "name": "signature"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "collectBtcFees",
+      "docs": [
+        "Moves accrued BTC protocol fees into the treasury balance."
+      ],
+      "discriminator": [
+        192,
+        153,
+        255,
+        191,
+        151,
+        63,
+        163,
+        171
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
         },
         {
           "name": "config",
@@ -1292,142 +1190,132 @@ export type SolanaDexContract = {
               }
             ]
           }
-        }
-      ],
-      "args": [
-        {
-          "name": "requestId",
-          "type": {
-            "array": [
-              "u8",
-              32
-            ]
-          }
         },
         {
-          "name": "inputs",
-          "type": {
-            "vec": {
-              "defined": {
-                "name": "btcInput"
+          "name": "chainRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  104,
+                  97,
+                  105,
+                  110,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
               }
-            }
+            ]
           }
         },
         {
-          "name": "amount",
-          "type": "u64"
+          "name": "treasuryBalance",
+          "writable": true
         },
         {
-          "name": "recipientAddress",
-          "type": "string"
-        },
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
         {
-          "name": "txParams",
-          "type": {
-            "defined": {
-              "name": "btcWithdrawParams"
-            }
-          }
+          "name": "caip2Id",
+          "type": "string"
         }
       ]
     },
     {
-      "name": "withdrawErc20",
+      "name": "collectErc20Fees",
+      "docs": [
+        "Moves accrued ERC20 protocol fees into the treasury balance."
+      ],
       "discriminator": [
-        19,
-        124,
-        28,
-        31,
-        171,
-        187,
-        87,
-        70
+        119,
+        143,
+        93,
+        21,
+        101,
+        119,
+        119,
+        128
       ],
       "accounts": [
         {
-          "name": "authority",
+          "name": "payer",
           "writable": true,
           "signer": true
         },
         {
-          "name": "requester",
-          "writable": true,
+          "name": "config",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  103,
-                  108,
-                  111,
-                  98,
-                  97,
-                  108,
-                  95,
                   118,
                   97,
                   117,
                   108,
                   116,
                   95,
-                  97,
-                  117,
-                  116,
-                  104,
+                  99,
                   111,
-                  114,
+                  110,
+                  102,
                   105,
-                  116,
-                  121
+                  103
                 ]
               }
             ]
           }
         },
         {
-          "name": "pendingWithdrawal",
+          "name": "tokenRegistry",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
+                  116,
+                  111,
+                  107,
                   101,
                   110,
-                  100,
-                  105,
-                  110,
-                  103,
                   95,
-                  101,
                   114,
-                  99,
-                  50,
-                  48,
-                  95,
-                  119,
+                  101,
+                  103,
                   105,
+                  115,
                   116,
-                  104,
-                  100,
                   114,
-                  97,
-                  119,
-                  97,
-                  108
+                  121
                 ]
               },
               {
                 "kind": "arg",
-                "path": "requestId"
+                "path": "chainId"
+              },
+              {
+                "kind": "arg",
+                "path": "erc20Address"
               }
             ]
           }
         },
         {
-          "name": "userBalance",
+          "name": "treasuryBalance",
           "writable": true,
           "pda": {
             "seeds": [
@@ -1456,7 +1344,12 @@ export type SolanaDexContract = {
               },
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "config.treasury",
+                "account": "vaultConfig"
+              },
+              {
+                "kind": "arg",
+                "path": "chainId"
               },
               {
                 "kind": "arg",
@@ -1466,86 +1359,126 @@ export type SolanaDexContract = {
           }
         },
         {
-          "name": "feePayer",
-          "writable": true,
-          "signer": true,
-          "optional": true
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u64"
         },
         {
-          "name": "chainSignaturesState",
+          "name": "erc20Address",
+          "type": {
+            "array": [
+              "u8",
+              20
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "completeSweepBtc",
+      "discriminator": [
+        240,
+        244,
+        173,
+        98,
+        65,
+        166,
+        40,
+        68
+      ],
+      "accounts": [
+        {
+          "name": "payer",
           "writable": true,
-          "pda": {
+          "signer": true
+        },
+        {
+          "name": "pendingSweep",
+          "writable": true,
+          "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
                   112,
-                  114,
-                  111,
+                  101,
+                  110,
+                  100,
+                  105,
+                  110,
                   103,
-                  114,
-                  97,
-                  109,
-                  45,
-                  115,
-                  116,
-                  97,
+                  95,
+                  98,
                   116,
-                  101
+                  99,
+                  95,
+                  115,
+                  119,
+                  101,
+                  101,
+                  112
                 ]
+              },
+              {
+                "kind": "arg",
+                "path": "requestId"
               }
-            ],
-            "program": {
-              "kind": "account",
-              "path": "chainSignaturesProgram"
-            }
+            ]
           }
         },
         {
-          "name": "eventAuthority",
+          "name": "rentPayer",
+          "writable": true,
+          "relations": [
+            "pendingSweep"
+          ]
+        },
+        {
+          "name": "processedRequest",
+          "docs": [
+            "Marks `request_id` as settled; `init` fails if it already was"
+          ],
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  95,
-                  95,
+                  112,
+                  114,
+                  111,
+                  99,
                   101,
-                  118,
+                  115,
+                  115,
                   101,
-                  110,
-                  116,
+                  100,
                   95,
-                  97,
-                  117,
-                  116,
-                  104,
-                  111,
                   114,
-                  105,
-                  116,
-                  121
+                  101,
+                  113,
+                  117,
+                  101,
+                  115,
+                  116
                 ]
+              },
+              {
+                "kind": "arg",
+                "path": "requestId"
               }
-            ],
-            "program": {
-              "kind": "account",
-              "path": "chainSignaturesProgram"
-            }
+            ]
           }
         },
-        {
-          "name": "chainSignaturesProgram",
-          "address": "H5tHfpYoEnarrrzcV7sWBcZhiKMvL2aRpUYvb1ydWkwS"
-        },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
         },
-        {
-          "name": "instructions",
-          "optional": true
-        },
         {
           "name": "config",
           "pda": {
@@ -1569,6 +1502,37 @@ export type SolanaDexContract = {
               }
             ]
           }
+        },
+        {
+          "name": "chainRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  104,
+                  97,
+                  105,
+                  110,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultUtxoSet",
+          "writable": true
         }
       ],
       "args": [
@@ -1582,247 +1546,7084 @@ export type SolanaDexContract = {
           }
         },
         {
-          "name": "erc20Address",
-          "type": {
-            "array": [
-              "u8",
-              20
-            ]
-          }
-        },
-        {
-          "name": "amount",
-          "type": "u128"
-        },
-        {
-          "name": "recipientAddress",
-          "type": {
-            "array": [
-              "u8",
-              20
-            ]
-          }
+          "name": "serializedOutput",
+          "type": "bytes"
         },
         {
-          "name": "txParams",
+          "name": "signature",
           "type": {
             "defined": {
-              "name": "evmTransactionParams"
+              "name": "signature"
             }
           }
         }
       ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "pendingBtcDeposit",
-      "discriminator": [
-        210,
-        198,
-        216,
-        218,
-        80,
-        77,
-        38,
-        139
-      ]
-    },
-    {
-      "name": "pendingBtcWithdrawal",
-      "discriminator": [
-        215,
-        0,
-        70,
-        221,
-        85,
-        240,
-        166,
-        85
-      ]
-    },
-    {
-      "name": "pendingErc20Deposit",
-      "discriminator": [
-        214,
-        238,
-        68,
-        242,
-        98,
-        102,
-        251,
-        178
-      ]
-    },
-    {
-      "name": "pendingErc20Withdrawal",
-      "discriminator": [
-        33,
-        60,
-        7,
-        188,
-        11,
-        40,
-        41,
-        150
-      ]
-    },
-    {
-      "name": "userBtcBalance",
-      "discriminator": [
-        15,
-        220,
-        25,
-        120,
-        133,
-        118,
-        246,
-        20
-      ]
     },
     {
-      "name": "userErc20Balance",
-      "discriminator": [
-        29,
-        16,
-        203,
-        40,
-        208,
-        43,
-        221,
-        11
-      ]
-    },
-    {
-      "name": "vaultConfig",
+      "name": "completeWithdrawBtc",
       "discriminator": [
-        99,
-        86,
-        43,
-        216,
+        38,
+        128,
+        71,
+        114,
         184,
-        102,
-        119,
-        77
-      ]
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "serializationError",
-      "msg": "Serialization error"
-    },
-    {
-      "code": 6001,
-      "name": "functionNotFound",
-      "msg": "Function not found in ABI"
-    },
-    {
-      "code": 6002,
-      "name": "invalidRequestId",
-      "msg": "Invalid request ID"
-    },
-    {
-      "code": 6003,
-      "name": "invalidSignature",
+        50,
+        131,
+        32
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "pendingWithdrawal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  101,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
+                  95,
+                  98,
+                  116,
+                  99,
+                  95,
+                  119,
+                  105,
+                  116,
+                  104,
+                  100,
+                  114,
+                  97,
+                  119,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "requestId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "rentPayer",
+          "writable": true,
+          "relations": [
+            "pendingWithdrawal"
+          ]
+        },
+        {
+          "name": "processedRequest",
+          "docs": [
+            "Marks `request_id` as settled; `init` fails if it already was"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  99,
+                  101,
+                  115,
+                  115,
+                  101,
+                  100,
+                  95,
+                  114,
+                  101,
+                  113,
+                  117,
+                  101,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "requestId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userBalance",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "chainRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  104,
+                  97,
+                  105,
+                  110,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultUtxoSet",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "requestId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "serializedOutput",
+          "type": "bytes"
+        },
+        {
+          "name": "signature",
+          "type": {
+            "defined": {
+              "name": "signature"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "completeWithdrawErc20",
+      "discriminator": [
+        108,
+        220,
+        227,
+        17,
+        212,
+        248,
+        163,
+        74
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "pendingWithdrawal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  101,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
+                  95,
+                  101,
+                  114,
+                  99,
+                  50,
+                  48,
+                  95,
+                  119,
+                  105,
+                  116,
+                  104,
+                  100,
+                  114,
+                  97,
+                  119,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "requestId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "rentPayer",
+          "writable": true,
+          "relations": [
+            "pendingWithdrawal"
+          ]
+        },
+        {
+          "name": "processedRequest",
+          "docs": [
+            "Marks `request_id` as settled; `init` fails if it already was"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  99,
+                  101,
+                  115,
+                  115,
+                  101,
+                  100,
+                  95,
+                  114,
+                  101,
+                  113,
+                  117,
+                  101,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "requestId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userBalance",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  111,
+                  107,
+                  101,
+                  110,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "pending_withdrawal.chain_id",
+                "account": "pendingErc20Withdrawal"
+              },
+              {
+                "kind": "account",
+                "path": "pending_withdrawal.erc20_address",
+                "account": "pendingErc20Withdrawal"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "requestId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "serializedOutput",
+          "type": "bytes"
+        },
+        {
+          "name": "signature",
+          "type": {
+            "defined": {
+              "name": "signature"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "depositBtc",
+      "discriminator": [
+        198,
+        160,
+        142,
+        68,
+        156,
+        232,
+        70,
+        202
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "requesterPda",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "requester"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pendingDeposit",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  101,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
+                  95,
+                  98,
+                  116,
+                  99,
+                  95,
+                  100,
+                  101,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "requestId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "processedRequest",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  99,
+                  101,
+                  115,
+                  115,
+                  101,
+                  100,
+                  95,
+                  114,
+                  101,
+                  113,
+                  117,
+                  101,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "requestId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feePayer",
+          "writable": true,
+          "signer": true,
+          "optional": true
+        },
+        {
+          "name": "chainSignaturesState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "chainSignaturesProgram"
+            }
+          }
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "chainSignaturesProgram"
+            }
+          }
+        },
+        {
+          "name": "chainSignaturesProgram",
+          "address": "H5tHfpYoEnarrrzcV7sWBcZhiKMvL2aRpUYvb1ydWkwS"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "instructions",
+          "optional": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "chainRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  104,
+                  97,
+                  105,
+                  110,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "requestId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "requester",
+          "type": "pubkey"
+        },
+        {
+          "name": "inputs",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "btcInput"
+              }
+            }
+          }
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "txParams",
+          "type": {
+            "defined": {
+              "name": "btcDepositParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "depositErc20",
+      "discriminator": [
+        22,
+        2,
+        82,
+        3,
+        29,
+        137,
+        71,
+        85
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "requesterPda",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "requester"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pendingDeposit",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  101,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
+                  95,
+                  101,
+                  114,
+                  99,
+                  50,
+                  48,
+                  95,
+                  100,
+                  101,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "requestId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "processedRequest",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  99,
+                  101,
+                  115,
+                  115,
+                  101,
+                  100,
+                  95,
+                  114,
+                  101,
+                  113,
+                  117,
+                  101,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "requestId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feePayer",
+          "writable": true,
+          "signer": true,
+          "optional": true
+        },
+        {
+          "name": "chainSignaturesState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "chainSignaturesProgram"
+            }
+          }
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "chainSignaturesProgram"
+            }
+          }
+        },
+        {
+          "name": "chainSignaturesProgram",
+          "address": "H5tHfpYoEnarrrzcV7sWBcZhiKMvL2aRpUYvb1ydWkwS"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "instructions",
+          "optional": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "chainRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  104,
+                  97,
+                  105,
+                  110,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  111,
+                  107,
+                  101,
+                  110,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tx_params.chain_id"
+              },
+              {
+                "kind": "arg",
+                "path": "erc20Address"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "requestId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "requester",
+          "type": "pubkey"
+        },
+        {
+          "name": "erc20Address",
+          "type": {
+            "array": [
+              "u8",
+              20
+            ]
+          }
+        },
+        {
+          "name": "amount",
+          "type": "u128"
+        },
+        {
+          "name": "txParams",
+          "type": {
+            "defined": {
+              "name": "evmTransactionParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "executeChainChange",
+      "docs": [
+        "Executes a SetChain, RemoveChain or CreditChainLiabilities proposal against the",
+        "chain registry."
+      ],
+      "discriminator": [
+        30,
+        115,
+        91,
+        36,
+        108,
+        250,
+        166,
+        76
+      ],
+      "accounts": [
+        {
+          "name": "executor",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  95,
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "proposalId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposer",
+          "writable": true,
+          "relations": [
+            "proposal"
+          ]
+        },
+        {
+          "name": "chainRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  104,
+                  97,
+                  105,
+                  110,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "proposalId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "executeConfigChange",
+      "discriminator": [
+        100,
+        150,
+        198,
+        236,
+        103,
+        241,
+        118,
+        71
+      ],
+      "accounts": [
+        {
+          "name": "executor",
+          "signer": true
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  95,
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "proposalId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposer",
+          "writable": true,
+          "relations": [
+            "proposal"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "proposalId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "executeEvmNativeTransfer",
+      "docs": [
+        "Executes a TransferEvmNative proposal, signing a native value transfer from",
+        "the EVM vault address with the chain's vault nonce."
+      ],
+      "discriminator": [
+        87,
+        82,
+        225,
+        46,
+        183,
+        94,
+        157,
+        17
+      ],
+      "accounts": [
+        {
+          "name": "executor",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  95,
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "proposalId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposer",
+          "writable": true,
+          "relations": [
+            "proposal"
+          ]
+        },
+        {
+          "name": "requester",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultNonce",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  118,
+                  109,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  110,
+                  111,
+                  110,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tx_params.chain_id"
+              },
+              {
+                "kind": "account",
+                "path": "config.active_key_version",
+                "account": "vaultConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "chainRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  104,
+                  97,
+                  105,
+                  110,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "feePayer",
+          "writable": true,
+          "signer": true,
+          "optional": true
+        },
+        {
+          "name": "chainSignaturesState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "chainSignaturesProgram"
+            }
+          }
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "chainSignaturesProgram"
+            }
+          }
+        },
+        {
+          "name": "chainSignaturesProgram",
+          "address": "H5tHfpYoEnarrrzcV7sWBcZhiKMvL2aRpUYvb1ydWkwS"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "instructions",
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "proposalId",
+          "type": "u64"
+        },
+        {
+          "name": "requestId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "txParams",
+          "type": {
+            "defined": {
+              "name": "evmTransactionParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "executeEvmNonceChange",
+      "docs": [
+        "Executes a SetEvmVaultNonce proposal, creating the nonce account of the chain",
+        "and key version if needed."
+      ],
+      "discriminator": [
+        39,
+        163,
+        199,
+        35,
+        222,
+        239,
+        228,
+        212
+      ],
+      "accounts": [
+        {
+          "name": "executor",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  95,
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "proposalId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposer",
+          "writable": true,
+          "relations": [
+            "proposal"
+          ]
+        },
+        {
+          "name": "vaultNonce",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  118,
+                  109,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  110,
+                  111,
+                  110,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "chainId"
+              },
+              {
+                "kind": "arg",
+                "path": "keyVersion"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "proposalId",
+          "type": "u64"
+        },
+        {
+          "name": "chainId",
+          "type": "u64"
+        },
+        {
+          "name": "keyVersion",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "executeTokenChange",
+      "docs": [
+        "Executes a SetToken proposal, creating the token's registry entry if needed."
+      ],
+      "discriminator": [
+        246,
+        8,
+        22,
+        213,
+        96,
+        4,
+        234,
+        226
+      ],
+      "accounts": [
+        {
+          "name": "executor",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  95,
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "proposalId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposer",
+          "writable": true,
+          "relations": [
+            "proposal"
+          ]
+        },
+        {
+          "name": "tokenRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  111,
+                  107,
+                  101,
+                  110,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "chainId"
+              },
+              {
+                "kind": "arg",
+                "path": "erc20Address"
+              }
+            ]
+          }
+        },
+        {
+          "name": "chainRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  104,
+                  97,
+                  105,
+                  110,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "proposalId",
+          "type": "u64"
+        },
+        {
+          "name": "chainId",
+          "type": "u64"
+        },
+        {
+          "name": "erc20Address",
+          "type": {
+            "array": [
+              "u8",
+              20
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "executeUtxoChange",
+      "docs": [
+        "Executes an AddVaultUtxos or RemoveVaultUtxos proposal against the chain's",
+        "vault UTXO set, creating the set if needed."
+      ],
+      "discriminator": [
+        157,
+        227,
+        63,
+        155,
+        238,
+        0,
+        201,
+        242
+      ],
+      "accounts": [
+        {
+          "name": "executor",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  95,
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "proposalId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposer",
+          "writable": true,
+          "relations": [
+            "proposal"
+          ]
+        },
+        {
+          "name": "vaultUtxoSet",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "proposalId",
+          "type": "u64"
+        },
+        {
+          "name": "caip2Id",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "finalizeFailedDepositBtc",
+      "docs": [
+        "Closes a deposit the MPC network reported as failed, keeping a receipt with the reason."
+      ],
+      "discriminator": [
+        83,
+        116,
+        239,
+        115,
+        197,
+        31,
+        114,
+        137
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "pendingDeposit",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  101,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
+                  95,
+                  98,
+                  116,
+                  99,
+                  95,
+                  100,
+                  101,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "requestId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "rentPayer",
+          "writable": true,
+          "relations": [
+            "pendingDeposit"
+          ]
+        },
+        {
+          "name": "processedRequest",
+          "docs": [
+            "Marks `request_id` as settled; `init` fails if it already was"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  99,
+                  101,
+                  115,
+                  115,
+                  101,
+                  100,
+                  95,
+                  114,
+                  101,
+                  113,
+                  117,
+                  101,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "requestId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "failedDeposit",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  97,
+                  105,
+                  108,
+                  101,
+                  100,
+                  95,
+                  98,
+                  116,
+                  99,
+                  95,
+                  100,
+                  101,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "requestId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "chainRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  104,
+                  97,
+                  105,
+                  110,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "requestId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "serializedOutput",
+          "type": "bytes"
+        },
+        {
+          "name": "signature",
+          "type": {
+            "defined": {
+              "name": "signature"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "finalizeFailedDepositErc20",
+      "docs": [
+        "Closes a deposit the MPC network reported as failed, keeping a receipt with the reason."
+      ],
+      "discriminator": [
+        212,
+        143,
+        38,
+        88,
+        135,
+        201,
+        8,
+        160
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "pendingDeposit",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  101,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
+                  95,
+                  101,
+                  114,
+                  99,
+                  50,
+                  48,
+                  95,
+                  100,
+                  101,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "requestId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "rentPayer",
+          "writable": true,
+          "relations": [
+            "pendingDeposit"
+          ]
+        },
+        {
+          "name": "processedRequest",
+          "docs": [
+            "Marks `request_id` as settled; `init` fails if it already was"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  99,
+                  101,
+                  115,
+                  115,
+                  101,
+                  100,
+                  95,
+                  114,
+                  101,
+                  113,
+                  117,
+                  101,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "requestId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "failedDeposit",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  97,
+                  105,
+                  108,
+                  101,
+                  100,
+                  95,
+                  101,
+                  114,
+                  99,
+                  50,
+                  48,
+                  95,
+                  100,
+                  101,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "requestId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  111,
+                  107,
+                  101,
+                  110,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "pending_deposit.chain_id",
+                "account": "pendingErc20Deposit"
+              },
+              {
+                "kind": "account",
+                "path": "pending_deposit.erc20_address",
+                "account": "pendingErc20Deposit"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "requestId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "serializedOutput",
+          "type": "bytes"
+        },
+        {
+          "name": "signature",
+          "type": {
+            "defined": {
+              "name": "signature"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializeConfig",
+      "discriminator": [
+        208,
+        127,
+        21,
+        1,
+        194,
+        190,
+        196,
+        70
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Must be the program's upgrade authority so the config can't be front-run after deployment"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "address": "DzSqpUpL8DJ1z3wNAFnPMKRPZQL1oEZrwwSnXkA4w8Ce"
+        },
+        {
+          "name": "programData"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "mpcRootPublicKey",
+          "type": {
+            "array": [
+              "u8",
+              64
+            ]
+          }
+        },
+        {
+          "name": "keyVersion",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "migrateBtcBalance",
+      "docs": [
+        "Moves a pre-upgrade BTC balance to its per-chain balance account."
+      ],
+      "discriminator": [
+        249,
+        136,
+        92,
+        61,
+        254,
+        112,
+        252,
+        113
+      ],
+      "accounts": [
+        {
+          "name": "requester",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "legacyBalance",
+          "docs": [
+            "Balance account from before balances were keyed by chain"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  98,
+                  116,
+                  99,
+                  95,
+                  98,
+                  97,
+                  108,
+                  97,
+                  110,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "requester"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userBalance",
+          "writable": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateErc20Balance",
+      "docs": [
+        "Moves a pre-upgrade ERC20 balance to its per-chain balance account."
+      ],
+      "discriminator": [
+        39,
+        221,
+        214,
+        196,
+        226,
+        238,
+        26,
+        230
+      ],
+      "accounts": [
+        {
+          "name": "requester",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "legacyBalance",
+          "docs": [
+            "Balance account from before balances were keyed by chain"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  101,
+                  114,
+                  99,
+                  50,
+                  48,
+                  95,
+                  98,
+                  97,
+                  108,
+                  97,
+                  110,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "requester"
+              },
+              {
+                "kind": "arg",
+                "path": "erc20Address"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userBalance",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  101,
+                  114,
+                  99,
+                  50,
+                  48,
+                  95,
+                  98,
+                  97,
+                  108,
+                  97,
+                  110,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "requester"
+              },
+              {
+                "kind": "account",
+                "path": "config.legacy_erc20_chain_id",
+                "account": "vaultConfig"
+              },
+              {
+                "kind": "arg",
+                "path": "erc20Address"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  111,
+                  107,
+                  101,
+                  110,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config.legacy_erc20_chain_id",
+                "account": "vaultConfig"
+              },
+              {
+                "kind": "arg",
+                "path": "erc20Address"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "erc20Address",
+          "type": {
+            "array": [
+              "u8",
+              20
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "proposeConfigChange",
+      "docs": [
+        "Opens a config proposal. Every privileged change to the vault goes through",
+        "a proposal approved by `admin_threshold` of the admin signers, then waits",
+        "`timelock_slots` before it can be executed."
+      ],
+      "discriminator": [
+        165,
+        15,
+        231,
+        227,
+        223,
+        229,
+        247,
+        119
+      ],
+      "accounts": [
+        {
+          "name": "proposer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  95,
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config.proposal_count",
+                "account": "vaultConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "action",
+          "type": {
+            "defined": {
+              "name": "configAction"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "recordFailedWithdrawBtc",
+      "docs": [
+        "Records a failure of a cancelled withdrawal's transaction or its",
+        "cancellation; the withdrawal is refunded once both have failed."
+      ],
+      "discriminator": [
+        241,
+        178,
+        90,
+        225,
+        194,
+        163,
+        52,
+        58
+      ],
+      "accounts": [
+        {
+          "name": "pendingWithdrawal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  101,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
+                  95,
+                  98,
+                  116,
+                  99,
+                  95,
+                  119,
+                  105,
+                  116,
+                  104,
+                  100,
+                  114,
+                  97,
+                  119,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "requestId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "requestId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "serializedOutput",
+          "type": "bytes"
+        },
+        {
+          "name": "signature",
+          "type": {
+            "defined": {
+              "name": "signature"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "recordFailedWithdrawErc20",
+      "docs": [
+        "Records a failure of one of a replaced withdrawal's transactions; the",
+        "withdrawal is refunded once all of them have failed."
+      ],
+      "discriminator": [
+        243,
+        37,
+        124,
+        77,
+        167,
+        239,
+        30,
+        49
+      ],
+      "accounts": [
+        {
+          "name": "pendingWithdrawal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  101,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
+                  95,
+                  101,
+                  114,
+                  99,
+                  50,
+                  48,
+                  95,
+                  119,
+                  105,
+                  116,
+                  104,
+                  100,
+                  114,
+                  97,
+                  119,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "requestId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "requestId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "serializedOutput",
+          "type": "bytes"
+        },
+        {
+          "name": "signature",
+          "type": {
+            "defined": {
+              "name": "signature"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseStalledDepositBtc",
+      "docs": [
+        "Releases the deposit cap reservation of a deposit that got no response",
+        "within the deposit timeout; the deposit can still be claimed."
+      ],
+      "discriminator": [
+        70,
+        166,
+        67,
+        79,
+        177,
+        129,
+        228,
+        222
+      ],
+      "accounts": [
+        {
+          "name": "pendingDeposit",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  101,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
+                  95,
+                  98,
+                  116,
+                  99,
+                  95,
+                  100,
+                  101,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "requestId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "chainRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  104,
+                  97,
+                  105,
+                  110,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "requestId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseStalledDepositErc20",
+      "docs": [
+        "Releases the deposit cap reservation of a deposit that got no response",
+        "within the deposit timeout; the deposit can still be claimed."
+      ],
+      "discriminator": [
+        69,
+        146,
+        232,
+        229,
+        184,
+        102,
+        133,
+        231
+      ],
+      "accounts": [
+        {
+          "name": "pendingDeposit",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  101,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
+                  95,
+                  101,
+                  114,
+                  99,
+                  50,
+                  48,
+                  95,
+                  100,
+                  101,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "requestId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  111,
+                  107,
+                  101,
+                  110,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "pending_deposit.chain_id",
+                "account": "pendingErc20Deposit"
+              },
+              {
+                "kind": "account",
+                "path": "pending_deposit.erc20_address",
+                "account": "pendingErc20Deposit"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "requestId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "replaceWithdrawErc20",
+      "docs": [
+        "Re-signs a stuck withdrawal with higher fees and the same vault nonce."
+      ],
+      "discriminator": [
+        186,
+        181,
+        188,
+        67,
+        71,
+        89,
+        22,
+        123
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "requester",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "pendingWithdrawal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  101,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
+                  95,
+                  101,
+                  114,
+                  99,
+                  50,
+                  48,
+                  95,
+                  119,
+                  105,
+                  116,
+                  104,
+                  100,
+                  114,
+                  97,
+                  119,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "requestId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userBalance",
+          "writable": true
+        },
+        {
+          "name": "feePayer",
+          "writable": true,
+          "signer": true,
+          "optional": true
+        },
+        {
+          "name": "chainSignaturesState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "chainSignaturesProgram"
+            }
+          }
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "chainSignaturesProgram"
+            }
+          }
+        },
+        {
+          "name": "chainSignaturesProgram",
+          "address": "H5tHfpYoEnarrrzcV7sWBcZhiKMvL2aRpUYvb1ydWkwS"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "instructions",
+          "optional": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "chainRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  104,
+                  97,
+                  105,
+                  110,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "requestId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "newRequestId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "txParams",
+          "type": {
+            "defined": {
+              "name": "evmTransactionParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPause",
+      "docs": [
+        "Sets pause switches globally or for one chain. The pause guardian and admin",
+        "signers may only pause here; unpausing requires a SetPause proposal.",
+        "Claims and completions are never paused."
+      ],
+      "discriminator": [
+        63,
+        32,
+        154,
+        2,
+        56,
+        103,
+        79,
+        45
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "scope",
+          "type": {
+            "defined": {
+              "name": "pauseScope"
+            }
+          }
+        },
+        {
+          "name": "flags",
+          "type": {
+            "defined": {
+              "name": "pauseFlags"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "sweepBtc",
+      "docs": [
+        "Executes a `SweepVaultUtxos` proposal, signing a transaction that merges",
+        "vault UTXOs into one output to free slots in the vault UTXO set."
+      ],
+      "discriminator": [
+        235,
+        214,
+        75,
+        62,
+        221,
+        69,
+        52,
+        149
+      ],
+      "accounts": [
+        {
+          "name": "executor",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103,
+                  95,
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "proposalId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposer",
+          "writable": true,
+          "relations": [
+            "proposal"
+          ]
+        },
+        {
+          "name": "requester",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "pendingSweep",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  101,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
+                  95,
+                  98,
+                  116,
+                  99,
+                  95,
+                  115,
+                  119,
+                  101,
+                  101,
+                  112
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "requestId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "processedRequest",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  99,
+                  101,
+                  115,
+                  115,
+                  101,
+                  100,
+                  95,
+                  114,
+                  101,
+                  113,
+                  117,
+                  101,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "requestId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "chainRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  104,
+                  97,
+                  105,
+                  110,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultUtxoSet",
+          "writable": true
+        },
+        {
+          "name": "feePayer",
+          "writable": true,
+          "signer": true,
+          "optional": true
+        },
+        {
+          "name": "chainSignaturesState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "chainSignaturesProgram"
+            }
+          }
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "chainSignaturesProgram"
+            }
+          }
+        },
+        {
+          "name": "chainSignaturesProgram",
+          "address": "H5tHfpYoEnarrrzcV7sWBcZhiKMvL2aRpUYvb1ydWkwS"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "instructions",
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "proposalId",
+          "type": "u64"
+        },
+        {
+          "name": "requestId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "caip2Id",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "withdrawBtc",
+      "discriminator": [
+        22,
+        191,
+        230,
+        176,
+        149,
+        62,
+        236,
+        140
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "requester",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "pendingWithdrawal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  101,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
+                  95,
+                  98,
+                  116,
+                  99,
+                  95,
+                  119,
+                  105,
+                  116,
+                  104,
+                  100,
+                  114,
+                  97,
+                  119,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "requestId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "processedRequest",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  99,
+                  101,
+                  115,
+                  115,
+                  101,
+                  100,
+                  95,
+                  114,
+                  101,
+                  113,
+                  117,
+                  101,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "requestId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userBalance",
+          "writable": true
+        },
+        {
+          "name": "feePayer",
+          "writable": true,
+          "signer": true,
+          "optional": true
+        },
+        {
+          "name": "chainSignaturesState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "chainSignaturesProgram"
+            }
+          }
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "chainSignaturesProgram"
+            }
+          }
+        },
+        {
+          "name": "chainSignaturesProgram",
+          "address": "H5tHfpYoEnarrrzcV7sWBcZhiKMvL2aRpUYvb1ydWkwS"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "instructions",
+          "optional": true
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "chainRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  104,
+                  97,
+                  105,
+                  110,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "userRateLimit",
+          "writable": true
+        },
+        {
+          "name": "vaultUtxoSet",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "requestId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "recipientAddress",
+          "type": "string"
+        },
+        {
+          "name": "txParams",
+          "type": {
+            "defined": {
+              "name": "btcWithdrawParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "withdrawErc20",
+      "discriminator": [
+        19,
+        124,
+        28,
+        31,
+        171,
+        187,
+        87,
+        70
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "requester",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "pendingWithdrawal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  101,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
+                  95,
+                  101,
+                  114,
+                  99,
+                  50,
+                  48,
+                  95,
+                  119,
+                  105,
+                  116,
+                  104,
+                  100,
+                  114,
+                  97,
+                  119,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "requestId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "processedRequest",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  99,
+                  101,
+                  115,
+                  115,
+                  101,
+                  100,
+                  95,
+                  114,
+                  101,
+                  113,
+                  117,
+                  101,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "requestId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userBalance",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  101,
+                  114,
+                  99,
+                  50,
+                  48,
+                  95,
+                  98,
+                  97,
+                  108,
+                  97,
+                  110,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              },
+              {
+                "kind": "arg",
+                "path": "tx_params.chain_id"
+              },
+              {
+                "kind": "arg",
+                "path": "erc20Address"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feePayer",
+          "writable": true,
+          "signer": true,
+          "optional": true
+        },
+        {
+          "name": "chainSignaturesState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "chainSignaturesProgram"
+            }
+          }
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "chainSignaturesProgram"
+            }
+          }
+        },
+        {
+          "name": "chainSignaturesProgram",
+          "address": "H5tHfpYoEnarrrzcV7sWBcZhiKMvL2aRpUYvb1ydWkwS"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "instructions",
+          "optional": true
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "chainRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  104,
+                  97,
+                  105,
+                  110,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  111,
+                  107,
+                  101,
+                  110,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tx_params.chain_id"
+              },
+              {
+                "kind": "arg",
+                "path": "erc20Address"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userRateLimit",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  101,
+                  114,
+                  99,
+                  50,
+                  48,
+                  95,
+                  114,
+                  97,
+                  116,
+                  101,
+                  95,
+                  108,
+                  105,
+                  109,
+                  105,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              },
+              {
+                "kind": "arg",
+                "path": "tx_params.chain_id"
+              },
+              {
+                "kind": "arg",
+                "path": "erc20Address"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultNonce",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  118,
+                  109,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  110,
+                  111,
+                  110,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tx_params.chain_id"
+              },
+              {
+                "kind": "account",
+                "path": "config.active_key_version",
+                "account": "vaultConfig"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "requestId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "erc20Address",
+          "type": {
+            "array": [
+              "u8",
+              20
+            ]
+          }
+        },
+        {
+          "name": "amount",
+          "type": "u128"
+        },
+        {
+          "name": "recipientAddress",
+          "type": {
+            "array": [
+              "u8",
+              20
+            ]
+          }
+        },
+        {
+          "name": "txParams",
+          "type": {
+            "defined": {
+              "name": "evmTransactionParams"
+            }
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "chainRegistry",
+      "discriminator": [
+        119,
+        7,
+        172,
+        219,
+        63,
+        243,
+        194,
+        231
+      ]
+    },
+    {
+      "name": "configProposal",
+      "discriminator": [
+        159,
+        239,
+        163,
+        63,
+        154,
+        191,
+        78,
+        98
+      ]
+    },
+    {
+      "name": "evmVaultNonce",
+      "discriminator": [
+        112,
+        47,
+        10,
+        142,
+        134,
+        182,
+        120,
+        112
+      ]
+    },
+    {
+      "name": "failedBtcDeposit",
+      "discriminator": [
+        251,
+        65,
+        150,
+        43,
+        134,
+        27,
+        40,
+        67
+      ]
+    },
+    {
+      "name": "failedErc20Deposit",
+      "discriminator": [
+        224,
+        7,
+        86,
+        232,
+        153,
+        182,
+        204,
+        81
+      ]
+    },
+    {
+      "name": "pendingBtcDeposit",
+      "discriminator": [
+        210,
+        198,
+        216,
+        218,
+        80,
+        77,
+        38,
+        139
+      ]
+    },
+    {
+      "name": "pendingBtcSweep",
+      "discriminator": [
+        84,
+        173,
+        0,
+        43,
+        220,
+        185,
+        185,
+        100
+      ]
+    },
+    {
+      "name": "pendingBtcWithdrawal",
+      "discriminator": [
+        215,
+        0,
+        70,
+        221,
+        85,
+        240,
+        166,
+        85
+      ]
+    },
+    {
+      "name": "pendingErc20Deposit",
+      "discriminator": [
+        214,
+        238,
+        68,
+        242,
+        98,
+        102,
+        251,
+        178
+      ]
+    },
+    {
+      "name": "pendingErc20Withdrawal",
+      "discriminator": [
+        33,
+        60,
+        7,
+        188,
+        11,
+        40,
+        41,
+        150
+      ]
+    },
+    {
+      "name": "processedRequest",
+      "discriminator": [
+        20,
+        124,
+        158,
+        247,
+        78,
+        104,
+        105,
+        189
+      ]
+    },
+    {
+      "name": "tokenRegistry",
+      "discriminator": [
+        227,
+        255,
+        152,
+        118,
+        84,
+        200,
+        145,
+        120
+      ]
+    },
+    {
+      "name": "userBtcBalance",
+      "discriminator": [
+        15,
+        220,
+        25,
+        120,
+        133,
+        118,
+        246,
+        20
+      ]
+    },
+    {
+      "name": "userErc20Balance",
+      "discriminator": [
+        29,
+        16,
+        203,
+        40,
+        208,
+        43,
+        221,
+        11
+      ]
+    },
+    {
+      "name": "userRateLimit",
+      "discriminator": [
+        156,
+        245,
+        156,
+        195,
+        8,
+        43,
+        139,
+        184
+      ]
+    },
+    {
+      "name": "vaultConfig",
+      "discriminator": [
+        99,
+        86,
+        43,
+        216,
+        184,
+        102,
+        119,
+        77
+      ]
+    },
+    {
+      "name": "vaultUtxoSet",
+      "discriminator": [
+        95,
+        117,
+        186,
+        54,
+        105,
+        62,
+        56,
+        45
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "configChangeCancelled",
+      "discriminator": [
+        66,
+        48,
+        129,
+        141,
+        104,
+        33,
+        113,
+        2
+      ]
+    },
+    {
+      "name": "configChangeExecuted",
+      "discriminator": [
+        247,
+        53,
+        112,
+        244,
+        231,
+        218,
+        42,
+        95
+      ]
+    },
+    {
+      "name": "configChangeProposed",
+      "discriminator": [
+        57,
+        212,
+        96,
+        3,
+        9,
+        29,
+        225,
+        198
+      ]
+    },
+    {
+      "name": "configChangeQueued",
+      "discriminator": [
+        201,
+        60,
+        41,
+        180,
+        178,
+        5,
+        147,
+        117
+      ]
+    },
+    {
+      "name": "evmNativeTransferRequested",
+      "discriminator": [
+        89,
+        90,
+        159,
+        159,
+        148,
+        22,
+        5,
+        171
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "serializationError",
+      "msg": "Serialization error"
+    },
+    {
+      "code": 6001,
+      "name": "functionNotFound",
+      "msg": "Function not found in ABI"
+    },
+    {
+      "code": 6002,
+      "name": "invalidRequestId",
+      "msg": "Invalid request ID"
+    },
+    {
+      "code": 6003,
+      "name": "invalidSignature",
       "msg": "Invalid signature"
     },
     {
-      "code": 6004,
-      "name": "transferFailed",
-      "msg": "Transfer failed"
+      "code": 6004,
+      "name": "transferFailed",
+      "msg": "Transfer failed"
+    },
+    {
+      "code": 6005,
+      "name": "invalidOutput",
+      "msg": "Invalid output format"
+    },
+    {
+      "code": 6006,
+      "name": "overflow",
+      "msg": "Arithmetic overflow"
+    },
+    {
+      "code": 6007,
+      "name": "invalidAddress",
+      "msg": "Invalid address"
+    },
+    {
+      "code": 6008,
+      "name": "insufficientBalance",
+      "msg": "Insufficient balance"
+    },
+    {
+      "code": 6009,
+      "name": "underflow",
+      "msg": "Underflow error"
+    },
+    {
+      "code": 6010,
+      "name": "insufficientInputs",
+      "msg": "Provided inputs do not cover requested amount + fee"
+    },
+    {
+      "code": 6011,
+      "name": "unauthorized",
+      "msg": "Signer is not authorized to perform this action"
+    },
+    {
+      "code": 6012,
+      "name": "invalidPublicKey",
+      "msg": "Public key is not a valid secp256k1 point"
+    },
+    {
+      "code": 6013,
+      "name": "unknownKeyVersion",
+      "msg": "MPC key version is not registered"
+    },
+    {
+      "code": 6014,
+      "name": "keyVersionAlreadyExists",
+      "msg": "MPC key version is already registered"
+    },
+    {
+      "code": 6015,
+      "name": "tooManyKeyVersions",
+      "msg": "Maximum number of MPC key versions reached"
+    },
+    {
+      "code": 6016,
+      "name": "depositsPaused",
+      "msg": "Deposits are paused"
+    },
+    {
+      "code": 6017,
+      "name": "withdrawalsPaused",
+      "msg": "Withdrawals are paused"
+    },
+    {
+      "code": 6018,
+      "name": "tooManyPausedChains",
+      "msg": "Maximum number of per-chain pause entries reached"
+    },
+    {
+      "code": 6019,
+      "name": "invalidCaip2Id",
+      "msg": "CAIP-2 ID is malformed or too long"
+    },
+    {
+      "code": 6020,
+      "name": "invalidAdminSigners",
+      "msg": "Admin signer set is empty, too large or contains duplicates"
+    },
+    {
+      "code": 6021,
+      "name": "invalidAdminThreshold",
+      "msg": "Admin threshold must be between 1 and the number of signers"
+    },
+    {
+      "code": 6022,
+      "name": "alreadyApproved",
+      "msg": "Proposal already approved by this signer"
+    },
+    {
+      "code": 6023,
+      "name": "thresholdNotMet",
+      "msg": "Proposal has not reached the approval threshold"
+    },
+    {
+      "code": 6024,
+      "name": "timelockNotElapsed",
+      "msg": "Proposal timelock has not elapsed"
+    },
+    {
+      "code": 6025,
+      "name": "invalidTimelock",
+      "msg": "Timelock exceeds the maximum delay"
+    },
+    {
+      "code": 6026,
+      "name": "invalidProposalAction",
+      "msg": "Proposal action must be executed with its dedicated instruction"
+    },
+    {
+      "code": 6027,
+      "name": "tokenDisabled",
+      "msg": "Token is not enabled"
+    },
+    {
+      "code": 6028,
+      "name": "amountBelowMinimum",
+      "msg": "Amount is below the token minimum"
+    },
+    {
+      "code": 6029,
+      "name": "amountAboveMaximum",
+      "msg": "Amount is above the token maximum"
+    },
+    {
+      "code": 6030,
+      "name": "invalidTokenSettings",
+      "msg": "Token minimum exceeds its maximum"
+    },
+    {
+      "code": 6031,
+      "name": "unsupportedChain",
+      "msg": "Chain is not in the chain registry"
+    },
+    {
+      "code": 6032,
+      "name": "chainDisabled",
+      "msg": "Chain is disabled"
+    },
+    {
+      "code": 6033,
+      "name": "tooManyChains",
+      "msg": "Maximum number of registered chains reached"
+    },
+    {
+      "code": 6034,
+      "name": "migrationDisabled",
+      "msg": "Legacy balance migration is disabled"
+    },
+    {
+      "code": 6035,
+      "name": "userRateLimitExceeded",
+      "msg": "Withdrawal exceeds the user's rate limit for this asset"
+    },
+    {
+      "code": 6036,
+      "name": "assetRateLimitExceeded",
+      "msg": "Withdrawal exceeds the rate limit for this asset"
+    },
+    {
+      "code": 6037,
+      "name": "globalRateLimitExceeded",
+      "msg": "Withdrawal exceeds the global rate limit"
+    },
+    {
+      "code": 6038,
+      "name": "tooManyRateLimitOverrides",
+      "msg": "Maximum number of rate limit overrides reached"
+    },
+    {
+      "code": 6039,
+      "name": "depositCapExceeded",
+      "msg": "Deposit would exceed the asset's deposit cap"
+    },
+    {
+      "code": 6040,
+      "name": "liabilitiesUnderflow",
+      "msg": "Debit exceeds the asset's tracked liabilities"
+    },
+    {
+      "code": 6041,
+      "name": "invalidFee",
+      "msg": "Fee exceeds 10000 basis points"
+    },
+    {
+      "code": 6042,
+      "name": "dustOutput",
+      "msg": "Output is below the P2WPKH dust limit"
+    },
+    {
+      "code": 6043,
+      "name": "networkFeeTooHigh",
+      "msg": "Network fee exceeds the chain's bound"
+    },
+    {
+      "code": 6044,
+      "name": "requestAlreadyProcessed",
+      "msg": "Request ID has already been processed"
+    },
+    {
+      "code": 6045,
+      "name": "nonCanonicalSignature",
+      "msg": "Signature r or s is out of range, or s is not in low form"
+    },
+    {
+      "code": 6046,
+      "name": "invalidRecoveryId",
+      "msg": "Signature recovery ID is not accepted"
+    },
+    {
+      "code": 6047,
+      "name": "withdrawalCancellationDisabled",
+      "msg": "Withdrawal cancellation is disabled"
+    },
+    {
+      "code": 6048,
+      "name": "withdrawalNotTimedOut",
+      "msg": "Withdrawal has not timed out yet"
+    },
+    {
+      "code": 6049,
+      "name": "depositNotFailed",
+      "msg": "Response does not report a failed transfer"
+    },
+    {
+      "code": 6050,
+      "name": "nonceMismatch",
+      "msg": "Transaction nonce does not match the vault nonce"
+    },
+    {
+      "code": 6051,
+      "name": "replacementUnderpriced",
+      "msg": "Replacement fees must exceed those of the transaction it replaces"
+    },
+    {
+      "code": 6052,
+      "name": "tooManyReplacements",
+      "msg": "Maximum number of withdrawal replacements reached"
+    },
+    {
+      "code": 6053,
+      "name": "withdrawalNotFailed",
+      "msg": "Response does not report a failed withdrawal"
+    },
+    {
+      "code": 6054,
+      "name": "withdrawalOutcomePending",
+      "msg": "Another transaction of this withdrawal may still execute"
+    },
+    {
+      "code": 6055,
+      "name": "gasLimitTooHigh",
+      "msg": "Gas limit exceeds the chain's bound"
+    },
+    {
+      "code": 6056,
+      "name": "maxFeePerGasTooHigh",
+      "msg": "Max fee per gas exceeds the chain's bound"
+    },
+    {
+      "code": 6057,
+      "name": "priorityFeeTooHigh",
+      "msg": "Priority fee exceeds the chain's bound or the max fee per gas"
+    },
+    {
+      "code": 6058,
+      "name": "nativeValueNotAllowed",
+      "msg": "ERC20 transactions must not carry native value"
+    },
+    {
+      "code": 6059,
+      "name": "utxoNotRegistered",
+      "msg": "UTXO is not in the vault UTXO set"
+    },
+    {
+      "code": 6060,
+      "name": "utxoReserved",
+      "msg": "UTXO is reserved by a pending withdrawal"
+    },
+    {
+      "code": 6061,
+      "name": "duplicateUtxo",
+      "msg": "UTXO is already in the vault UTXO set"
+    },
+    {
+      "code": 6062,
+      "name": "vaultUtxoSetFull",
+      "msg": "Vault UTXO set is full"
+    },
+    {
+      "code": 6063,
+      "name": "insufficientVaultUtxos",
+      "msg": "Vault UTXOs do not cover requested amount + fee"
+    },
+    {
+      "code": 6064,
+      "name": "tooManyWithdrawalInputs",
+      "msg": "Withdrawal needs more vault UTXOs than one transaction may spend"
+    },
+    {
+      "code": 6065,
+      "name": "feeRateTooLow",
+      "msg": "Fee rate is below the minimum relay fee rate"
+    },
+    {
+      "code": 6066,
+      "name": "chainHasLiabilities",
+      "msg": "Chain still has liabilities or uncollected fees"
+    },
+    {
+      "code": 6067,
+      "name": "withdrawalAlreadyCancelled",
+      "msg": "Withdrawal already has a signed cancellation"
+    },
+    {
+      "code": 6068,
+      "name": "invalidLockTime",
+      "msg": "Lock time must be a block height"
+    },
+    {
+      "code": 6069,
+      "name": "insufficientAccruedFees",
+      "msg": "Sweep fee exceeds the chain's accrued fees"
+    },
+    {
+      "code": 6070,
+      "name": "depositReleaseDisabled",
+      "msg": "Releasing stalled deposits is disabled"
+    },
+    {
+      "code": 6071,
+      "name": "depositNotTimedOut",
+      "msg": "Deposit has not timed out yet"
+    },
+    {
+      "code": 6072,
+      "name": "depositAlreadyReleased",
+      "msg": "Deposit reservation was already released"
+    }
+  ],
+  "types": [
+    {
+      "name": "affinePoint",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "x",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "y",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "btcDepositParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lockTime",
+            "type": "u32"
+          },
+          {
+            "name": "caip2Id",
+            "type": "string"
+          },
+          {
+            "name": "fee",
+            "docs": [
+              "Network fee paid from the requester's inputs, bounded by the chain's",
+              "`max_deposit_network_fee`"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "btcInput",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "txid",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "vout",
+            "type": "u32"
+          },
+          {
+            "name": "scriptPubkey",
+            "type": "bytes"
+          },
+          {
+            "name": "value",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "btcOutpoint",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "txid",
+            "docs": [
+              "Transaction ID in explorer byte order, as in `BtcInput`"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "vout",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "btcWithdrawParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lockTime",
+            "type": "u32"
+          },
+          {
+            "name": "caip2Id",
+            "type": "string"
+          },
+          {
+            "name": "recipientScriptPubkey",
+            "type": "bytes"
+          },
+          {
+            "name": "feeRate",
+            "docs": [
+              "Network fee rate in sat/vB, at least `MIN_WITHDRAWAL_FEE_RATE`"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "chainEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "caip2Id",
+            "type": "string"
+          },
+          {
+            "name": "settings",
+            "type": {
+              "defined": {
+                "name": "chainSettings"
+              }
+            }
+          },
+          {
+            "name": "withdrawalWindow",
+            "docs": [
+              "Usage of `settings.withdrawal_rate_limit`"
+            ],
+            "type": {
+              "defined": {
+                "name": "rateLimitWindow"
+              }
+            }
+          },
+          {
+            "name": "liabilities",
+            "docs": [
+              "Liabilities in the chain's native asset"
+            ],
+            "type": {
+              "defined": {
+                "name": "liabilities"
+              }
+            }
+          },
+          {
+            "name": "accruedFees",
+            "docs": [
+              "Native asset protocol fees not yet moved to the treasury balance"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "tokenCount",
+            "docs": [
+              "ERC20 tokens registered on the chain, each tracking its own liabilities",
+              "in its `TokenRegistry` entry"
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "chainPause",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "caip2Id",
+            "type": "string"
+          },
+          {
+            "name": "flags",
+            "type": {
+              "defined": {
+                "name": "pauseFlags"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "chainRegistry",
+      "docs": [
+        "Chains the vault is allowed to request signatures for, keyed by CAIP-2 ID."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chains",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "chainEntry"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "chainSettings",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "enabled",
+            "docs": [
+              "Deposits and withdrawals are rejected while disabled"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "withdrawalRateLimit",
+            "docs": [
+              "Limit on withdrawals of the chain's native asset across all users"
+            ],
+            "type": {
+              "defined": {
+                "name": "rateLimitSettings"
+              }
+            }
+          },
+          {
+            "name": "userWithdrawalRateLimit",
+            "docs": [
+              "Limit on withdrawals of the chain's native asset by a single user"
+            ],
+            "type": {
+              "defined": {
+                "name": "rateLimitSettings"
+              }
+            }
+          },
+          {
+            "name": "depositCap",
+            "docs": [
+              "Cap on the native asset's `liabilities`, 0 for no cap"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "fees",
+            "docs": [
+              "Protocol fees on the native asset"
+            ],
+            "type": {
+              "defined": {
+                "name": "feeSettings"
+              }
+            }
+          },
+          {
+            "name": "maxDepositNetworkFee",
+            "docs": [
+              "Largest network fee a BTC deposit may spend from the requester's inputs.",
+              "There is no default: while it is 0 every deposit paying a fee is rejected"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxGasLimit",
+            "docs": [
+              "Bounds on the gas of EVM withdrawals signed from the vault address"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "maxFeePerGas",
+            "type": "u128"
+          },
+          {
+            "name": "maxPriorityFeePerGas",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "configAction",
+      "docs": [
+        "A privileged change to the vault configuration. Every admin operation goes",
+        "through a `ConfigProposal` carrying one of these actions."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "addKeyVersion",
+            "fields": [
+              {
+                "name": "keyVersion",
+                "type": "u32"
+              },
+              {
+                "name": "mpcRootPublicKey",
+                "type": {
+                  "array": [
+                    "u8",
+                    64
+                  ]
+                }
+              }
+            ]
+          },
+          {
+            "name": "setActiveKeyVersion",
+            "fields": [
+              {
+                "name": "keyVersion",
+                "type": "u32"
+              }
+            ]
+          },
+          {
+            "name": "setPauseGuardian",
+            "fields": [
+              {
+                "name": "pauseGuardian",
+                "type": "pubkey"
+              }
+            ]
+          },
+          {
+            "name": "setTreasury",
+            "fields": [
+              {
+                "name": "treasury",
+                "type": "pubkey"
+              }
+            ]
+          },
+          {
+            "name": "setPause",
+            "fields": [
+              {
+                "name": "scope",
+                "type": {
+                  "defined": {
+                    "name": "pauseScope"
+                  }
+                }
+              },
+              {
+                "name": "flags",
+                "type": {
+                  "defined": {
+                    "name": "pauseFlags"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "setAdminSigners",
+            "fields": [
+              {
+                "name": "adminSigners",
+                "type": {
+                  "vec": "pubkey"
+                }
+              },
+              {
+                "name": "adminThreshold",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "setTimelock",
+            "fields": [
+              {
+                "name": "timelockSlots",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "setLegacyErc20ChainId",
+            "fields": [
+              {
+                "name": "chainId",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "setLegacyBtcCaip2Id",
+            "fields": [
+              {
+                "name": "caip2Id",
+                "type": "string"
+              }
+            ]
+          },
+          {
+            "name": "setGlobalWithdrawalRateLimit",
+            "fields": [
+              {
+                "name": "settings",
+                "type": {
+                  "defined": {
+                    "name": "rateLimitSettings"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "setRateLimitOverride",
+            "fields": [
+              {
+                "name": "user",
+                "type": "pubkey"
+              },
+              {
+                "name": "expiresSlot",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "setAllowHighRecoveryIds",
+            "fields": [
+              {
+                "name": "allowed",
+                "type": "bool"
+              }
+            ]
+          },
+          {
+            "name": "setWithdrawalTimeout",
+            "fields": [
+              {
+                "name": "timeoutSlots",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "setCrankBounty",
+            "fields": [
+              {
+                "name": "lamports",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "setEvmVaultNonce",
+            "fields": [
+              {
+                "name": "chainId",
+                "type": "u64"
+              },
+              {
+                "name": "keyVersion",
+                "type": "u32"
+              },
+              {
+                "name": "nextNonce",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "transferEvmNative",
+            "fields": [
+              {
+                "name": "chainId",
+                "type": "u64"
+              },
+              {
+                "name": "recipient",
+                "type": {
+                  "array": [
+                    "u8",
+                    20
+                  ]
+                }
+              },
+              {
+                "name": "value",
+                "type": "u128"
+              }
+            ]
+          },
+          {
+            "name": "cancelWithdrawal",
+            "fields": [
+              {
+                "name": "requestId",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                }
+              }
+            ]
+          },
+          {
+            "name": "setToken",
+            "fields": [
+              {
+                "name": "chainId",
+                "type": "u64"
+              },
+              {
+                "name": "erc20Address",
+                "type": {
+                  "array": [
+                    "u8",
+                    20
+                  ]
+                }
+              },
+              {
+                "name": "settings",
+                "type": {
+                  "defined": {
+                    "name": "tokenSettings"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "setChain",
+            "fields": [
+              {
+                "name": "caip2Id",
+                "type": "string"
+              },
+              {
+                "name": "settings",
+                "type": {
+                  "defined": {
+                    "name": "chainSettings"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "removeChain",
+            "fields": [
+              {
+                "name": "caip2Id",
+                "type": "string"
+              }
+            ]
+          },
+          {
+            "name": "creditChainLiabilities",
+            "fields": [
+              {
+                "name": "caip2Id",
+                "type": "string"
+              },
+              {
+                "name": "amount",
+                "type": "u128"
+              }
+            ]
+          },
+          {
+            "name": "addVaultUtxos",
+            "fields": [
+              {
+                "name": "caip2Id",
+                "type": "string"
+              },
+              {
+                "name": "utxos",
+                "type": {
+                  "vec": {
+                    "defined": {
+                      "name": "vaultUtxo"
+                    }
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "removeVaultUtxos",
+            "fields": [
+              {
+                "name": "caip2Id",
+                "type": "string"
+              },
+              {
+                "name": "outpoints",
+                "type": {
+                  "vec": {
+                    "defined": {
+                      "name": "btcOutpoint"
+                    }
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "sweepVaultUtxos",
+            "fields": [
+              {
+                "name": "caip2Id",
+                "type": "string"
+              },
+              {
+                "name": "keyVersion",
+                "type": "u32"
+              },
+              {
+                "name": "feeRate",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "setDepositTimeout",
+            "fields": [
+              {
+                "name": "timeoutSlots",
+                "type": "u64"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "configChangeCancelled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposalId",
+            "type": "u64"
+          },
+          {
+            "name": "cancelledBy",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "configChangeExecuted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposalId",
+            "type": "u64"
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": {
+                "name": "configAction"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "configChangeProposed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposalId",
+            "type": "u64"
+          },
+          {
+            "name": "proposer",
+            "type": "pubkey"
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": {
+                "name": "configAction"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "configChangeQueued",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposalId",
+            "type": "u64"
+          },
+          {
+            "name": "etaSlot",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "configProposal",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "id",
+            "type": "u64"
+          },
+          {
+            "name": "proposer",
+            "type": "pubkey"
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": {
+                "name": "configAction"
+              }
+            }
+          },
+          {
+            "name": "approvals",
+            "docs": [
+              "Admin signers that approved the proposal"
+            ],
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "etaSlot",
+            "docs": [
+              "Slot from which the proposal can be executed, 0 until the threshold is reached"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "evmNativeTransferRequested",
+      "docs": [
+        "Native value sent from the EVM vault address, outside any ERC20 flow"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposalId",
+            "type": "u64"
+          },
+          {
+            "name": "chainId",
+            "type": "u64"
+          },
+          {
+            "name": "recipient",
+            "type": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          },
+          {
+            "name": "value",
+            "type": "u128"
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "requestId",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "evmTransactionParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "value",
+            "type": "u128"
+          },
+          {
+            "name": "gasLimit",
+            "type": "u128"
+          },
+          {
+            "name": "maxFeePerGas",
+            "type": "u128"
+          },
+          {
+            "name": "maxPriorityFeePerGas",
+            "type": "u128"
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "chainId",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "evmVaultNonce",
+      "docs": [
+        "Next nonce of the vault address on an EVM chain, seeded by",
+        "`[b\"evm_vault_nonce\", chain_id, key_version]`. The vault address is derived",
+        "from the MPC key, so each key version has its own nonce, starting at 0.",
+        "Withdrawals take their nonce from here rather than from the caller; a",
+        "SetEvmVaultNonce proposal re-syncs it."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": "u64"
+          },
+          {
+            "name": "keyVersion",
+            "type": "u32"
+          },
+          {
+            "name": "nextNonce",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6005,
-      "name": "invalidOutput",
-      "msg": "Invalid output format"
+      "name": "failedBtcDeposit",
+      "docs": [
+        "Receipt for a deposit the MPC network reported as failed, seeded by",
+        "`[b\"failed_btc_deposit\", request_id]`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "requester",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "caip2Id",
+            "type": "string"
+          },
+          {
+            "name": "reason",
+            "docs": [
+              "Failure reported in the signed response"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "failedSlot",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6006,
-      "name": "overflow",
-      "msg": "Arithmetic overflow"
+      "name": "failedErc20Deposit",
+      "docs": [
+        "Receipt for a deposit the MPC network reported as failed, seeded by",
+        "`[b\"failed_erc20_deposit\", request_id]`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "requester",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u128"
+          },
+          {
+            "name": "erc20Address",
+            "type": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          },
+          {
+            "name": "chainId",
+            "type": "u64"
+          },
+          {
+            "name": "reason",
+            "docs": [
+              "Failure reported in the signed response"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "failedSlot",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6007,
-      "name": "invalidAddress",
-      "msg": "Invalid address"
+      "name": "feeSettings",
+      "docs": [
+        "Protocol fees charged on one asset, in basis points of the transferred amount"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "depositFeeBps",
+            "docs": [
+              "Deducted from a deposit when it is claimed"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "withdrawalFeeBps",
+            "docs": [
+              "Debited on top of a withdrawal and returned if it is refunded"
+            ],
+            "type": "u16"
+          }
+        ]
+      }
     },
     {
-      "code": 6008,
-      "name": "insufficientBalance",
-      "msg": "Insufficient balance"
+      "name": "liabilities",
+      "docs": [
+        "Outstanding obligations of the vault for one asset, checked against the",
+        "asset's deposit cap."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "credited",
+            "docs": [
+              "Balances credited to users"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "pendingDeposits",
+            "docs": [
+              "Deposits requested but not yet claimed"
+            ],
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "mpcKeyVersion",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u32"
+          },
+          {
+            "name": "mpcRootPublicKey",
+            "docs": [
+              "The full 64-byte uncompressed secp256k1 public key (without 0x04 prefix)"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "pauseFlags",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "deposits",
+            "type": "bool"
+          },
+          {
+            "name": "withdrawals",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pauseScope",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "global"
+          },
+          {
+            "name": "chain",
+            "fields": [
+              {
+                "name": "caip2Id",
+                "type": "string"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "pendingBtcDeposit",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "requester",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "caip2Id",
+            "type": "string"
+          },
+          {
+            "name": "path",
+            "type": "string"
+          },
+          {
+            "name": "requestId",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "keyVersion",
+            "docs": [
+              "MPC key version the request was signed under"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "protocolFee",
+            "docs": [
+              "Protocol fee charged on `amount`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rentPayer",
+            "docs": [
+              "Paid the account's rent and gets it back when the account is closed"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "txid",
+            "docs": [
+              "Deposit transaction ID in explorer byte order; its output 0 pays the vault"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "createdSlot",
+            "docs": [
+              "Slot the deposit was requested in, checked against the deposit timeout"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reservationReleased",
+            "docs": [
+              "Set once the deposit's reservation against the deposit cap was released",
+              "by `release_stalled_deposit_btc`"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pendingBtcSweep",
+      "docs": [
+        "A governed transaction merging vault UTXOs into one output back to the vault,",
+        "seeded by `[b\"pending_btc_sweep\", request_id]`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "caip2Id",
+            "type": "string"
+          },
+          {
+            "name": "requestId",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "keyVersion",
+            "docs": [
+              "MPC key version the request was signed under"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "rentPayer",
+            "docs": [
+              "Paid the account's rent and gets it back when the account is closed"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "outputKeyVersion",
+            "docs": [
+              "MPC key version whose vault script output 0 pays to, the active one when",
+              "the sweep was signed"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "txid",
+            "docs": [
+              "Sweep transaction ID in explorer byte order; its output 0 pays the vault"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "output",
+            "docs": [
+              "Value of output 0"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "fee",
+            "docs": [
+              "Network fee, taken from the chain's accrued fees"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "inputs",
+            "docs": [
+              "Vault UTXOs spent by the transaction, in input order"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "btcOutpoint"
+                }
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6009,
-      "name": "underflow",
-      "msg": "Underflow error"
+      "name": "pendingBtcWithdrawal",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "requester",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "fee",
+            "type": "u64"
+          },
+          {
+            "name": "caip2Id",
+            "type": "string"
+          },
+          {
+            "name": "recipientAddress",
+            "type": "string"
+          },
+          {
+            "name": "path",
+            "type": "string"
+          },
+          {
+            "name": "requestId",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "keyVersion",
+            "docs": [
+              "MPC key version the request was signed under"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "protocolFee",
+            "docs": [
+              "Protocol fee charged on `amount`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "createdSlot",
+            "docs": [
+              "Slot the withdrawal was requested in, checked against the withdrawal timeout"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rentPayer",
+            "docs": [
+              "Paid the account's rent and gets it back when the account is closed"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "txid",
+            "docs": [
+              "Withdrawal transaction ID in explorer byte order"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "change",
+            "docs": [
+              "Value of the change output (output 1) returned to the vault, 0 if none"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "inputs",
+            "docs": [
+              "Vault UTXOs spent by the transaction, in input order"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "btcOutpoint"
+                }
+              }
+            }
+          },
+          {
+            "name": "cancelRequestId",
+            "docs": [
+              "Request ID of the transaction signed by `cancel_withdraw_btc`, which spends",
+              "`inputs` back to the vault. Its success refunds the withdrawal, since the",
+              "withdrawal transaction can no longer confirm"
+            ],
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "cancelTxid",
+            "docs": [
+              "Cancellation transaction ID in explorer byte order; its output 0 pays the vault"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "cancelFee",
+            "docs": [
+              "Network fee of the cancellation, taken from the refund"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "cancelOutput",
+            "docs": [
+              "Value of the cancellation's output returned to the vault"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "failedRequestIds",
+            "docs": [
+              "Signed transactions the MPC network reported as failed"
+            ],
+            "type": {
+              "vec": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6010,
-      "name": "vaultOutputNotFound",
-      "msg": "No vault outputs found in transaction"
+      "name": "pendingErc20Deposit",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "requester",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u128"
+          },
+          {
+            "name": "erc20Address",
+            "type": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          },
+          {
+            "name": "chainId",
+            "type": "u64"
+          },
+          {
+            "name": "path",
+            "type": "string"
+          },
+          {
+            "name": "requestId",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "keyVersion",
+            "docs": [
+              "MPC key version the request was signed under"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "protocolFee",
+            "docs": [
+              "Protocol fee charged on `amount`"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "rentPayer",
+            "docs": [
+              "Paid the account's rent and gets it back when the account is closed"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "createdSlot",
+            "docs": [
+              "Slot the deposit was requested in, checked against the deposit timeout"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reservationReleased",
+            "docs": [
+              "Set once the deposit's reservation against the deposit cap was released",
+              "by `release_stalled_deposit_erc20`"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "code": 6011,
-      "name": "insufficientInputs",
-      "msg": "Provided inputs do not cover requested amount + fee"
-    }
-  ],
-  "types": [
-    {
-      "name": "affinePoint",
+      "name": "pendingErc20Withdrawal",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "x",
+            "name": "requester",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u128"
+          },
+          {
+            "name": "erc20Address",
             "type": {
               "array": [
                 "u8",
-                32
+                20
               ]
             }
           },
           {
-            "name": "y",
+            "name": "chainId",
+            "type": "u64"
+          },
+          {
+            "name": "recipientAddress",
+            "type": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          },
+          {
+            "name": "path",
+            "type": "string"
+          },
+          {
+            "name": "requestId",
             "type": {
               "array": [
                 "u8",
                 32
               ]
             }
+          },
+          {
+            "name": "keyVersion",
+            "docs": [
+              "MPC key version the request was signed under"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "protocolFee",
+            "docs": [
+              "Protocol fee charged on `amount`"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "gasFee",
+            "docs": [
+              "Gas fee charged in token units, kept by the vault even if the transfer fails"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "gasCost",
+            "docs": [
+              "Most gas the signed transactions may spend, `gas_limit * max_fee_per_gas`",
+              "in wei. `gas_fee` was charged for this much"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "createdSlot",
+            "docs": [
+              "Slot the withdrawal was requested in, checked against the withdrawal timeout"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rentPayer",
+            "docs": [
+              "Paid the account's rent and gets it back when the account is closed"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "nonce",
+            "docs": [
+              "Vault nonce assigned to the transaction and reused by its replacements"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxFeePerGas",
+            "docs": [
+              "Fees of the most recently signed transaction"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "maxPriorityFeePerGas",
+            "type": "u128"
+          },
+          {
+            "name": "replacementRequestIds",
+            "docs": [
+              "Request IDs of replacement transactions, which settle the withdrawal like `request_id`"
+            ],
+            "type": {
+              "vec": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "failedRequestIds",
+            "docs": [
+              "Signed transactions the MPC network reported as failed"
+            ],
+            "type": {
+              "vec": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "cancelRequestId",
+            "docs": [
+              "Request ID of the zero-value self-transfer signed by `cancel_withdraw_erc20`.",
+              "Its success refunds the withdrawal, since it consumed the shared nonce"
+            ],
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
           }
         ]
       }
     },
     {
-      "name": "btcDepositParams",
+      "name": "processedRequest",
+      "docs": [
+        "Tombstone for a settled request ID, seeded by `[b\"processed_request\", request_id]`.",
+        "",
+        "Pending accounts are closed once a request settles, which would let the same",
+        "request ID be initiated and settled again. Tombstones are never closed, so",
+        "`deposit_*`/`withdraw_*` refuse a request ID that has one and the instructions",
+        "settling a request (`claim_*`, `complete_withdraw_*`, `cancel_withdraw_*`,",
+        "`finalize_failed_deposit_*`) can create it only once."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": []
+      }
+    },
+    {
+      "name": "rateLimitOverride",
+      "docs": [
+        "Lets `user` withdraw past every rate limit until `expires_slot`"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "lockTime",
-            "type": "u32"
+            "name": "user",
+            "type": "pubkey"
           },
           {
-            "name": "caip2Id",
-            "type": "string"
+            "name": "expiresSlot",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "rateLimitSettings",
+      "docs": [
+        "A limit on how much may be consumed within `window_slots`. A zero limit or",
+        "window disables it."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
+            "type": "u128"
           },
           {
-            "name": "vaultScriptPubkey",
-            "type": "bytes"
+            "name": "windowSlots",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "btcInput",
+      "name": "rateLimitWindow",
+      "docs": [
+        "Usage tracked against a `RateLimitSettings`.",
+        "",
+        "Slots are split into buckets of `window_slots`. Usage over the rolling window",
+        "ending at the current slot is estimated as the current bucket plus the share",
+        "of the previous bucket that still overlaps the window."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "txid",
+            "name": "windowStartSlot",
+            "type": "u64"
+          },
+          {
+            "name": "previousUsed",
+            "type": "u128"
+          },
+          {
+            "name": "currentUsed",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "signature",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bigR",
+            "type": {
+              "defined": {
+                "name": "affinePoint"
+              }
+            }
+          },
+          {
+            "name": "s",
             "type": {
               "array": [
                 "u8",
@@ -1831,315 +8632,445 @@ export type SolanaDexContract = {
             }
           },
           {
-            "name": "vout",
-            "type": "u32"
-          },
-          {
-            "name": "scriptPubkey",
-            "type": "bytes"
-          },
-          {
-            "name": "value",
-            "type": "u64"
+            "name": "recoveryId",
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "btcOutput",
+      "name": "tokenRegistry",
+      "docs": [
+        "Registry entry for one ERC20 token on one EVM chain. Entries are",
+        "self-describing so clients can list supported tokens from program accounts."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "scriptPubkey",
-            "type": "bytes"
+            "name": "chainId",
+            "type": "u64"
           },
           {
-            "name": "value",
-            "type": "u64"
+            "name": "erc20Address",
+            "type": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          },
+          {
+            "name": "settings",
+            "type": {
+              "defined": {
+                "name": "tokenSettings"
+              }
+            }
+          },
+          {
+            "name": "withdrawalWindow",
+            "docs": [
+              "Usage of `settings.withdrawal_rate_limit`"
+            ],
+            "type": {
+              "defined": {
+                "name": "rateLimitWindow"
+              }
+            }
+          },
+          {
+            "name": "liabilities",
+            "type": {
+              "defined": {
+                "name": "liabilities"
+              }
+            }
+          },
+          {
+            "name": "accruedFees",
+            "docs": [
+              "Protocol fees not yet moved to the treasury balance"
+            ],
+            "type": "u128"
           }
         ]
       }
     },
     {
-      "name": "btcWithdrawParams",
+      "name": "tokenSettings",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "lockTime",
-            "type": "u32"
+            "name": "enabled",
+            "docs": [
+              "Deposits and withdrawals are rejected while disabled"
+            ],
+            "type": "bool"
           },
           {
-            "name": "caip2Id",
+            "name": "decimals",
+            "type": "u8"
+          },
+          {
+            "name": "symbol",
             "type": "string"
           },
           {
-            "name": "vaultScriptPubkey",
-            "type": "bytes"
+            "name": "minAmount",
+            "docs": [
+              "Smallest amount accepted per deposit or withdrawal, in token units"
+            ],
+            "type": "u128"
           },
           {
-            "name": "recipientScriptPubkey",
-            "type": "bytes"
+            "name": "maxAmount",
+            "docs": [
+              "Largest amount accepted per deposit or withdrawal, in token units"
+            ],
+            "type": "u128"
           },
           {
-            "name": "fee",
-            "type": "u64"
+            "name": "defaultGasLimit",
+            "docs": [
+              "Gas limit clients should use for `transfer` transactions of this token"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "withdrawalGasFee",
+            "docs": [
+              "Flat charge for the gas of a withdrawal transaction, in token units.",
+              "Debited with the withdrawal and kept even if the transfer fails"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "withdrawalRateLimit",
+            "docs": [
+              "Limit on withdrawals of this token across all users, in token units"
+            ],
+            "type": {
+              "defined": {
+                "name": "rateLimitSettings"
+              }
+            }
+          },
+          {
+            "name": "userWithdrawalRateLimit",
+            "docs": [
+              "Limit on withdrawals of this token by a single user, in token units"
+            ],
+            "type": {
+              "defined": {
+                "name": "rateLimitSettings"
+              }
+            }
+          },
+          {
+            "name": "depositCap",
+            "docs": [
+              "Cap on `liabilities` enforced when deposits are requested, 0 for no cap"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "fees",
+            "type": {
+              "defined": {
+                "name": "feeSettings"
+              }
+            }
           }
         ]
       }
     },
     {
-      "name": "evmTransactionParams",
+      "name": "userBtcBalance",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "value",
-            "type": "u128"
-          },
-          {
-            "name": "gasLimit",
-            "type": "u128"
-          },
-          {
-            "name": "maxFeePerGas",
-            "type": "u128"
-          },
-          {
-            "name": "maxPriorityFeePerGas",
-            "type": "u128"
-          },
-          {
-            "name": "nonce",
-            "type": "u64"
-          },
-          {
-            "name": "chainId",
+            "name": "amount",
             "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "pendingBtcDeposit",
+      "name": "userErc20Balance",
       "type": {
         "kind": "struct",
         "fields": [
-          {
-            "name": "requester",
-            "type": "pubkey"
-          },
           {
             "name": "amount",
-            "type": "u64"
-          },
-          {
-            "name": "path",
-            "type": "string"
-          },
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "userRateLimit",
+      "docs": [
+        "Per-user usage of an asset's `user_withdrawal_rate_limit`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "requestId",
+            "name": "window",
             "type": {
-              "array": [
-                "u8",
-                32
-              ]
+              "defined": {
+                "name": "rateLimitWindow"
+              }
             }
           }
         ]
       }
     },
     {
-      "name": "pendingBtcWithdrawal",
+      "name": "vaultConfig",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "requester",
-            "type": "pubkey"
+            "name": "adminSigners",
+            "docs": [
+              "Members of the admin multisig"
+            ],
+            "type": {
+              "vec": "pubkey"
+            }
           },
           {
-            "name": "amount",
+            "name": "adminThreshold",
+            "docs": [
+              "Number of admin approvals required to execute a config proposal"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "proposalCount",
+            "docs": [
+              "Number of config proposals created so far, used to seed the next one"
+            ],
             "type": "u64"
           },
           {
-            "name": "fee",
+            "name": "timelockSlots",
+            "docs": [
+              "Delay between a proposal reaching its threshold and becoming executable"
+            ],
             "type": "u64"
           },
           {
-            "name": "recipientAddress",
-            "type": "string"
+            "name": "pauseGuardian",
+            "docs": [
+              "Authority allowed to pause (but not unpause) deposits and withdrawals"
+            ],
+            "type": "pubkey"
           },
           {
-            "name": "path",
-            "type": "string"
+            "name": "treasury",
+            "docs": [
+              "Owner of the balances protocol fees are collected into"
+            ],
+            "type": "pubkey"
           },
           {
-            "name": "requestId",
+            "name": "globalPause",
+            "docs": [
+              "Pause switches applying to every chain"
+            ],
             "type": {
-              "array": [
-                "u8",
-                32
-              ]
+              "defined": {
+                "name": "pauseFlags"
+              }
             }
-          }
-        ]
-      }
-    },
-    {
-      "name": "pendingErc20Deposit",
-      "type": {
-        "kind": "struct",
-        "fields": [
+          },
           {
-            "name": "requester",
-            "type": "pubkey"
+            "name": "chainPauses",
+            "docs": [
+              "Pause switches for individual chains, keyed by CAIP-2 ID"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "chainPause"
+                }
+              }
+            }
           },
           {
-            "name": "amount",
-            "type": "u128"
+            "name": "activeKeyVersion",
+            "docs": [
+              "Key version used for new signature requests"
+            ],
+            "type": "u32"
           },
           {
-            "name": "erc20Address",
+            "name": "keyVersions",
+            "docs": [
+              "Root public keys of every MPC key version known to the vault"
+            ],
             "type": {
-              "array": [
-                "u8",
-                20
-              ]
+              "vec": {
+                "defined": {
+                  "name": "mpcKeyVersion"
+                }
+              }
             }
           },
           {
-            "name": "path",
+            "name": "legacyErc20ChainId",
+            "docs": [
+              "Chain ID that balances from before per-chain accounting are migrated to,",
+              "0 while migration is disabled"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "legacyBtcCaip2Id",
+            "docs": [
+              "CAIP-2 ID that BTC balances from before per-chain accounting are migrated",
+              "to, empty while migration is disabled"
+            ],
             "type": "string"
           },
           {
-            "name": "requestId",
+            "name": "globalWithdrawalRateLimit",
+            "docs": [
+              "Limit on the number of withdrawal requests across all users and assets"
+            ],
             "type": {
-              "array": [
-                "u8",
-                32
-              ]
+              "defined": {
+                "name": "rateLimitSettings"
+              }
             }
-          }
-        ]
-      }
-    },
-    {
-      "name": "pendingErc20Withdrawal",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "requester",
-            "type": "pubkey"
-          },
-          {
-            "name": "amount",
-            "type": "u128"
           },
           {
-            "name": "erc20Address",
+            "name": "globalWithdrawalWindow",
+            "docs": [
+              "Usage of `global_withdrawal_rate_limit`"
+            ],
             "type": {
-              "array": [
-                "u8",
-                20
-              ]
+              "defined": {
+                "name": "rateLimitWindow"
+              }
             }
           },
           {
-            "name": "recipientAddress",
+            "name": "rateLimitOverrides",
+            "docs": [
+              "Users currently exempt from withdrawal rate limits"
+            ],
             "type": {
-              "array": [
-                "u8",
-                20
-              ]
+              "vec": {
+                "defined": {
+                  "name": "rateLimitOverride"
+                }
+              }
             }
           },
           {
-            "name": "path",
-            "type": "string"
+            "name": "allowHighRecoveryIds",
+            "docs": [
+              "Accept response signatures with recovery IDs 2 and 3 (R.x ≥ n), which",
+              "honest signers practically never produce"
+            ],
+            "type": "bool"
           },
           {
-            "name": "requestId",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
+            "name": "withdrawalTimeoutSlots",
+            "docs": [
+              "Slots after which a pending withdrawal can be cancelled, 0 to disable",
+              "cancellation"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "crankBountyLamports",
+            "docs": [
+              "Lamports taken from a pending account's rent for whoever settles it"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "depositTimeoutSlots",
+            "docs": [
+              "Slots after which a pending deposit's reservation against the deposit cap",
+              "can be released, 0 to disable releasing"
+            ],
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "signature",
+      "name": "vaultUtxo",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "bigR",
+            "name": "outpoint",
             "type": {
               "defined": {
-                "name": "affinePoint"
+                "name": "btcOutpoint"
               }
             }
           },
           {
-            "name": "s",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
+            "name": "value",
+            "type": "u64"
           },
           {
-            "name": "recoveryId",
-            "type": "u8"
-          }
-        ]
-      }
-    },
-    {
-      "name": "userBtcBalance",
-      "type": {
-        "kind": "struct",
-        "fields": [
+            "name": "keyVersion",
+            "docs": [
+              "MPC key version whose vault script the output pays to"
+            ],
+            "type": "u32"
+          },
           {
-            "name": "amount",
-            "type": "u64"
+            "name": "reservedFor",
+            "docs": [
+              "Request ID of the pending withdrawal spending it"
+            ],
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
           }
         ]
       }
     },
     {
-      "name": "userErc20Balance",
+      "name": "vaultUtxoSet",
+      "docs": [
+        "Outputs held by the BTC vault on one chain, seeded by",
+        "`[b\"vault_utxo_set\", caip2_id_seed(caip2_id)]`. Claims add deposit outputs,",
+        "withdrawals reserve what they spend, and completions consume the reserved",
+        "outputs and add the change."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "amount",
-            "type": "u128"
-          }
-        ]
-      }
-    },
-    {
-      "name": "vaultConfig",
-      "type": {
-        "kind": "struct",
-        "fields": [
+            "name": "caip2Id",
+            "type": "string"
+          },
           {
-            "name": "mpcRootPublicKey",
-            "docs": [
-              "The full 64-byte uncompressed secp256k1 public key (without 0x04 prefix)"
-            ],
+            "name": "utxos",
             "type": {
-              "array": [
-                "u8",
-                64
-              ]
+              "vec": {
+                "defined": {
+                  "name": "vaultUtxo"
+                }
+              }
             }
           }
         ]
@@ -28,6 +28,9 @@ export const queryKeys = {
       [...queryKeys.solana.all, 'userBalances', publicKey] as const,
     txList: (publicKey: string) =>
       [...queryKeys.solana.all, 'txList', publicKey] as const,
+    erc20Tokens: () => [...queryKeys.solana.all, 'erc20Tokens'] as const,
+    networksWithTokens: () =>
+      [...queryKeys.solana.all, 'networksWithTokens'] as const,
   },
 } as const;
 
@@ -15,6 +15,7 @@ import {
 import { initializeRelayerSetup } from '@/lib/utils/relayer-setup';
 import { evmParamsToProgram } from '@/lib/program/utils';
 import { generateDepositRequestId } from '@/lib/utils/request-id';
+import { normalizeResponseSignature } from '@/lib/utils/signature';
 import {
   VAULT_ETHEREUM_ADDRESS,
   deriveVaultAuthorityPda,
@@ -118,9 +119,9 @@ async function executeDeposit(args: {
 
     const processAmount = applyContractSafetyReduction(actualAmount);
 
-    // Fetch decimals from chain (throws if token not in allowlist)
+    // Fetch decimals from chain (throws if token not in the token registry)
     const decimals = await fetchErc20Decimals(erc20Address);
-    const tokenMetadata = getErc20Token(erc20Address);
+    const tokenMetadata = await getErc20Token(erc20Address);
 
     // Phase 1.5: Gas top-up if needed
     const { topUpTxHash, fees } = await ensureGasForErc20Transfer(
@@ -187,7 +188,10 @@ async function executeDeposit(args: {
           requester: userPublicKey,
           requestIdBytes,
           serializedOutput: respondBidirectionalData.serializedOutput,
-          signature: respondBidirectionalData.signature,
+          signature: normalizeResponseSignature(
+            respondBidirectionalData.signature,
+          ),
+          chainId: txRequest.chainId,
           erc20AddressBytes,
         });
         console.log(`[DEPOSIT] Claim successful! Tx: ${claimTxHash}`);
@@ -199,9 +203,6 @@ async function executeDeposit(args: {
           payer: relayerWallet.publicKey,
           requestIdBytes,
           erc20AddressBytes,
-          recipientAddressBytes: Array.from(
-            toBytes(VAULT_ETHEREUM_ADDRESS as Hex),
-          ),
           amount: amountBN,
           evmParams,
         });
@@ -324,7 +325,10 @@ async function executeWithdrawal(args: {
           requester: requesterPublicKey,
           requestIdBytes,
           serializedOutput: respondBidirectionalData.serializedOutput,
-          signature: respondBidirectionalData.signature,
+          signature: normalizeResponseSignature(
+            respondBidirectionalData.signature,
+          ),
+          chainId: transactionParams.chainId,
           erc20AddressBytes,
         });
         console.log(`[WITHDRAW] Complete successful! Tx: ${completeTxHash}`);
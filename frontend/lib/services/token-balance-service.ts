@@ -4,10 +4,11 @@ import { getAssociatedTokenAddressSync } from '@solana/spl-token';
 import type { TokenBalance } from '@/lib/types/token.types';
 import {
   getErc20Token,
-  ERC20_TOKENS,
+  fetchErc20Tokens,
   SOLANA_TOKENS,
   fetchErc20Decimals,
 } from '@/lib/constants/token-metadata';
+import { SERVICE_CONFIG } from '@/lib/constants/service.config';
 import type { DexContract } from '@/lib/contracts/dex-contract';
 import { getAlchemyProvider } from '@/lib/rpc';
 import { isRateLimitError } from '@/lib/utils/rate-limit';
@@ -114,7 +115,9 @@ export class TokenBalanceService {
     derivedAddress: string,
   ): Promise<TokenBalance[]> {
     try {
-      const tokenAddresses = ERC20_TOKENS.map(token => token.erc20Address);
+      const tokenAddresses = (await fetchErc20Tokens()).map(
+        token => token.erc20Address,
+      );
 
       // Use batch fetching to reduce RPC calls
       const batchResults = await this.batchFetchErc20Balances(
@@ -126,7 +129,7 @@ export class TokenBalanceService {
 
       for (const result of batchResults) {
         if (result.balance > BigInt(0)) {
-          const tokenMetadata = getErc20Token(result.address);
+          const tokenMetadata = await getErc20Token(result.address);
           results.push({
             erc20Address: result.address,
             amount: result.balance.toString(),
@@ -150,23 +153,22 @@ export class TokenBalanceService {
    */
   async fetchUserBalances(publicKey: PublicKey): Promise<TokenBalance[]> {
     try {
-      const tokenAddresses = ERC20_TOKENS.map(token => token.erc20Address);
+      const erc20Tokens = await fetchErc20Tokens();
 
       // Fetch ERC20 balances from the bridge contract
-      const balancesPromises = tokenAddresses.map(async erc20Address => {
+      const balancesPromises = erc20Tokens.map(async token => {
         const balance = await this.dexContract.fetchUserBalance(
           publicKey,
-          erc20Address,
+          SERVICE_CONFIG.ETHEREUM.CHAIN_ID,
+          token.erc20Address,
         );
         if (balance !== '0') {
-          const tokenMetadata = getErc20Token(erc20Address);
-          const decimals = await fetchErc20Decimals(erc20Address);
           return {
-            erc20Address,
+            erc20Address: token.erc20Address,
             amount: balance,
-            decimals,
-            symbol: tokenMetadata?.symbol ?? 'Unknown',
-            name: tokenMetadata?.name ?? 'Unknown Token',
+            decimals: token.decimals,
+            symbol: token.symbol,
+            name: token.name,
             chain: 'ethereum',
           };
         }
@@ -31,6 +31,7 @@ import {
   VAULT_ETHEREUM_ADDRESS,
   GLOBAL_VAULT_AUTHORITY_PDA,
 } from '@/lib/constants/addresses';
+import { SERVICE_CONFIG } from '@/lib/constants/service.config';
 import { getEthereumProvider } from '@/lib/rpc';
 
 /**
@@ -192,13 +193,18 @@ export class WithdrawalService {
     try {
       const globalVaultAuthority = GLOBAL_VAULT_AUTHORITY_PDA;
 
-      // Fetch decimals from chain (throws if token not in allowlist)
+      // Fetch decimals from chain (throws if token not in the token registry)
       const decimals = await fetchErc20Decimals(erc20Address);
-      const tokenMetadata = getErc20Token(erc20Address);
+      const tokenMetadata = await getErc20Token(erc20Address);
 
       const amountBigInt = parseUnits(amount, decimals);
 
-      const userBalanceRaw = await this.dexContract.fetchUserBalance(publicKey, erc20Address);
+      const chainId = SERVICE_CONFIG.ETHEREUM.CHAIN_ID;
+      const userBalanceRaw = await this.dexContract.fetchUserBalance(
+        publicKey,
+        chainId,
+        erc20Address,
+      );
       const userBalanceBigInt = BigInt(userBalanceRaw);
       if (userBalanceBigInt < amountBigInt) {
         const formattedBalance = formatUnits(userBalanceBigInt, decimals);
@@ -219,12 +225,17 @@ export class WithdrawalService {
       const checksummedAddress = getAddress(recipientAddress);
       const recipientAddressBytes = Array.from(toBytes(checksummedAddress));
 
+      // The program assigns vault nonces, so the vault address's on-chain
+      // transaction count may lag behind pending withdrawals
+      const nonce = await this.dexContract.fetchEvmVaultNonce(chainId);
+
       const txRequest: EvmTransactionRequest = await buildErc20TransferTx({
         provider: getEthereumProvider(),
         from: VAULT_ETHEREUM_ADDRESS,
         erc20Address,
         recipient: checksummedAddress,
         amount: processAmountBigInt,
+        nonce,
       });
 
       const evmParams = evmParamsToProgram(txRequest);
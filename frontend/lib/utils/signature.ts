@@ -0,0 +1,34 @@
+import { bytesToBigInt, numberToBytes } from 'viem';
+import type { RSVSignature } from 'signet.js';
+
+const SECP256K1_ORDER =
+  0xfffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141n;
+const SECP256K1_HALF_ORDER = SECP256K1_ORDER / 2n;
+
+// Shape of the signature in `respondBidirectionalEvent`, which is typed as
+// RSVSignature by signet.js
+interface MpcSignature {
+  bigR: { x: number[]; y: number[] };
+  s: number[];
+  recoveryId: number;
+}
+
+/**
+ * Returns an MPC response signature in low-s form, flipping the recovery ID
+ * when `s` is replaced by `n - s`. The vault program rejects high-s signatures.
+ */
+export function normalizeResponseSignature(
+  signature: RSVSignature,
+): RSVSignature {
+  const mpcSignature = signature as unknown as MpcSignature;
+  const s = bytesToBigInt(Uint8Array.from(mpcSignature.s));
+  if (s <= SECP256K1_HALF_ORDER) {
+    return signature;
+  }
+
+  return {
+    ...mpcSignature,
+    s: Array.from(numberToBytes(SECP256K1_ORDER - s, { size: 32 })),
+    recoveryId: mpcSignature.recoveryId ^ 1,
+  } as unknown as RSVSignature;
+}